@@ -0,0 +1,47 @@
+// Measure the cost of evaluating each function at several dimensionalities. Run with
+// `cargo bench --bench evaluation`. To check for regressions, save a baseline with
+// `BENCHFUN_SAVE=baseline.json` and compare a later run against it with `BENCHFUN_BASELINE=baseline.json`.
+use benchfun::*;
+use benchfun::json::{Json, ToJson, FromJson};
+use benchfun::timing::{time, Timing, TimingConfig};
+
+/// The slowdown beyond which a function is reported as a regression
+const THRESHOLD: f64 = 0.1;
+
+fn main() {
+    let config = TimingConfig::default();
+    let dims = [2, 10, 100];
+    let mut timings: Vec<Timing> = Vec::new();
+    timings.extend(time::<Ackley>(&dims, &config));
+    timings.extend(time::<Griewank>(&dims, &config));
+    timings.extend(time::<Rastrigin>(&dims, &config));
+    timings.extend(time::<Rosenbrock>(&dims, &config));
+    timings.extend(time::<Zakharov>(&dims, &config));
+    timings.extend(time::<Weierstrass>(&dims, &config));
+    timings.extend(time::<Whitley>(&dims, &config));
+    timings.extend(time::<Branin>(&[2], &config));
+    timings.extend(time::<Booth>(&[2], &config));
+
+    let baseline: Vec<Timing> = match std::env::var("BENCHFUN_BASELINE") {
+        Ok(path) => {
+            let json: Json = std::fs::read_to_string(path).expect("The baseline could not be read.").parse().expect("The baseline is not valid JSON.");
+            Vec::from_json(&json).expect("The baseline is not a list of timings.")
+        }
+        Err(_) => Vec::new(),
+    };
+
+    println!("{:<12}{:>8}{:>14}{:>14}{:>10}", "function", "d", "median (ns)", "min (ns)", "change");
+    let mut regressions = 0;
+    for timing in timings.iter() {
+        let change = baseline.iter().find(|b| b.name == timing.name && b.dimension == timing.dimension).map(|b| timing.change_from(b));
+        let flag = if change.is_some_and(|c| c > THRESHOLD) { regressions += 1; " !" } else { "" };
+        let change = change.map_or(String::from("-"), |c| format!("{:+.1}%", 100.0*c));
+        println!("{:<12}{:>8}{:>14.1}{:>14.1}{:>10}{}", timing.name, timing.dimension, timing.median(), timing.min(), change, flag);
+    }
+    if let Ok(path) = std::env::var("BENCHFUN_SAVE") {
+        std::fs::write(path, timings.to_json().to_string()).expect("The timings could not be saved.");
+    }
+    if regressions > 0 {
+        println!("{} of the measurements are more than {}% slower than the baseline", regressions, 100.0*THRESHOLD);
+    }
+}
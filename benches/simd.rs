@@ -0,0 +1,39 @@
+// Compare the `f` functions of the hot functions with their vectorized implementations, which
+// requires the `simd` feature. Run with `cargo bench --features simd`.
+use benchfun::*;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// An implementation of a function on a slice
+type Implementation = fn(&[f64]) -> f64;
+
+/// Time a function over many evaluations and return the mean time per evaluation in nanoseconds
+fn time(x: &[f64], repetitions: usize, g: impl Fn(&[f64]) -> f64) -> f64 {
+    let start = Instant::now();
+    for _ in 0..repetitions {
+        black_box(g(black_box(x)));
+    }
+    start.elapsed().as_nanos() as f64/repetitions as f64
+}
+
+fn main() {
+    // The scalar side is `f`, as users call it, including the allocation of its argument
+    let implementations: [(&str, Implementation, Implementation); 5] = [
+        ("Sphere", |x| Sphere::f(x.to_vec()), simd::sphere),
+        ("Rastrigin", |x| Rastrigin::f(x.to_vec()), simd::rastrigin),
+        ("Rosenbrock", |x| Rosenbrock::f(x.to_vec()), simd::rosenbrock),
+        ("Ackley", |x| Ackley::f(x.to_vec()), simd::ackley),
+        ("Griewank", |x| Griewank::f(x.to_vec()), simd::griewank),
+    ];
+    let mut rng = Rng::new(0);
+    println!("{:<12}{:>8}{:>14}{:>14}{:>10}", "function", "d", "f (ns)", "simd (ns)", "speedup");
+    for d in [10, 100, 1000, 10000].iter() {
+        let x: Vec<f64> = (0..*d).map(|_| 4.0*rng.uniform() - 2.0).collect();
+        let repetitions = 10_000_000/d;
+        for (name, scalar, vectorized) in implementations.iter() {
+            let t_scalar = time(&x, repetitions, scalar);
+            let t_simd = time(&x, repetitions, vectorized);
+            println!("{:<12}{:>8}{:>14.1}{:>14.1}{:>10.2}", name, d, t_scalar, t_simd, t_scalar/t_simd);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+"""Generates the consistency tables, the values of the single-objective functions over a grid.
+
+Each table is written by one implementation of the functions, in the format of reference.csv with a
+row for each point of the grid. The grid has 5 levels per coordinate in 1 and 2 dimensions, 3 levels
+in 3 and 4 dimensions and 2 levels beyond, and N-dimensional functions are evaluated in 2 and 5
+dimensions. Tables are written for the implementations that can be imported:
+
+- reference.csv, from the implementation of ../reference.py, which is maintained alongside the crate
+  and so is a self-consistency check rather than an independent one
+- deap.csv, from deap.benchmarks, which is not bundled
+- pymoo.csv, from pymoo.problems, which is not bundled
+
+Run it from this directory with `python3 generate.py`.
+"""
+
+import csv
+import itertools
+import sys
+
+sys.path.insert(0, "..")
+from reference import FUNCTIONS  # noqa: E402
+
+
+def levels(d):
+    if d <= 2:
+        return [0.1, 0.3, 0.5, 0.7, 0.9]
+    if d <= 4:
+        return [0.2, 0.5, 0.8]
+    return [0.25, 0.75]
+
+
+def grid(bounds, d):
+    bounds = bounds if isinstance(bounds, list) else [bounds] * d
+    axes = [[round(low + (high - low) * t, 4) for t in levels(d)] for low, high in bounds]
+    return [list(x) for x in itertools.product(*axes)]
+
+
+def write(path, implementations):
+    """Writes the values of the functions that have an implementation, given by name."""
+    with open(path, "w", newline="") as file:
+        writer = csv.writer(file, lineterminator="\n")
+        writer.writerow(["function", "x", "f"])
+        for name, _, dimension, bounds, _ in FUNCTIONS:
+            if name not in implementations:
+                continue
+            for d in ([dimension] if dimension else [2, 5]):
+                for x in grid(bounds, d):
+                    writer.writerow([name, " ".join(repr(float(xi)) for xi in x), repr(float(implementations[name](x)))])
+
+
+write("reference.csv", {name: f for name, f, _, _, _ in FUNCTIONS})
+
+try:
+    from deap import benchmarks
+except ImportError:
+    print("deap is not installed, so deap.csv is not written")
+else:
+    write("deap.csv", {
+        name: (lambda f: lambda x: f(x)[0])(f) for name, f in [
+            ("Sphere", benchmarks.sphere),
+            ("Rastrigin", benchmarks.rastrigin),
+            ("Rosenbrock", benchmarks.rosenbrock),
+            ("Ackley", benchmarks.ackley),
+            ("Griewank", benchmarks.griewank),
+            ("Himmelblau", benchmarks.himmelblau),
+        ]
+    })
+
+try:
+    import numpy as np
+    from pymoo.problems import get_problem
+except ImportError:
+    print("pymoo is not installed, so pymoo.csv is not written")
+else:
+    def problem(key):
+        return lambda x: get_problem(key, n_var=len(x)).evaluate(np.array([x]))[0][0]
+
+    write("pymoo.csv", {
+        name: problem(key) for name, key in [
+            ("Sphere", "sphere"),
+            ("Rastrigin", "rastrigin"),
+            ("Rosenbrock", "rosenbrock"),
+            ("Ackley", "ackley"),
+            ("Griewank", "griewank"),
+            ("Zakharov", "zakharov"),
+        ]
+    })
@@ -0,0 +1,499 @@
+"""Generates reference.csv, the reference values of the single-objective functions.
+
+Each function is implemented here from the formula given by its source, independently of the Rust
+implementation, and evaluated in double precision at its global minimizer and at a few points
+spread over its domain. Run it from this directory with `python3 reference.py`.
+"""
+
+import csv
+from math import cos, exp, fabs, floor, log, log10, pi, sin, sqrt
+
+# Functions of any dimensionality, with their bounds and their minimizer at a dimensionality
+
+
+def sphere(x):
+    return sum(xi**2 for xi in x)
+
+
+def rastrigin(x):
+    return 10 * len(x) + sum(xi**2 - 10 * cos(2 * pi * xi) for xi in x)
+
+
+def rosenbrock(x):
+    return sum(100 * (x[i + 1] - x[i] ** 2) ** 2 + (1 - x[i]) ** 2 for i in range(len(x) - 1))
+
+
+def ackley(x):
+    n = len(x)
+    return (-20 * exp(-0.2 * sqrt(sum(xi**2 for xi in x) / n))
+            - exp(sum(cos(2 * pi * xi) for xi in x) / n) + 20 + exp(1))
+
+
+def matyas(x):
+    a, b = x
+    return 0.26 * (a**2 + b**2) - 0.48 * a * b
+
+
+def griewank(x):
+    prod = 1.0
+    for i, xi in enumerate(x, 1):
+        prod *= cos(xi / sqrt(i))
+    return 1 + sum(xi**2 for xi in x) / 4000 - prod
+
+
+def ridge(x, d=1.0, alpha=0.5):
+    return x[0] + d * sum(xi**2 for xi in x[1:]) ** alpha
+
+
+def zakharov(x):
+    s = sum(0.5 * i * xi for i, xi in enumerate(x, 1))
+    return sum(xi**2 for xi in x) + s**2 + s**4
+
+
+def salomon(x):
+    r = sqrt(sum(xi**2 for xi in x))
+    return 1 - cos(2 * pi * r) + 0.1 * r
+
+
+def rosenbrock_2d(x):
+    return (1 - x[0]) ** 2 + 100 * (x[1] - x[0] ** 2) ** 2
+
+
+def katsuura(x):
+    n = len(x)
+    prod = 1.0
+    for i, xi in enumerate(x, 1):
+        s = sum(fabs(2**j * xi - round(2**j * xi)) / 2**j for j in range(1, 33))
+        prod *= (1 + i * s) ** (10 / n**1.2)
+    return 10 / n**2 * prod - 10 / n**2
+
+
+def weierstrass(x, a=0.5, b=3.0, k_max=20):
+    n = len(x)
+    total = sum(sum(a**k * cos(2 * pi * b**k * (xi + 0.5)) for k in range(k_max + 1)) for xi in x)
+    return total - n * sum(a**k * cos(pi * b**k) for k in range(k_max + 1))
+
+
+def quartic(x):
+    return sum(i * xi**4 for i, xi in enumerate(x, 1))
+
+
+def step(x):
+    return sum(floor(xi + 0.5) ** 2 for xi in x)
+
+
+def exponential(x):
+    return -exp(-0.5 * sum(xi**2 for xi in x))
+
+
+def brown(x):
+    return sum((x[i] ** 2) ** (x[i + 1] ** 2 + 1) + (x[i + 1] ** 2) ** (x[i] ** 2 + 1) for i in range(len(x) - 1))
+
+
+def chung_reynolds(x):
+    return sum(xi**2 for xi in x) ** 2
+
+
+def csendes(x):
+    return sum(xi**6 * (2 + sin(1 / xi)) if xi != 0 else 0.0 for xi in x)
+
+
+def qing(x):
+    return sum((xi**2 - i) ** 2 for i, xi in enumerate(x, 1))
+
+
+def quintic(x):
+    return sum(fabs(xi**5 - 3 * xi**4 + 4 * xi**3 + 2 * xi**2 - 10 * xi - 4) for xi in x)
+
+
+def schaffer_g(a, b, inner):
+    return 0.5 + (sin(inner) ** 2 - 0.5) / (1 + 0.001 * (a**2 + b**2)) ** 2
+
+
+def expanded_schaffer_f6(x):
+    n = len(x)
+    return sum(schaffer_g(x[i], x[(i + 1) % n], sqrt(x[i] ** 2 + x[(i + 1) % n] ** 2)) for i in range(n))
+
+
+def schaffer_f7(x):
+    n = len(x)
+    s = [sqrt(x[i] ** 2 + x[i + 1] ** 2) for i in range(n - 1)]
+    return (sum(sqrt(si) * (1 + sin(50 * si**0.2) ** 2) for si in s) / (n - 1)) ** 2
+
+
+def bent_cigar(x):
+    return x[0] ** 2 + 1e6 * sum(xi**2 for xi in x[1:])
+
+
+def discus(x):
+    return 1e6 * x[0] ** 2 + sum(xi**2 for xi in x[1:])
+
+
+def sharp_ridge(x):
+    return x[0] ** 2 + 100 * sqrt(sum(xi**2 for xi in x[1:]))
+
+
+def ellipsoidal(x, condition=1e6):
+    n = len(x)
+    return sum((condition ** ((i) / (n - 1)) if n > 1 else 1.0) * xi**2 for i, xi in enumerate(x))
+
+
+def lunacek_s(n):
+    return 1 - 1 / (2 * sqrt(n + 20) - 8.2)
+
+
+def lunacek_bi_rastrigin(x, mu0=2.5, d=1.0):
+    n = len(x)
+    s = lunacek_s(n)
+    mu1 = -sqrt((mu0**2 - d) / s)
+    first = sum((xi - mu0) ** 2 for xi in x)
+    second = d * n + s * sum((xi - mu1) ** 2 for xi in x)
+    return min(first, second) + 10 * sum(1 - cos(2 * pi * (xi - mu0)) for xi in x)
+
+
+def cosine_mixture(x):
+    return -0.1 * sum(cos(5 * pi * xi) for xi in x) + sum(xi**2 for xi in x)
+
+
+def pinter(x):
+    n = len(x)
+    total = 0.0
+    for i in range(n):
+        w = i + 1
+        prev, nxt = x[i - 1], x[(i + 1) % n]
+        a = prev * sin(x[i]) + sin(nxt)
+        b = prev**2 - 2 * x[i] + 3 * nxt - cos(x[i]) + 1
+        total += w * x[i] ** 2 + 20 * w * sin(a) ** 2 + w * log10(1 + w * b**2)
+    return total
+
+
+def sine_envelope(x):
+    return sum(schaffer_g(x[i], x[i + 1], sqrt(x[i] ** 2 + x[i + 1] ** 2)) for i in range(len(x) - 1))
+
+
+def vincent(x):
+    return -sum(sin(10 * log(xi)) for xi in x) / len(x)
+
+
+def deb_n1(x):
+    return -sum(sin(5 * pi * xi) ** 6 for xi in x) / len(x)
+
+
+def deb_deceptive(x):
+    return -sum(exp(-2 * log(2) * ((xi - 0.08) / 0.854) ** 2) * sin(5 * pi * (xi**0.75 - 0.05)) ** 6 for xi in x) / len(x)
+
+
+def periodic(x):
+    return 1 + sum(sin(xi) ** 2 for xi in x) - 0.1 * exp(-sum(xi**2 for xi in x))
+
+
+def whitley(x):
+    total = 0.0
+    for xi in x:
+        for xj in x:
+            y = 100 * (xi**2 - xj) ** 2 + (1 - xj) ** 2
+            total += y**2 / 4000 - cos(y) + 1
+    return total
+
+
+# Fixed-dimensional functions
+
+
+def beale(x):
+    a, b = x
+    return (1.5 - a + a * b) ** 2 + (2.25 - a + a * b**2) ** 2 + (2.625 - a + a * b**3) ** 2
+
+
+def booth(x):
+    a, b = x
+    return (a + 2 * b - 7) ** 2 + (2 * a + b - 5) ** 2
+
+
+def bukin_n6(x):
+    a, b = x
+    return 100 * sqrt(fabs(b - 0.01 * a**2)) + 0.01 * fabs(a + 10)
+
+
+def cross_in_tray(x):
+    a, b = x
+    return -0.0001 * (fabs(sin(a) * sin(b) * exp(fabs(100 - sqrt(a**2 + b**2) / pi))) + 1) ** 0.1
+
+
+def drop_wave(x):
+    r2 = x[0] ** 2 + x[1] ** 2
+    return -(1 + cos(12 * sqrt(r2))) / (0.5 * r2 + 2)
+
+
+def easom(x):
+    a, b = x
+    return -cos(a) * cos(b) * exp(-((a - pi) ** 2) - (b - pi) ** 2)
+
+
+def eggholder(x):
+    a, b = x
+    return -(b + 47) * sin(sqrt(fabs(a / 2 + b + 47))) - a * sin(sqrt(fabs(a - (b + 47))))
+
+
+def goldstein_price(x):
+    a, b = x
+    first = 1 + (a + b + 1) ** 2 * (19 - 14 * a + 3 * a**2 - 14 * b + 6 * a * b + 3 * b**2)
+    second = 30 + (2 * a - 3 * b) ** 2 * (18 - 32 * a + 12 * a**2 + 48 * b - 36 * a * b + 27 * b**2)
+    return first * second
+
+
+def himmelblau(x):
+    a, b = x
+    return (a**2 + b - 11) ** 2 + (a + b**2 - 7) ** 2
+
+
+def holder_table(x):
+    a, b = x
+    return -fabs(sin(a) * cos(b) * exp(fabs(1 - sqrt(a**2 + b**2) / pi)))
+
+
+def levi_n13(x):
+    a, b = x
+    return sin(3 * pi * a) ** 2 + (a - 1) ** 2 * (1 + sin(3 * pi * b) ** 2) + (b - 1) ** 2 * (1 + sin(2 * pi * b) ** 2)
+
+
+def mccormick(x):
+    a, b = x
+    return sin(a + b) + (a - b) ** 2 - 1.5 * a + 2.5 * b + 1
+
+
+def schaffer_n2(x):
+    a, b = x
+    return schaffer_g(a, b, a**2 - b**2)
+
+
+def schaffer_n4(x):
+    a, b = x
+    return 0.5 + (cos(sin(fabs(a**2 - b**2))) ** 2 - 0.5) / (1 + 0.001 * (a**2 + b**2)) ** 2
+
+
+def three_hump_camel(x):
+    a, b = x
+    return 2 * a**2 - 1.05 * a**4 + a**6 / 6 + a * b + b**2
+
+
+def six_hump_camel(x):
+    a, b = x
+    return (4 - 2.1 * a**2 + a**4 / 3) * a**2 + a * b + (-4 + 4 * b**2) * b**2
+
+
+def branin(x):
+    a, b = x
+    return (b - 5.1 / (4 * pi**2) * a**2 + 5 / pi * a - 6) ** 2 + 10 * (1 - 1 / (8 * pi)) * cos(a) + 10
+
+
+def shubert(x):
+    prod = 1.0
+    for xi in x:
+        prod *= sum(i * cos((i + 1) * xi + i) for i in range(1, 6))
+    return prod
+
+
+def shekel_foxholes(x):
+    grid = [-32.0, -16.0, 0.0, 16.0, 32.0]
+    total = 0.002
+    for i in range(25):
+        total += 1 / (i + 1 + (x[0] - grid[i % 5]) ** 6 + (x[1] - grid[i // 5]) ** 6)
+    return 1 / total
+
+
+def langermann(x):
+    c = [1, 2, 5, 2, 3]
+    a = [[3, 5], [5, 2], [2, 1], [1, 4], [7, 9]]
+    total = 0.0
+    for ci, ai in zip(c, a):
+        r2 = sum((xj - aj) ** 2 for xj, aj in zip(x, ai))
+        total += ci * exp(-r2 / pi) * cos(pi * r2)
+    return total
+
+
+def bohachevsky_n1(x):
+    a, b = x
+    return a**2 + 2 * b**2 - 0.3 * cos(3 * pi * a) - 0.4 * cos(4 * pi * b) + 0.7
+
+
+def bohachevsky_n2(x):
+    a, b = x
+    return a**2 + 2 * b**2 - 0.3 * cos(3 * pi * a) * cos(4 * pi * b) + 0.3
+
+
+def bohachevsky_n3(x):
+    a, b = x
+    return a**2 + 2 * b**2 - 0.3 * cos(3 * pi * a + 4 * pi * b) + 0.3
+
+
+def bird(x):
+    a, b = x
+    return sin(a) * exp((1 - cos(b)) ** 2) + cos(b) * exp((1 - sin(a)) ** 2) + (a - b) ** 2
+
+
+def adjiman(x):
+    a, b = x
+    return cos(a) * sin(b) - a / (b**2 + 1)
+
+
+def bartels_conn(x):
+    a, b = x
+    return fabs(a**2 + b**2 + a * b) + fabs(sin(a)) + fabs(cos(b))
+
+
+def hartmann(x, alpha, a, p):
+    return -sum(al * exp(-sum(aj * (xj - pj) ** 2 for xj, aj, pj in zip(x, ai, pi_))) for al, ai, pi_ in zip(alpha, a, p))
+
+
+HARTMANN_ALPHA = [1.0, 1.2, 3.0, 3.2]
+
+
+def hartmann3(x):
+    a = [[3, 10, 30], [0.1, 10, 35], [3, 10, 30], [0.1, 10, 35]]
+    p = [[3689, 1170, 2673], [4699, 4387, 7470], [1091, 8732, 5547], [381, 5743, 8828]]
+    return hartmann(x, HARTMANN_ALPHA, a, [[pij * 1e-4 for pij in pi_] for pi_ in p])
+
+
+def hartmann6(x):
+    a = [[10, 3, 17, 3.5, 1.7, 8], [0.05, 10, 17, 0.1, 8, 14], [3, 3.5, 1.7, 10, 17, 8], [17, 8, 0.05, 10, 0.1, 14]]
+    p = [[1312, 1696, 5569, 124, 8283, 5886], [2329, 4135, 8307, 3736, 1004, 9991],
+         [2348, 1451, 3522, 2883, 3047, 6650], [4047, 8828, 8732, 5743, 1091, 381]]
+    return hartmann(x, HARTMANN_ALPHA, a, [[pij * 1e-4 for pij in pi_] for pi_ in p])
+
+
+def shekel(x, m=10):
+    beta = [v / 10 for v in [1, 2, 2, 4, 4, 6, 3, 7, 5, 5]]
+    c = [[4, 1, 8, 6, 3, 2, 5, 8, 6, 7], [4, 1, 8, 6, 7, 9, 3, 1, 2, 3.6],
+         [4, 1, 8, 6, 3, 2, 5, 8, 6, 7], [4, 1, 8, 6, 7, 9, 3, 1, 2, 3.6]]
+    return -sum(1 / (sum((x[j] - c[j][i]) ** 2 for j in range(4)) + beta[i]) for i in range(m))
+
+
+def colville(x):
+    x1, x2, x3, x4 = x
+    return (100 * (x1**2 - x2) ** 2 + (x1 - 1) ** 2 + (x3 - 1) ** 2 + 90 * (x3**2 - x4) ** 2
+            + 10.1 * ((x2 - 1) ** 2 + (x4 - 1) ** 2) + 19.8 * (x2 - 1) * (x4 - 1))
+
+
+def wood(x):
+    x1, x2, x3, x4 = x
+    return (100 * (x1**2 - x2) ** 2 + (x1 - 1) ** 2 + 90 * (x3**2 - x4) ** 2 + (1 - x3) ** 2
+            + 10.1 * ((x2 - 1) ** 2 + (x4 - 1) ** 2) + 19.8 * (x2 - 1) * (x4 - 1))
+
+
+def forrester(x):
+    return (6 * x[0] - 2) ** 2 * sin(12 * x[0] - 4)
+
+
+def gramacy_lee(x):
+    return sin(10 * pi * x[0]) / (2 * x[0]) + (x[0] - 1) ** 4
+
+
+def damavandi(x):
+    def sinc(t):
+        return 1.0 if t == 0 else sin(pi * t) / (pi * t)
+    ratio = fabs(sinc(x[0] - 2) * sinc(x[1] - 2))
+    return (1 - ratio**5) * (2 + (x[0] - 7) ** 2 + 2 * (x[1] - 7) ** 2)
+
+
+def constant(value):
+    return lambda n: [value] * n
+
+
+# Name, function, dimensionality (None for any), bounds of every coordinate or a list of bounds of
+# each coordinate, and minimizer, in the order of the registry
+FUNCTIONS = [
+    ("Sphere", sphere, None, (-5.0, 5.0), constant(0.0)),
+    ("Rastrigin", rastrigin, None, (-5.12, 5.12), constant(0.0)),
+    ("Rosenbrock", rosenbrock, None, (-5.0, 10.0), constant(1.0)),
+    ("Ackley", ackley, None, (-5.0, 5.0), constant(0.0)),
+    ("Matyas", matyas, 2, (-10.0, 10.0), constant(0.0)),
+    ("Griewank", griewank, None, (-600.0, 600.0), constant(0.0)),
+    ("Ridge", ridge, None, (-5.0, 5.0), lambda n: [-5.0] + [0.0] * (n - 1)),
+    ("Zakharov", zakharov, None, (-5.0, 10.0), constant(0.0)),
+    ("Salomon", salomon, None, (-100.0, 100.0), constant(0.0)),
+    ("RosenbrockConst1", rosenbrock_2d, 2, (-1.5, 1.5), constant(1.0)),
+    ("RosenbrockConst2", rosenbrock_2d, 2, (-1.5, 1.5), constant(1.0)),
+    ("Katsuura", katsuura, None, (-100.0, 100.0), constant(0.0)),
+    ("Weierstrass", weierstrass, None, (-0.5, 0.5), constant(0.0)),
+    ("Quartic", quartic, None, (-1.28, 1.28), constant(0.0)),
+    ("Step", step, None, (-100.0, 100.0), constant(0.0)),
+    ("Exponential", exponential, None, (-1.0, 1.0), constant(0.0)),
+    ("Brown", brown, None, (-1.0, 4.0), constant(0.0)),
+    ("ChungReynolds", chung_reynolds, None, (-100.0, 100.0), constant(0.0)),
+    ("Csendes", csendes, None, (-1.0, 1.0), constant(0.0)),
+    ("Qing", qing, None, (-500.0, 500.0), lambda n: [sqrt(i) for i in range(1, n + 1)]),
+    ("Quintic", quintic, None, (-10.0, 10.0), constant(-1.0)),
+    ("ExpandedSchafferF6", expanded_schaffer_f6, None, (-100.0, 100.0), constant(0.0)),
+    ("SchafferF7", schaffer_f7, None, (-100.0, 100.0), constant(0.0)),
+    ("BentCigar", bent_cigar, None, (-5.0, 5.0), constant(0.0)),
+    ("Discus", discus, None, (-5.0, 5.0), constant(0.0)),
+    ("SharpRidge", sharp_ridge, None, (-5.0, 5.0), constant(0.0)),
+    ("Ellipsoidal", ellipsoidal, None, (-5.0, 5.0), constant(0.0)),
+    ("LunacekBiRastrigin", lunacek_bi_rastrigin, None, (-5.12, 5.12), constant(2.5)),
+    ("CosineMixture", cosine_mixture, None, (-1.0, 1.0), constant(0.0)),
+    ("Pinter", pinter, None, (-10.0, 10.0), constant(0.0)),
+    ("SineEnvelope", sine_envelope, None, (-100.0, 100.0), constant(0.0)),
+    ("Vincent", vincent, None, (0.25, 10.0), constant(exp(0.5 * pi / 10))),
+    ("DebN1", deb_n1, None, (0.0, 1.0), constant(0.1)),
+    ("DebDeceptive", deb_deceptive, None, (0.0, 1.0), constant(0.07969977961179582)),
+    ("Periodic", periodic, None, (-10.0, 10.0), constant(0.0)),
+    ("Whitley", whitley, None, (-10.24, 10.24), constant(1.0)),
+    ("Beale", beale, 2, (-4.5, 4.5), lambda n: [3.0, 0.5]),
+    ("Booth", booth, 2, (-10.0, 10.0), lambda n: [1.0, 3.0]),
+    ("BukinN6", bukin_n6, 2, [(-15.0, -5.0), (-3.0, 3.0)], lambda n: [-10.0, 1.0]),
+    ("CrossInTray", cross_in_tray, 2, (-10.0, 10.0), constant(1.349406617153911)),
+    ("DropWave", drop_wave, 2, (-5.12, 5.12), constant(0.0)),
+    ("Easom", easom, 2, (-100.0, 100.0), constant(pi)),
+    ("Eggholder", eggholder, 2, (-512.0, 512.0), lambda n: [512.0, 404.2318051137578]),
+    ("GoldsteinPrice", goldstein_price, 2, (-2.0, 2.0), lambda n: [0.0, -1.0]),
+    ("Himmelblau", himmelblau, 2, (-5.0, 5.0), lambda n: [3.0, 2.0]),
+    ("HolderTable", holder_table, 2, (-10.0, 10.0), lambda n: [8.055023475736563, 9.664590019241273]),
+    ("LeviN13", levi_n13, 2, (-10.0, 10.0), constant(1.0)),
+    ("McCormick", mccormick, 2, [(-1.5, 4.0), (-3.0, 4.0)], lambda n: [0.5 - pi / 3, -0.5 - pi / 3]),
+    ("SchafferN2", schaffer_n2, 2, (-100.0, 100.0), constant(0.0)),
+    ("SchafferN4", schaffer_n4, 2, (-100.0, 100.0), lambda n: [0.0, 1.253131831463733]),
+    ("ThreeHumpCamel", three_hump_camel, 2, (-5.0, 5.0), constant(0.0)),
+    ("SixHumpCamel", six_hump_camel, 2, [(-3.0, 3.0), (-2.0, 2.0)], lambda n: [0.08984201310031806, -0.7126564030207396]),
+    ("Branin", branin, 2, [(-5.0, 10.0), (0.0, 15.0)], lambda n: [pi, 2.275]),
+    ("Shubert", shubert, 2, (-10.0, 10.0), lambda n: [-1.425128428319761, -0.8003211004719731]),
+    ("ShekelFoxholes", shekel_foxholes, 2, (-65.536, 65.536), lambda n: [-31.97833483565697, -31.978334837300795]),
+    ("Langermann", langermann, 2, (0.0, 10.0), lambda n: [2.793402208645037, 1.59723250132836]),
+    ("BohachevskyN1", bohachevsky_n1, 2, (-100.0, 100.0), constant(0.0)),
+    ("BohachevskyN2", bohachevsky_n2, 2, (-100.0, 100.0), constant(0.0)),
+    ("BohachevskyN3", bohachevsky_n3, 2, (-100.0, 100.0), constant(0.0)),
+    ("Bird", bird, 2, (-2 * pi, 2 * pi), lambda n: [4.701043130249553, 3.15293850372493]),
+    ("Adjiman", adjiman, 2, [(-1.0, 2.0), (-1.0, 1.0)], lambda n: [2.0, 0.1057834694517169]),
+    ("BartelsConn", bartels_conn, 2, (-500.0, 500.0), constant(0.0)),
+    ("Hartmann3", hartmann3, 3, (0.0, 1.0), lambda n: [0.11458887665506897, 0.5556488946169301, 0.8525469846866774]),
+    ("Hartmann6", hartmann6, 6, (0.0, 1.0), lambda n: [0.20168951100670543, 0.15001069182345797, 0.476873974221897,
+                                                       0.2753324304940561, 0.31165161660011324, 0.6573005340656203]),
+    ("Shekel", shekel, 4, (0.0, 10.0), lambda n: [4.000746868270634, 3.9995094800857736] * 2),
+    ("Colville", colville, 4, (-10.0, 10.0), constant(1.0)),
+    ("Wood", wood, 4, (-10.0, 10.0), constant(1.0)),
+    ("Forrester", forrester, 1, (0.0, 1.0), lambda n: [0.7572487578418559]),
+    ("GramacyLee", gramacy_lee, 1, (0.5, 2.5), lambda n: [0.5485634445276052]),
+    ("Damavandi", damavandi, 2, (0.0, 14.0), constant(2.0)),
+]
+
+# The points are spread over the domain at these fractions of its width in each coordinate
+FRACTIONS = [
+    [0.15, 0.7, 0.4, 0.9, 0.25, 0.55],
+    [0.6, 0.35, 0.8, 0.05, 0.5, 0.3],
+    [0.9, 0.1, 0.65, 0.45, 0.75, 0.2],
+]
+
+
+def points(bounds, minimizer, d):
+    bounds = bounds if isinstance(bounds, list) else [bounds] * d
+    spread = [[round(low + (high - low) * t, 4) for (low, high), t in zip(bounds, fractions)] for fractions in FRACTIONS]
+    return [minimizer(d)] + spread
+
+
+if __name__ == "__main__":
+    with open("reference.csv", "w", newline="") as file:
+        writer = csv.writer(file, lineterminator="\n")
+        writer.writerow(["function", "x", "f"])
+        for name, f, dimension, bounds, minimizer in FUNCTIONS:
+            for d in ([dimension] if dimension else [2, 5]):
+                for x in points(bounds, minimizer, d):
+                    writer.writerow([name, " ".join(repr(float(xi)) for xi in x), repr(f(x))])
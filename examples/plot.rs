@@ -0,0 +1,7 @@
+// Render a heatmap of the Himmelblau function, which requires the `plot` feature
+use benchfun::*;
+
+fn main() -> std::io::Result<()> {
+    plot::save_svg::<Himmelblau, _>("himmelblau.svg", 100, 10)?;
+    plot::save_png::<Himmelblau, _>("himmelblau.png", 256)
+}
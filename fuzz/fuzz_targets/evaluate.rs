@@ -0,0 +1,21 @@
+//! This target evaluates a function of the registry, chosen by the first byte, at a point made of
+//! the remaining bytes, and describes it at the dimensionality of the point.
+
+#![no_main]
+
+use std::convert::TryInto;
+
+use benchfun::registry::functions;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((first, rest)) = data.split_first() {
+        let function = &functions()[*first as usize % functions().len()];
+        let x: Vec<f64> = rest.chunks_exact(8).map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap())).collect();
+        if let Ok(value) = function.try_f(&x) {
+            assert!(value.is_finite());
+        }
+        let _ = function.try_constraints(&x);
+        let _ = function.try_descriptor(x.len());
+    }
+});
@@ -0,0 +1,16 @@
+//! This target evaluates a function by an arbitrary name, which is the bytes up to the first zero,
+//! at a point made of the remaining bytes.
+
+#![no_main]
+
+use benchfun::total::{constraints, describe, evaluate};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let split = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    let name = String::from_utf8_lossy(&data[..split]);
+    let x: Vec<f64> = data[split..].iter().skip(1).map(|b| (*b as f64 - 128.0)/8.0).collect();
+    let _ = evaluate(&name, &x);
+    let _ = constraints(&name, &x);
+    let _ = describe(&name, x.len());
+});
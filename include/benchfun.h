@@ -0,0 +1,53 @@
+/* C interface to the benchfun benchmark functions, built with
+ * `cargo rustc --release --features capi --crate-type cdylib`.
+ *
+ * Functions are identified by their index in the registry, from 0 to benchfun_count() - 1, which
+ * can be looked up by name with benchfun_id. Every call returns one of the status codes below, and
+ * writes its results through the pointers supplied by the caller. */
+#ifndef BENCHFUN_H
+#define BENCHFUN_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define BENCHFUN_OK 0
+#define BENCHFUN_UNKNOWN_FUNCTION -1
+#define BENCHFUN_UNSUPPORTED_DIMENSION -2
+#define BENCHFUN_INVALID_ARGUMENT -3
+#define BENCHFUN_INTERNAL_ERROR -4
+
+/* The number of functions */
+int benchfun_count(void);
+
+/* The id of a function from its name, or BENCHFUN_UNKNOWN_FUNCTION */
+int benchfun_id(const char *name);
+
+/* Copy the NUL-terminated name of a function into a buffer */
+int benchfun_name(int id, char *buffer, size_t capacity);
+
+/* The dimensionality of a function, which is 0 for functions of any dimensionality */
+int benchfun_dimension(int id, size_t *dimension);
+
+/* Evaluate a function at a point with d elements */
+int benchfun_evaluate(int id, const double *x, size_t d, double *f);
+
+/* Evaluate a function, given by name, at a point with d elements */
+int benchfun_evaluate_by_name(const char *name, const double *x, size_t d, double *f);
+
+/* The lower and upper bounds of each of d dimensions, which are infinite for unbounded functions */
+int benchfun_bounds(int id, size_t d, double *lower, double *upper);
+
+/* The global minimum at a dimensionality */
+int benchfun_minimum(int id, size_t d, double *minimum);
+
+/* The global minimizer at a dimensionality, written to d doubles */
+int benchfun_minimizer(int id, size_t d, double *minimizer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
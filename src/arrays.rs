@@ -0,0 +1,282 @@
+//! This module contains export of grids and datasets as NumPy and MATLAB files
+//!
+//! Arrays are written as `.npy` files, as `.npz` archives of several named arrays, or as MATLAB
+//! level 5 `.mat` files holding several named variables. The files can be read with `numpy.load`,
+//! `scipy.io.loadmat` or MATLAB's `load`. All values are written as doubles.
+//!
+//! ```
+//! use benchfun::Himmelblau;
+//! use benchfun::arrays::{grid_arrays, write_npz, write_mat};
+//! use benchfun::grid::grid_evaluate;
+//!
+//! let arrays = grid_arrays(&grid_evaluate::<Himmelblau>(50));
+//! let mut npz = Vec::new();
+//! write_npz(&mut npz, &arrays).unwrap();
+//! let mut mat = Vec::new();
+//! write_mat(&mut mat, &arrays).unwrap();
+//! ```
+
+use std::io::{Result, Write};
+
+use crate::dataset::Dataset;
+use crate::grid::Grid;
+
+/// This struct holds an array of doubles in row-major (C) order
+#[derive(Clone, Debug, PartialEq)]
+pub struct Array {
+    /// The length of each axis
+    pub shape: Vec<usize>,
+    /// The values, in row-major order
+    pub data: Vec<f64>,
+}
+
+impl Array {
+    /// This function creates a one-dimensional array
+    pub fn vector(data: Vec<f64>) -> Array {
+        Array { shape: vec![data.len()], data }
+    }
+
+    /// This function creates a two-dimensional array from its rows, which must have the same length.
+    /// `columns` sets the number of columns when there are no rows.
+    pub fn from_rows(rows: &[Vec<f64>], columns: usize) -> Array {
+        let columns = rows.first().map_or(columns, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == columns), "Every row of an array must have the same length.");
+        Array { shape: vec![rows.len(), columns], data: rows.concat() }
+    }
+
+    /// This function returns the values in column-major (Fortran) order
+    fn column_major(&self) -> Vec<f64> {
+        let n = self.data.len();
+        let mut strides = vec![1; self.shape.len()];
+        for k in (0..self.shape.len().saturating_sub(1)).rev() {
+            strides[k] = strides[k + 1]*self.shape[k + 1];
+        }
+        (0..n)
+            .map(|mut i| {
+                let mut offset = 0;
+                for (length, stride) in self.shape.iter().zip(strides.iter()) {
+                    offset += (i % length)*stride;
+                    i /= length;
+                }
+                self.data[offset]
+            })
+            .collect()
+    }
+}
+
+/// This function returns the coordinates and values of a grid as the arrays `x`, `y` and `z`,
+/// where `z` has a row for each value of `y`
+pub fn grid_arrays(grid: &Grid) -> Vec<(String, Array)> {
+    vec![
+        ("x".to_string(), Array::vector(grid.x.clone())),
+        ("y".to_string(), Array::vector(grid.y.clone())),
+        ("z".to_string(), Array::from_rows(&grid.z, grid.x.len())),
+    ]
+}
+
+/// This function returns the splits of a dataset as the arrays `x_train`, `y_train`,
+/// `x_validation`, `y_validation`, `x_test` and `y_test`
+pub fn dataset_arrays(dataset: &Dataset) -> Vec<(String, Array)> {
+    let d = dataset.train.x.first().map_or(0, |x| x.len());
+    [("train", &dataset.train), ("validation", &dataset.validation), ("test", &dataset.test)].iter()
+        .flat_map(|(name, split)| vec![
+            (format!("x_{}", name), Array::from_rows(&split.x, d)),
+            (format!("y_{}", name), Array::vector(split.y.clone())),
+        ])
+        .collect()
+}
+
+/// This function returns the bytes of an array in the NPY format, version 1.0
+fn npy(array: &Array) -> Vec<u8> {
+    let shape = match array.shape.len() {
+        1 => format!("({},)", array.shape[0]),
+        _ => format!("({})", array.shape.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")),
+    };
+    let mut header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}", shape);
+    // The header is padded with spaces and ends with a newline, so that the data is aligned to 64 bytes
+    let total = (10 + header.len() + 1).next_multiple_of(64);
+    header += &" ".repeat(total - 10 - header.len() - 1);
+    header += "\n";
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for x in array.data.iter() {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes
+}
+
+/// This function writes an array in the NPY format
+pub fn write_npy<W: Write>(w: &mut W, array: &Array) -> Result<()> {
+    w.write_all(&npy(array))
+}
+
+/// This function computes the CRC-32 checksum used by PNG and ZIP
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// This function writes named arrays as an NPZ archive, which is an uncompressed ZIP file with an
+/// NPY file for each array
+pub fn write_npz<W: Write>(w: &mut W, arrays: &[(String, Array)]) -> Result<()> {
+    // The date of every file is 1980-01-01, the earliest date ZIP can represent
+    let date: u16 = (1 << 5) | 1;
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+    for (name, array) in arrays.iter() {
+        let name = format!("{}.npy", name);
+        let data = npy(array);
+        let offset = archive.len() as u32;
+        let common = [
+            &20u16.to_le_bytes()[..], &0u16.to_le_bytes(), &0u16.to_le_bytes(), &0u16.to_le_bytes(), &date.to_le_bytes(),
+            &crc32(&data).to_le_bytes(), &(data.len() as u32).to_le_bytes(), &(data.len() as u32).to_le_bytes(),
+            &(name.len() as u16).to_le_bytes(), &0u16.to_le_bytes(),
+        ].concat();
+        archive.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        archive.extend_from_slice(&common);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(&data);
+        directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        directory.extend_from_slice(&20u16.to_le_bytes());
+        directory.extend_from_slice(&common);
+        directory.extend_from_slice(&[0; 10]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+    let directory_offset = archive.len() as u32;
+    archive.extend_from_slice(&directory);
+    archive.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    archive.extend_from_slice(&[0; 4]);
+    archive.extend_from_slice(&(arrays.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(arrays.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+    archive.extend_from_slice(&directory_offset.to_le_bytes());
+    archive.extend_from_slice(&[0; 2]);
+    w.write_all(&archive)
+}
+
+/// This function returns a MAT-file data element, which is a tag followed by data padded to 8 bytes
+fn mat_element(data_type: u32, data: &[u8]) -> Vec<u8> {
+    let mut element = data_type.to_le_bytes().to_vec();
+    element.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    element.extend_from_slice(data);
+    element.resize(element.len().next_multiple_of(8), 0);
+    element
+}
+
+/// This function writes named arrays as variables of a MATLAB level 5 MAT-file. One-dimensional
+/// arrays become row vectors, and names must be valid MATLAB variable names.
+pub fn write_mat<W: Write>(w: &mut W, arrays: &[(String, Array)]) -> Result<()> {
+    const MI_INT8: u32 = 1;
+    const MI_INT32: u32 = 5;
+    const MI_UINT32: u32 = 6;
+    const MI_DOUBLE: u32 = 9;
+    const MI_MATRIX: u32 = 14;
+    const MX_DOUBLE_CLASS: u32 = 6;
+
+    let mut text = format!("MATLAB 5.0 MAT-file, Platform: {}, Created by: benchfun", std::env::consts::OS).into_bytes();
+    text.resize(116, b' ');
+    let mut file = text;
+    file.extend_from_slice(&[0; 8]);
+    file.extend_from_slice(&0x0100u16.to_le_bytes());
+    file.extend_from_slice(b"IM");
+    for (name, array) in arrays.iter() {
+        assert!(name.chars().next().is_some_and(|c| c.is_ascii_alphabetic()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'), "{} is not a valid MATLAB variable name.", name);
+        let dimensions: Vec<i32> = match array.shape.len() {
+            0 => vec![1, 1],
+            1 => vec![1, array.shape[0] as i32],
+            _ => array.shape.iter().map(|n| *n as i32).collect(),
+        };
+        let matrix = [
+            mat_element(MI_UINT32, &[MX_DOUBLE_CLASS.to_le_bytes(), 0u32.to_le_bytes()].concat()),
+            mat_element(MI_INT32, &dimensions.iter().flat_map(|n| n.to_le_bytes()).collect::<Vec<u8>>()),
+            mat_element(MI_INT8, name.as_bytes()),
+            mat_element(MI_DOUBLE, &array.column_major().iter().flat_map(|x| x.to_le_bytes()).collect::<Vec<u8>>()),
+        ].concat();
+        file.extend_from_slice(&mat_element(MI_MATRIX, &matrix));
+    }
+    w.write_all(&file)
+}
+
+#[cfg(test)]
+mod arrays_tests {
+    use std::convert::TryInto;
+    use super::{Array, crc32, npy, write_npz, write_mat, grid_arrays, dataset_arrays};
+    use crate::dataset::DatasetConfig;
+    use crate::grid::grid_evaluate;
+    use crate::Booth;
+
+    #[test]
+    fn layout() {
+        let array = Array::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]], 3);
+        assert_eq!(array.shape, vec![2, 3]);
+        assert_eq!(array.column_major(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(Array::from_rows(&[], 4).shape, vec![0, 4]);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn npy_format() {
+        let bytes = npy(&Array::vector(vec![1.5, -2.0]));
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header_length = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_length) % 64, 0);
+        let header = std::str::from_utf8(&bytes[10..10 + header_length]).unwrap();
+        assert!(header.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }") && header.ends_with(" \n"));
+        assert_eq!(&bytes[10 + header_length..], &[1.5f64.to_le_bytes(), (-2.0f64).to_le_bytes()].concat()[..]);
+    }
+
+    #[test]
+    fn npz_format() {
+        let arrays = grid_arrays(&grid_evaluate::<Booth>(4));
+        assert_eq!(arrays[2].1.shape, vec![4, 4]);
+        let mut archive = Vec::new();
+        write_npz(&mut archive, &arrays).unwrap();
+        assert_eq!(&archive[..4], &[0x50, 0x4b, 0x03, 0x04]);
+        let end = archive.len() - 22;
+        assert_eq!(&archive[end..end + 4], &[0x50, 0x4b, 0x05, 0x06]);
+        assert_eq!(u16::from_le_bytes([archive[end + 10], archive[end + 11]]), 3);
+        let directory = u32::from_le_bytes(archive[end + 16..end + 20].try_into().unwrap()) as usize;
+        assert_eq!(&archive[directory..directory + 4], &[0x50, 0x4b, 0x01, 0x02]);
+        assert_eq!(&archive[directory + 46..directory + 51], b"x.npy");
+        // The first entry's data follows its 30-byte local header and its name
+        let data = npy(&arrays[0].1);
+        assert_eq!(&archive[30..35], b"x.npy");
+        assert_eq!(&archive[35..35 + data.len()], &data[..]);
+        assert_eq!(u32::from_le_bytes(archive[14..18].try_into().unwrap()), crc32(&data));
+    }
+
+    #[test]
+    fn mat_format() {
+        let mut mat = Vec::new();
+        write_mat(&mut mat, &[("z".to_string(), Array::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]], 2))]).unwrap();
+        assert!(mat.starts_with(b"MATLAB 5.0 MAT-file"));
+        assert_eq!(&mat[124..128], &[0x00, 0x01, b'I', b'M']);
+        let element = &mat[128..];
+        assert_eq!(u32::from_le_bytes(element[..4].try_into().unwrap()), 14);
+        assert_eq!(u32::from_le_bytes(element[4..8].try_into().unwrap()) as usize, element.len() - 8);
+        // Flags, dimensions and name each take 16 bytes, followed by the tag of the values
+        assert_eq!(&element[24..32], &[5, 0, 0, 0, 8, 0, 0, 0]);
+        assert_eq!(&element[32..40], &[3, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(element[48], b'z');
+        let values: Vec<f64> = element[64..].chunks(8).map(|c| f64::from_le_bytes(c.try_into().unwrap())).collect();
+        assert_eq!(values, vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn datasets() {
+        let dataset = DatasetConfig { n_points: 20, ..Default::default() }.generate::<Booth>();
+        let arrays = dataset_arrays(&dataset);
+        assert_eq!(arrays.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), vec!["x_train", "y_train", "x_validation", "y_validation", "x_test", "y_test"]);
+        assert_eq!(arrays[0].1.shape, vec![dataset.train.x.len(), 2]);
+        let mut mat = Vec::new();
+        write_mat(&mut mat, &arrays).unwrap();
+    }
+}
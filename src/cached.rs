@@ -0,0 +1,204 @@
+//! This module contains a wrapper that caches the evaluations of a benchmark function
+//!
+//! Optimizers that revisit points, such as pattern searches or genetic algorithms with duplicate
+//! individuals, can avoid paying for repeated evaluations. Inputs are quantized before they are
+//! looked up, so that points that differ only by rounding error share an entry.
+//!
+//! ```
+//! use benchfun::Whitley;
+//! use benchfun::cached::Cached;
+//!
+//! let cached = Cached::<Whitley>::new(1e-9);
+//! cached.f(vec![0.5, 0.5]);
+//! cached.f(vec![0.5, 0.5 + 1e-12]);
+//! assert_eq!((cached.stats().hits, cached.stats().misses), (1, 1));
+//! ```
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{SingleObjective, Parameterized};
+
+/// This struct holds a snapshot of cache statistics
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of evaluations answered from the cache
+    pub hits: usize,
+    /// The number of evaluations passed on to the function
+    pub misses: usize,
+    /// The number of entries in the cache
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// This function returns the fraction of evaluations answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        if self.hits + self.misses == 0 {
+            0.0
+        } else {
+            self.hits as f64/(self.hits + self.misses) as f64
+        }
+    }
+}
+
+/// This is a wrapper around a benchmark function that caches its values.
+///
+/// Each coordinate is rounded to the nearest multiple of `quantum` to form the cache key. A
+/// `quantum` of zero only matches points that are exactly equal. Points with a coordinate that is
+/// NaN or infinite, or too large to quantize, bypass the cache and are always evaluated. The cache
+/// is shared between threads.
+#[derive(Debug)]
+pub struct Cached<F> {
+    quantum: f64,
+    cache: Mutex<HashMap<Vec<u64>, f64>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    function: PhantomData<fn() -> F>,
+}
+
+impl<F> Cached<F> {
+    /// This function creates an empty cache with a given quantization step
+    pub fn new(quantum: f64) -> Cached<F> {
+        if quantum.is_nan() || quantum < 0.0 {
+            panic!("The quantization step must be non-negative, but it is {}.", quantum);
+        }
+        Cached {
+            quantum,
+            cache: Mutex::new(HashMap::new()),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            function: PhantomData,
+        }
+    }
+
+    /// This function returns the cache key of a point, or `None` if the point cannot be cached
+    fn key(&self, x: &[f64]) -> Option<Vec<u64>> {
+        x.iter()
+            .map(|xi| {
+                let step = if self.quantum > 0.0 { (xi/self.quantum).round() } else { *xi };
+                if step.is_finite() {
+                    Some((step + 0.0).to_bits())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// This function returns a snapshot of the cache statistics
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.cache.lock().unwrap().len(),
+        }
+    }
+
+    /// This function empties the cache and sets the statistics back to zero
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<F> Default for Cached<F> {
+    fn default() -> Self {
+        Cached::new(0.0)
+    }
+}
+
+impl<F: SingleObjective> Cached<F> {
+    /// Function for evaluating, which only calls the wrapped function on a cache miss
+    pub fn f(&self, x: Vec<f64>) -> f64 {
+        let key = self.key(&x);
+        if let Some(fx) = key.as_ref().and_then(|key| self.cache.lock().unwrap().get(key).copied()) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return fx;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let fx = F::f(x);
+        if let Some(key) = key {
+            self.cache.lock().unwrap().insert(key, fx);
+        }
+        fx
+    }
+}
+
+impl<F: SingleObjective> Parameterized for Cached<F> {
+    /// Function for evaluating through the cache
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        self.f(x)
+    }
+
+    /// The global minimum is that of the wrapped function
+    fn global_minimum(&self) -> Option<f64> {
+        Some(F::MINIMUM)
+    }
+
+    /// The minimizer is that of the wrapped function
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        Some(F::minimizer(n))
+    }
+}
+
+#[cfg(test)]
+mod cached_tests {
+    use super::{Cached, CacheStats};
+    use crate::{SingleObjective, Parameterized, Rastrigin, Sphere};
+
+    #[test]
+    fn exact() {
+        let cached = Cached::<Rastrigin>::default();
+        assert_eq!(cached.f(vec![0.5, 1.0]), Rastrigin::f(vec![0.5, 1.0]));
+        assert_eq!(cached.f(vec![0.5, 1.0]), Rastrigin::f(vec![0.5, 1.0]));
+        cached.f(vec![0.5, 1.0 + 1e-15]);
+        cached.f(vec![-0.0, 0.0]);
+        cached.f(vec![0.0, 0.0]);
+        assert_eq!(cached.stats(), CacheStats { hits: 2, misses: 3, entries: 3 });
+        assert_eq!(cached.stats().hit_rate(), 0.4);
+        cached.clear();
+        assert_eq!(cached.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn quantized() {
+        let cached = Cached::<Rastrigin>::new(1e-6);
+        cached.f(vec![0.1, 0.2]);
+        cached.evaluate(vec![0.1 + 1e-9, 0.2 - 1e-9]);
+        cached.f(vec![0.1 + 1e-5, 0.2]);
+        assert_eq!(cached.stats(), CacheStats { hits: 1, misses: 2, entries: 2 });
+        cached.check_global_minimizer(3);
+    }
+
+    #[test]
+    fn non_finite() {
+        let cached = Cached::<Sphere>::new(1e-9);
+        assert_eq!(cached.f(vec![0.0]), 0.0);
+        assert!(cached.f(vec![f64::NAN]).is_nan());
+        assert_eq!(cached.f(vec![f64::INFINITY]), f64::INFINITY);
+        assert_eq!(cached.f(vec![1e300]), Sphere::f(vec![1e300]));
+        assert_eq!(cached.f(vec![2e300]), Sphere::f(vec![2e300]));
+        assert_eq!(cached.stats(), CacheStats { hits: 0, misses: 5, entries: 1 });
+        let exact = Cached::<Sphere>::default();
+        assert!(exact.f(vec![f64::NAN]).is_nan());
+        assert_eq!(exact.stats().entries, 0);
+    }
+
+    #[test]
+    fn large() {
+        let cached = Cached::<Sphere>::new(1e-9);
+        assert_eq!(cached.f(vec![1e10]), 1e20);
+        assert_eq!(cached.f(vec![3e10]), 9e20);
+        assert_eq!(cached.f(vec![-1e10]), 1e20);
+        assert_eq!(cached.stats(), CacheStats { hits: 0, misses: 3, entries: 3 });
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_quantum() {
+        Cached::<Rastrigin>::new(-1.0);
+    }
+}
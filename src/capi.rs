@@ -0,0 +1,239 @@
+//! This module contains a C interface to the registry of single-objective functions. It is only
+//! available with the `capi` feature. The crate is built as a Rust library by default, so a C
+//! dynamic library is built with `cargo rustc --release --features capi --crate-type cdylib`.
+//!
+//! Functions are identified by their index in the registry, which can be looked up by name. Every
+//! function returns a status code, with results written through pointers supplied by the caller,
+//! and panics never cross the interface. The declarations are in `include/benchfun.h`.
+//!
+//! ```
+//! use benchfun::capi::*;
+//! use std::ffi::CString;
+//!
+//! let name = CString::new("Booth").unwrap();
+//! let mut f = 0.0;
+//! unsafe {
+//!     let id = benchfun_id(name.as_ptr());
+//!     assert_eq!(benchfun_evaluate(id, [1.0, 3.0].as_ptr(), 2, &mut f), BENCHFUN_OK);
+//! }
+//! assert_eq!(f, 0.0);
+//! ```
+
+use std::convert::TryFrom;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::registry::{functions, index, Function};
+
+/// The status of a successful call
+pub const BENCHFUN_OK: c_int = 0;
+/// The status of a call with an unknown function id or name
+pub const BENCHFUN_UNKNOWN_FUNCTION: c_int = -1;
+/// The status of a call with a dimensionality the function does not support
+pub const BENCHFUN_UNSUPPORTED_DIMENSION: c_int = -2;
+/// The status of a call with a null pointer or a buffer that is too small
+pub const BENCHFUN_INVALID_ARGUMENT: c_int = -3;
+/// The status of a call that failed inside the function
+pub const BENCHFUN_INTERNAL_ERROR: c_int = -4;
+
+/// This function looks up a function by id
+pub(crate) fn function(id: c_int) -> Option<&'static Function> {
+    usize::try_from(id).ok().and_then(|i| functions().get(i))
+}
+
+/// This function looks up a function by id and checks the dimensionality
+fn lookup(id: c_int, d: usize) -> Result<&'static Function, c_int> {
+    let function = function(id).ok_or(BENCHFUN_UNKNOWN_FUNCTION)?;
+    if function.supports(d) { Ok(function) } else { Err(BENCHFUN_UNSUPPORTED_DIMENSION) }
+}
+
+/// This function runs a call, converting errors and panics into status codes
+fn status<G: FnOnce() -> Result<(), c_int>>(g: G) -> c_int {
+    match catch_unwind(AssertUnwindSafe(g)) {
+        Ok(Ok(())) => BENCHFUN_OK,
+        Ok(Err(code)) => code,
+        Err(_) => BENCHFUN_INTERNAL_ERROR,
+    }
+}
+
+/// This function returns the number of functions in the registry
+#[no_mangle]
+pub extern "C" fn benchfun_count() -> c_int {
+    functions().len() as c_int
+}
+
+/// This function returns the id of a function from its NUL-terminated name, or
+/// `BENCHFUN_UNKNOWN_FUNCTION` if there is no such function
+///
+/// # Safety
+/// `name` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_id(name: *const c_char) -> c_int {
+    if name.is_null() {
+        return BENCHFUN_UNKNOWN_FUNCTION;
+    }
+    CStr::from_ptr(name).to_str().ok().and_then(index).map_or(BENCHFUN_UNKNOWN_FUNCTION, |i| i as c_int)
+}
+
+/// This function copies the NUL-terminated name of a function into a buffer of `capacity` bytes
+///
+/// # Safety
+/// `buffer` must be null or point to at least `capacity` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_name(id: c_int, buffer: *mut c_char, capacity: usize) -> c_int {
+    let function = match function(id) {
+        Some(function) => function,
+        None => return BENCHFUN_UNKNOWN_FUNCTION,
+    };
+    if buffer.is_null() || capacity <= function.name.len() {
+        return BENCHFUN_INVALID_ARGUMENT;
+    }
+    std::ptr::copy_nonoverlapping(function.name.as_ptr() as *const c_char, buffer, function.name.len());
+    *buffer.add(function.name.len()) = 0;
+    BENCHFUN_OK
+}
+
+/// This function writes the dimensionality of a function, which is 0 for functions that accept
+/// any number of dimensions
+///
+/// # Safety
+/// `dimension` must be null or point to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_dimension(id: c_int, dimension: *mut usize) -> c_int {
+    match function(id) {
+        None => BENCHFUN_UNKNOWN_FUNCTION,
+        Some(_) if dimension.is_null() => BENCHFUN_INVALID_ARGUMENT,
+        Some(function) => {
+            *dimension = function.dimension.unwrap_or(0);
+            BENCHFUN_OK
+        }
+    }
+}
+
+/// This function evaluates a function at a point with `d` elements
+///
+/// # Safety
+/// `x` must point to `d` readable doubles and `f` to a writable double, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_evaluate(id: c_int, x: *const f64, d: usize, f: *mut f64) -> c_int {
+    status(|| {
+        let function = lookup(id, d)?;
+        if x.is_null() || f.is_null() {
+            return Err(BENCHFUN_INVALID_ARGUMENT);
+        }
+        *f = (function.f)(std::slice::from_raw_parts(x, d).to_vec());
+        Ok(())
+    })
+}
+
+/// This function evaluates a function, given by its NUL-terminated name, at a point with `d` elements
+///
+/// # Safety
+/// `name` must be null or point to a NUL-terminated string, `x` must point to `d` readable
+/// doubles and `f` to a writable double, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_evaluate_by_name(name: *const c_char, x: *const f64, d: usize, f: *mut f64) -> c_int {
+    benchfun_evaluate(benchfun_id(name), x, d, f)
+}
+
+/// This function writes the lower and upper bounds of each of `d` dimensions of a function, which
+/// are infinite for unbounded functions
+///
+/// # Safety
+/// `lower` and `upper` must each point to `d` writable doubles, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_bounds(id: c_int, d: usize, lower: *mut f64, upper: *mut f64) -> c_int {
+    status(|| {
+        let function = lookup(id, d)?;
+        if lower.is_null() || upper.is_null() {
+            return Err(BENCHFUN_INVALID_ARGUMENT);
+        }
+        for (i, (low, high)) in (function.bounds)(d).into_iter().enumerate() {
+            *lower.add(i) = low;
+            *upper.add(i) = high;
+        }
+        Ok(())
+    })
+}
+
+/// This function writes the global minimum of a function at a dimensionality
+///
+/// # Safety
+/// `minimum` must point to a writable double, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_minimum(id: c_int, d: usize, minimum: *mut f64) -> c_int {
+    status(|| {
+        let function = lookup(id, d)?;
+        if minimum.is_null() {
+            return Err(BENCHFUN_INVALID_ARGUMENT);
+        }
+        *minimum = (function.minimum)(d);
+        Ok(())
+    })
+}
+
+/// This function writes the global minimizer of a function at a dimensionality
+///
+/// # Safety
+/// `minimizer` must point to `d` writable doubles, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_minimizer(id: c_int, d: usize, minimizer: *mut f64) -> c_int {
+    status(|| {
+        let function = lookup(id, d)?;
+        if minimizer.is_null() {
+            return Err(BENCHFUN_INVALID_ARGUMENT);
+        }
+        std::ptr::copy_nonoverlapping((function.minimizer)(d).as_ptr(), minimizer, d);
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod capi_tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::ptr::{null, null_mut};
+
+    #[test]
+    fn lookup() {
+        let name = CString::new("Hartmann3").unwrap();
+        let unknown = CString::new("Nonexistent").unwrap();
+        unsafe {
+            let id = benchfun_id(name.as_ptr());
+            assert!(id >= 0 && id < benchfun_count());
+            assert_eq!(benchfun_id(unknown.as_ptr()), BENCHFUN_UNKNOWN_FUNCTION);
+            assert_eq!(benchfun_id(null()), BENCHFUN_UNKNOWN_FUNCTION);
+            let mut buffer = [0 as c_char; 16];
+            assert_eq!(benchfun_name(id, buffer.as_mut_ptr(), buffer.len()), BENCHFUN_OK);
+            assert_eq!(CStr::from_ptr(buffer.as_ptr()).to_str().unwrap(), "Hartmann3");
+            assert_eq!(benchfun_name(id, buffer.as_mut_ptr(), 9), BENCHFUN_INVALID_ARGUMENT);
+            let mut d = 0;
+            assert_eq!(benchfun_dimension(id, &mut d), BENCHFUN_OK);
+            assert_eq!(d, 3);
+            assert_eq!(benchfun_dimension(benchfun_count(), &mut d), BENCHFUN_UNKNOWN_FUNCTION);
+        }
+    }
+
+    #[test]
+    fn evaluation() {
+        let name = CString::new("Rastrigin").unwrap();
+        unsafe {
+            let id = benchfun_id(name.as_ptr());
+            let mut minimizer = [1.0; 3];
+            let (mut f, mut minimum) = (1.0, 1.0);
+            assert_eq!(benchfun_minimizer(id, 3, minimizer.as_mut_ptr()), BENCHFUN_OK);
+            assert_eq!(benchfun_minimum(id, 3, &mut minimum), BENCHFUN_OK);
+            assert_eq!(benchfun_evaluate_by_name(name.as_ptr(), minimizer.as_ptr(), 3, &mut f), BENCHFUN_OK);
+            assert_eq!((f, minimum), (0.0, 0.0));
+            let (mut lower, mut upper) = ([0.0; 3], [0.0; 3]);
+            assert_eq!(benchfun_bounds(id, 3, lower.as_mut_ptr(), upper.as_mut_ptr()), BENCHFUN_OK);
+            assert_eq!((lower, upper), ([-5.12; 3], [5.12; 3]));
+            assert_eq!(benchfun_evaluate(id, null(), 3, &mut f), BENCHFUN_INVALID_ARGUMENT);
+            assert_eq!(benchfun_evaluate(id, minimizer.as_ptr(), 0, &mut f), BENCHFUN_UNSUPPORTED_DIMENSION);
+            assert_eq!(benchfun_evaluate(-1, minimizer.as_ptr(), 3, &mut f), BENCHFUN_UNKNOWN_FUNCTION);
+            let booth = CString::new("Booth").unwrap();
+            assert_eq!(benchfun_minimum(benchfun_id(booth.as_ptr()), 3, null_mut()), BENCHFUN_UNSUPPORTED_DIMENSION);
+        }
+    }
+}
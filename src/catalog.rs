@@ -0,0 +1,179 @@
+//! This module contains a machine-readable catalog of the single-objective functions
+//!
+//! The catalog lists every function of the registry with its id, dimensionality, properties and
+//! source, and describes it at each requested dimensionality with its bounds, global minimum and
+//! minimizer. Fixed-dimensional functions are only described at their own dimensionality. The
+//! catalog can be written as TOML, and as JSON with the `json` feature, so other frameworks can
+//! generate their problem lists from it. JSON has no infinities, so the bounds of unbounded
+//! functions are written as null there.
+//!
+//! ```
+//! use benchfun::catalog::{catalog, to_toml};
+//!
+//! let entries = catalog(&[2, 10]);
+//! assert_eq!(entries[0].name, "Sphere");
+//! assert_eq!(entries[0].instances.len(), 2);
+//! let toml = to_toml(&entries);
+//! assert!(toml.starts_with("[[function]]"));
+//! ```
+
+use std::fmt::Write;
+
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+use crate::problem::Descriptor;
+use crate::registry::{functions, Properties};
+
+/// This struct describes a function in the catalog
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The index of the function in the registry
+    pub id: usize,
+    /// The name of the function
+    pub name: String,
+    /// The dimensionality of fixed-dimensional functions, or `None` for N-dimensional functions
+    pub dimension: Option<usize>,
+    /// The properties of the function
+    pub properties: Properties,
+    /// The source the function is borrowed from
+    pub reference: String,
+    /// The description of the function at each dimensionality
+    pub instances: Vec<Descriptor>,
+}
+
+/// This function returns the catalog of every function, describing N-dimensional functions at each
+/// of a set of dimensionalities
+pub fn catalog(dims: &[usize]) -> Vec<Entry> {
+    functions().iter().enumerate()
+        .map(|(id, function)| {
+            let dims = match function.dimension {
+                Some(d) => vec![d],
+                None => dims.iter().cloned().filter(|d| function.supports(*d)).collect(),
+            };
+            Entry {
+                id,
+                name: function.name.to_string(),
+                dimension: function.dimension,
+                properties: function.properties,
+                reference: function.reference.to_string(),
+                instances: dims.into_iter().map(|d| function.descriptor(d)).collect(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Properties {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("smooth".to_string(), self.smooth.to_json()),
+            ("constrained".to_string(), self.constrained.to_json()),
+            ("stochastic".to_string(), self.stochastic.to_json()),
+            ("differentiable".to_string(), self.differentiable.to_json()),
+            ("multiple_minimizers".to_string(), self.multiple_minimizers.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Entry {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("id".to_string(), self.id.to_json()),
+            ("name".to_string(), self.name.to_json()),
+            ("dimension".to_string(), self.dimension.to_json()),
+            ("properties".to_string(), self.properties.to_json()),
+            ("reference".to_string(), self.reference.to_json()),
+            ("instances".to_string(), self.instances.to_json()),
+        ])
+    }
+}
+
+/// This function formats a number as a TOML float
+fn toml_float(x: f64) -> String {
+    if x.is_nan() {
+        "nan".to_string()
+    } else {
+        // Debug formatting always includes a decimal point or exponent, and writes infinities as inf
+        format!("{:?}", x)
+    }
+}
+
+/// This function formats a string as a TOML basic string
+fn toml_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// This function writes a catalog as TOML, with a `[[function]]` table for each function and a
+/// `[[function.instance]]` table for each dimensionality. Since TOML has no null, keys without a
+/// value are left out.
+pub fn to_toml(entries: &[Entry]) -> String {
+    let mut toml = String::new();
+    for entry in entries.iter() {
+        let p = entry.properties;
+        writeln!(toml, "[[function]]").unwrap();
+        writeln!(toml, "id = {}", entry.id).unwrap();
+        writeln!(toml, "name = {}", toml_string(&entry.name)).unwrap();
+        if let Some(d) = entry.dimension {
+            writeln!(toml, "dimension = {}", d).unwrap();
+        }
+        writeln!(toml, "reference = {}", toml_string(&entry.reference)).unwrap();
+        if let Some(smooth) = p.smooth {
+            writeln!(toml, "smooth = {}", smooth).unwrap();
+        }
+        writeln!(toml, "constrained = {}", p.constrained).unwrap();
+        writeln!(toml, "stochastic = {}", p.stochastic).unwrap();
+        writeln!(toml, "differentiable = {}", p.differentiable).unwrap();
+        writeln!(toml, "multiple_minimizers = {}", p.multiple_minimizers).unwrap();
+        for instance in entry.instances.iter() {
+            let bounds: Vec<String> = instance.bounds.iter().map(|(low, high)| format!("[{}, {}]", toml_float(*low), toml_float(*high))).collect();
+            let minimizer: Vec<String> = instance.minimizer.iter().map(|x| toml_float(*x)).collect();
+            writeln!(toml, "\n[[function.instance]]").unwrap();
+            writeln!(toml, "dimension = {}", instance.dimension).unwrap();
+            writeln!(toml, "bounds = [{}]", bounds.join(", ")).unwrap();
+            writeln!(toml, "minimum = {}", toml_float(instance.minimum)).unwrap();
+            writeln!(toml, "minimizer = [{}]", minimizer.join(", ")).unwrap();
+        }
+        writeln!(toml).unwrap();
+    }
+    toml
+}
+
+#[cfg(test)]
+mod catalog_tests {
+    use super::{catalog, to_toml, toml_float};
+    #[cfg(feature = "json")]
+    use crate::json::{Json, ToJson};
+
+    #[test]
+    fn entries() {
+        let entries = catalog(&[1, 3]);
+        assert_eq!(entries.len(), 70);
+        let booth = entries.iter().find(|e| e.name == "Booth").unwrap();
+        assert_eq!(booth.instances.iter().map(|i| i.dimension).collect::<Vec<_>>(), vec![2]);
+        let ackley = entries.iter().find(|e| e.name == "Ackley").unwrap();
+        assert_eq!(ackley.instances.iter().map(|i| i.dimension).collect::<Vec<_>>(), vec![1, 3]);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let entries = catalog(&[1, 3]);
+        let ackley = entries.iter().find(|e| e.name == "Ackley").unwrap();
+        let json: Json = entries.to_json().to_string().parse().unwrap();
+        match json {
+            Json::Array(items) => assert_eq!(items[ackley.id].field::<String>("name").unwrap(), "Ackley"),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn toml() {
+        assert_eq!((toml_float(1.0), toml_float(-5.12), toml_float(1e-8)), ("1.0".to_string(), "-5.12".to_string(), "1e-8".to_string()));
+        assert_eq!((toml_float(f64::NEG_INFINITY), toml_float(f64::NAN)), ("-inf".to_string(), "nan".to_string()));
+        let toml = to_toml(&catalog(&[2])[..1]);
+        assert_eq!(toml, "[[function]]\nid = 0\nname = \"Sphere\"\nreference = \"https://en.wikipedia.org/wiki/Test_functions_for_optimization\"\n\
+            constrained = false\nstochastic = false\ndifferentiable = false\nmultiple_minimizers = false\n\n\
+            [[function.instance]]\ndimension = 2\nbounds = [[-inf, inf], [-inf, inf]]\nminimum = 0.0\nminimizer = [0.0, 0.0]\n\n");
+    }
+}
@@ -0,0 +1,94 @@
+//! This module contains the error returned by checked evaluations of the functions
+//!
+//! The `f` functions return NaN or an infinity when they are evaluated outside of their domain, such
+//! as the logarithm of a negative coordinate, and optimizers that compare these values can silently
+//! go wrong. The `f_checked` functions of `SingleObjective`, `MultiObjective` and registry functions
+//! return a `NonFiniteError` with the offending point instead.
+//!
+//! ```
+//! use benchfun::{SingleObjective, Vincent};
+//!
+//! assert_eq!(Vincent::f_checked(vec![1.0, 1.0]), Ok(Vincent::f(vec![1.0, 1.0])));
+//! let error = Vincent::f_checked(vec![1.0, -1.0]).unwrap_err();
+//! assert_eq!(error.x, vec![1.0, -1.0]);
+//! assert!(error.values[0].is_nan() && error.non_finite_inputs.is_empty());
+//! ```
+
+use std::fmt;
+
+/// This is an error reporting that a function evaluated to NaN or an infinity
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonFiniteError {
+    /// The point where the function was evaluated
+    pub x: Vec<f64>,
+    /// The indices of the coordinates of the point that are NaN or infinite themselves
+    pub non_finite_inputs: Vec<usize>,
+    /// The values of the function, with one for each objective
+    pub values: Vec<f64>,
+}
+
+impl NonFiniteError {
+    /// This function checks the values of a function at a point, and returns an error if any of them
+    /// is NaN or infinite
+    pub fn check(x: &[f64], values: &[f64]) -> Result<(), NonFiniteError> {
+        if values.iter().all(|value| value.is_finite()) {
+            return Ok(());
+        }
+        Err(NonFiniteError {
+            x: x.to_vec(),
+            non_finite_inputs: x.iter().enumerate().filter(|(_, xi)| !xi.is_finite()).map(|(i, _)| i).collect(),
+            values: values.to_vec(),
+        })
+    }
+}
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The function evaluated to {:?} at {:?}", self.values, self.x)?;
+        if !self.non_finite_inputs.is_empty() {
+            write!(f, ", where the coordinates {:?} are not finite", self.non_finite_inputs)?;
+        }
+        write!(f, ".")
+    }
+}
+
+impl std::error::Error for NonFiniteError {}
+
+#[cfg(test)]
+mod checked_tests {
+    use super::NonFiniteError;
+    use crate::{SingleObjective, MultiObjective, Sphere, Rastrigin, Vincent, FonsecaFlemming};
+    use crate::registry::find;
+
+    #[test]
+    fn single() {
+        assert_eq!(Sphere::f_checked(vec![1.0, 2.0]), Ok(5.0));
+        let error = Sphere::f_checked(vec![1.0, f64::INFINITY]).unwrap_err();
+        assert_eq!((error.non_finite_inputs, error.values), (vec![1], vec![f64::INFINITY]));
+        let error = Rastrigin::f_checked(vec![f64::NAN, 0.0, f64::NEG_INFINITY]).unwrap_err();
+        assert_eq!(error.non_finite_inputs, vec![0, 2]);
+        assert!(Sphere::f_checked(vec![1e200, 0.0]).is_err());
+        assert!(Vincent::f_checked(vec![0.0]).is_err());
+    }
+
+    #[test]
+    fn multi() {
+        assert_eq!(FonsecaFlemming::f_checked(vec![0.0; 3]), Ok(FonsecaFlemming::f(vec![0.0; 3])));
+        assert_eq!(FonsecaFlemming::f_checked(vec![0.0, f64::NAN]).unwrap_err().values.len(), FonsecaFlemming::NF);
+    }
+
+    #[test]
+    fn registry() {
+        let vincent = find("Vincent").unwrap();
+        assert_eq!(vincent.f_checked(vec![1.0]), Ok(Vincent::f(vec![1.0])));
+        assert_eq!(vincent.f_checked(vec![-1.0]).unwrap_err().x, vec![-1.0]);
+    }
+
+    #[test]
+    fn display() {
+        let error = NonFiniteError::check(&[f64::NAN, 1.0], &[f64::NAN]).unwrap_err();
+        assert_eq!(error.to_string(), "The function evaluated to [NaN] at [NaN, 1.0], where the coordinates [0] are not finite.");
+        assert_eq!(NonFiniteError::check(&[1.0], &[f64::INFINITY]).unwrap_err().to_string(), "The function evaluated to [inf] at [1.0].");
+        assert_eq!(NonFiniteError::check(&[1.0], &[1.0, 2.0]), Ok(()));
+    }
+}
@@ -0,0 +1,457 @@
+//! This module contains nonparametric statistical tests for comparing optimizers
+//!
+//! The Wilcoxon signed-rank test compares two optimizers over a set of problems, and the Friedman
+//! test compares several at once by their average ranks. When the Friedman test rejects the
+//! hypothesis that all optimizers perform the same, the post-hoc Nemenyi critical difference and the
+//! Holm-corrected pairwise comparisons show which of them differ, following Demšar (2006). Smaller
+//! values are better throughout.
+//!
+//! ```
+//! use benchfun::{Ackley, Rastrigin};
+//! use benchfun::comparison::compare;
+//! use benchfun::harness::{Harness, Budget, problems, random_search};
+//!
+//! let mut suite = problems::<Ackley>(&[2, 3, 4]);
+//! suite.extend(problems::<Rastrigin>(&[2, 3, 4]));
+//! let harness = Harness { budget: Budget::PerDimension(20), repetitions: 3, ..Harness::new(suite) };
+//! let short = Harness { budget: Budget::PerDimension(2), ..harness.clone() };
+//! let results = [harness.run("random search", &mut random_search), short.run("short random search", &mut random_search)];
+//! let comparison = compare(&results, 0.05);
+//! println!("Friedman p-value: {}", comparison.friedman.p_value);
+//! for pair in comparison.pairwise.iter() {
+//!     println!("{} vs {}: adjusted p-value {}", comparison.solvers[pair.a], comparison.solvers[pair.b], pair.adjusted_p_value);
+//! }
+//! ```
+
+use crate::harness::Results;
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+
+/// This constant is the smallest magnitude allowed in the continued fractions
+const TINY: f64 = 1e-300;
+
+/// This function returns the natural logarithm of the gamma function, using the Lanczos approximation
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [76.180_091_729_471_46, -86.505_320_329_416_77, 24.014_098_240_830_91, -1.231_739_572_450_155, 0.001_208_650_973_866_179, -0.000_005_395_239_384_953];
+    let mut series = 1.000_000_000_190_015;
+    for (j, c) in COEFFICIENTS.iter().enumerate() {
+        series += c/(x + 1.0 + j as f64);
+    }
+    let t = x + 5.5;
+    (x + 0.5)*t.ln() - t + (2.506_628_274_631_000_5*series/x).ln()
+}
+
+/// This function returns the regularized upper incomplete gamma function Q(a, x)
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefactor = (-x + a*x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let mut term = 1.0/a;
+        let mut sum = term;
+        for n in 1..1000 {
+            term *= x/(a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs()*1e-15 {
+                break;
+            }
+        }
+        (1.0 - sum*prefactor).max(0.0)
+    } else {
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0/TINY;
+        let mut d = 1.0/b;
+        let mut h = d;
+        for n in 1..1000 {
+            let an = -(n as f64)*(n as f64 - a);
+            b += 2.0;
+            d = an*d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an/c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0/d;
+            h *= d*c;
+            if (d*c - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+        prefactor*h
+    }
+}
+
+/// This function evaluates the continued fraction of the incomplete beta function
+fn beta_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let clamp = |v: f64| if v.abs() < TINY { TINY } else { v };
+    let mut c = 1.0;
+    let mut d = 1.0/clamp(1.0 - (a + b)*x/(a + 1.0));
+    let mut h = d;
+    for m in 1..1000 {
+        let m = m as f64;
+        let even = m*(b - m)*x/((a - 1.0 + 2.0*m)*(a + 2.0*m));
+        d = 1.0/clamp(1.0 + even*d);
+        c = clamp(1.0 + even/c);
+        h *= d*c;
+        let odd = -(a + m)*(a + b + m)*x/((a + 2.0*m)*(a + 1.0 + 2.0*m));
+        d = 1.0/clamp(1.0 + odd*d);
+        c = clamp(1.0 + odd/c);
+        h *= d*c;
+        if (d*c - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    h
+}
+
+/// This function returns the regularized incomplete beta function I_x(a, b)
+fn beta_i(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let prefactor = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a*x.ln() + b*(1.0 - x).ln()).exp();
+    if x < (a + 1.0)/(a + b + 2.0) {
+        prefactor*beta_fraction(a, b, x)/a
+    } else {
+        1.0 - prefactor*beta_fraction(b, a, 1.0 - x)/b
+    }
+}
+
+/// This function returns the cumulative distribution function of the standard normal distribution
+pub fn normal_cdf(z: f64) -> f64 {
+    // erfc(t) = Q(1/2, t^2) for t >= 0
+    let tail = 0.5*gamma_q(0.5, 0.5*z*z);
+    if z >= 0.0 { 1.0 - tail } else { tail }
+}
+
+/// This function returns the probability that a chi-squared variable with `k` degrees of freedom exceeds `x`
+pub fn chi_squared_sf(x: f64, k: f64) -> f64 {
+    gamma_q(0.5*k, 0.5*x)
+}
+
+/// This function returns the probability that an F variable with `d1` and `d2` degrees of freedom exceeds `x`
+pub fn f_sf(x: f64, d1: f64, d2: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    beta_i(0.5*d2, 0.5*d1, d2/(d2 + d1*x))
+}
+
+/// This function returns the ranks of a set of values, starting from 1, with tied values sharing
+/// the average of their ranks
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = 0.5*((start + 1) + end) as f64;
+        for i in order[start..end].iter() {
+            ranks[*i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// This struct holds the result of a Wilcoxon signed-rank test
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wilcoxon {
+    /// The sum of the ranks of the positive differences
+    pub positive_rank_sum: f64,
+    /// The sum of the ranks of the negative differences
+    pub negative_rank_sum: f64,
+    /// The number of nonzero differences
+    pub n: usize,
+    /// The two-sided p-value
+    pub p_value: f64,
+}
+
+/// This function performs a two-sided Wilcoxon signed-rank test of the paired differences `a - b`.
+/// Zero differences are dropped. The p-value is exact for up to 50 differences without ties, and
+/// otherwise uses the normal approximation with tie and continuity corrections.
+pub fn wilcoxon(a: &[f64], b: &[f64]) -> Wilcoxon {
+    assert_eq!(a.len(), b.len(), "The samples must be paired.");
+    let differences: Vec<f64> = a.iter().zip(b.iter()).map(|(a, b)| a - b).filter(|d| *d != 0.0).collect();
+    let n = differences.len();
+    let magnitudes: Vec<f64> = differences.iter().map(|d| d.abs()).collect();
+    let ranks = ranks(&magnitudes);
+    let positive_rank_sum: f64 = ranks.iter().zip(differences.iter()).filter(|(_, d)| **d > 0.0).map(|(r, _)| r).sum();
+    let negative_rank_sum = (n*(n + 1)) as f64/2.0 - positive_rank_sum;
+    let smaller = positive_rank_sum.min(negative_rank_sum);
+    let tied = ranks.iter().any(|r| r.fract() != 0.0);
+    let p_value = if n == 0 {
+        1.0
+    } else if n <= 50 && !tied {
+        // Count the subsets of the ranks 1..=n by their sum
+        let total = n*(n + 1)/2;
+        let mut counts = vec![0.0; total + 1];
+        counts[0] = 1.0;
+        for rank in 1..=n {
+            for s in (rank..=total).rev() {
+                counts[s] += counts[s - rank];
+            }
+        }
+        let below: f64 = counts[..=(smaller as usize)].iter().sum();
+        (2.0*below/2f64.powi(n as i32)).min(1.0)
+    } else {
+        let mut ties = 0.0;
+        let mut sorted = magnitudes.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        for group in sorted.chunk_by(|a, b| a == b) {
+            let t = group.len() as f64;
+            ties += t*t*t - t;
+        }
+        let nf = n as f64;
+        let mean = nf*(nf + 1.0)/4.0;
+        let sd = (nf*(nf + 1.0)*(2.0*nf + 1.0)/24.0 - ties/48.0).sqrt();
+        let z = ((smaller - mean).abs() - 0.5).max(0.0)/sd;
+        (2.0*(1.0 - normal_cdf(z))).min(1.0)
+    };
+    Wilcoxon { positive_rank_sum, negative_rank_sum, n, p_value }
+}
+
+/// This struct holds the result of a Friedman test
+#[derive(Clone, Debug, PartialEq)]
+pub struct Friedman {
+    /// The average rank of each treatment, where rank 1 is the smallest value
+    pub mean_ranks: Vec<f64>,
+    /// The Friedman chi-squared statistic
+    pub statistic: f64,
+    /// The p-value of the chi-squared statistic
+    pub p_value: f64,
+    /// The Iman–Davenport F statistic, which is less conservative than the chi-squared statistic
+    pub f_statistic: f64,
+    /// The p-value of the Iman–Davenport statistic
+    pub f_p_value: f64,
+}
+
+/// This function performs a Friedman test on a table of values, with a row for each block (such
+/// as a problem) and a column for each treatment (such as an optimizer)
+pub fn friedman(table: &[Vec<f64>]) -> Friedman {
+    let n = table.len();
+    let k = table.first().map_or(0, |row| row.len());
+    assert!(n > 0 && k > 1, "The table must have at least one row and two columns.");
+    assert!(table.iter().all(|row| row.len() == k), "Every row of the table must have the same length.");
+    let mut mean_ranks = vec![0.0; k];
+    for row in table.iter() {
+        for (total, rank) in mean_ranks.iter_mut().zip(ranks(row)) {
+            *total += rank/n as f64;
+        }
+    }
+    let (nf, kf) = (n as f64, k as f64);
+    let statistic = 12.0*nf/(kf*(kf + 1.0))*(mean_ranks.iter().map(|r| r*r).sum::<f64>() - kf*(kf + 1.0)*(kf + 1.0)/4.0);
+    let f_statistic = (nf - 1.0)*statistic/(nf*(kf - 1.0) - statistic);
+    let f_p_value = if f_statistic.is_finite() && f_statistic >= 0.0 { f_sf(f_statistic, kf - 1.0, (kf - 1.0)*(nf - 1.0)) } else { 0.0 };
+    Friedman { mean_ranks, statistic, p_value: chi_squared_sf(statistic, kf - 1.0), f_statistic, f_p_value }
+}
+
+/// This function returns the critical difference of the Nemenyi test, which two mean ranks must
+/// exceed to differ significantly, for `k` treatments and `n` blocks. Only significance levels of
+/// 0.05 and 0.10 and up to 10 treatments are supported.
+pub fn nemenyi_critical_difference(k: usize, n: usize, alpha: f64) -> Option<f64> {
+    const Q_05: [f64; 9] = [1.960, 2.343, 2.569, 2.728, 2.850, 2.949, 3.031, 3.102, 3.164];
+    const Q_10: [f64; 9] = [1.645, 2.052, 2.291, 2.459, 2.589, 2.693, 2.780, 2.855, 2.920];
+    let table = if alpha == 0.05 { Q_05 } else if alpha == 0.10 { Q_10 } else { return None };
+    let q = table.get(k.checked_sub(2)?)?;
+    Some(q*((k*(k + 1)) as f64/(6.0*n as f64)).sqrt())
+}
+
+/// This function returns the Holm-adjusted p-values of a family of hypotheses, in the same order
+pub fn holm(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|a, b| p_values[*a].total_cmp(&p_values[*b]));
+    let mut adjusted = vec![0.0; m];
+    let mut running: f64 = 0.0;
+    for (i, j) in order.into_iter().enumerate() {
+        running = running.max(((m - i) as f64*p_values[j]).min(1.0));
+        adjusted[j] = running;
+    }
+    adjusted
+}
+
+/// This struct holds a post-hoc comparison of two treatments
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pairwise {
+    /// The index of the first treatment
+    pub a: usize,
+    /// The index of the second treatment
+    pub b: usize,
+    /// The difference of the mean ranks of the treatments, `a` minus `b`
+    pub rank_difference: f64,
+    /// The z statistic of the difference
+    pub z: f64,
+    /// The unadjusted two-sided p-value
+    pub p_value: f64,
+    /// The Holm-adjusted p-value over all pairs
+    pub adjusted_p_value: f64,
+    /// Whether the mean ranks differ by more than the Nemenyi critical difference
+    pub nemenyi: Option<bool>,
+}
+
+/// This function compares every pair of treatments after a Friedman test on `n` blocks
+pub fn pairwise(friedman: &Friedman, n: usize, alpha: f64) -> Vec<Pairwise> {
+    let k = friedman.mean_ranks.len();
+    let se = ((k*(k + 1)) as f64/(6.0*n as f64)).sqrt();
+    let critical_difference = nemenyi_critical_difference(k, n, alpha);
+    let mut pairs: Vec<Pairwise> = Vec::new();
+    for a in 0..k {
+        for b in (a + 1)..k {
+            let rank_difference = friedman.mean_ranks[a] - friedman.mean_ranks[b];
+            let z = rank_difference/se;
+            pairs.push(Pairwise {
+                a,
+                b,
+                rank_difference,
+                z,
+                p_value: (2.0*(1.0 - normal_cdf(z.abs()))).min(1.0),
+                adjusted_p_value: 0.0,
+                nemenyi: critical_difference.map(|cd| rank_difference.abs() > cd),
+            });
+        }
+    }
+    let adjusted = holm(&pairs.iter().map(|p| p.p_value).collect::<Vec<_>>());
+    for (pair, p) in pairs.iter_mut().zip(adjusted) {
+        pair.adjusted_p_value = p;
+    }
+    pairs
+}
+
+/// This struct holds a statistical comparison of several optimizers
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// The names of the optimizers
+    pub solvers: Vec<String>,
+    /// The function and number of dimensions of each problem
+    pub problems: Vec<(String, usize)>,
+    /// The mean best value of each optimizer on each problem, indexed by problem and then optimizer
+    pub table: Vec<Vec<f64>>,
+    /// The Friedman test over the problems
+    pub friedman: Friedman,
+    /// The Nemenyi critical difference, if it is available for the significance level
+    pub critical_difference: Option<f64>,
+    /// The post-hoc comparison of every pair of optimizers
+    pub pairwise: Vec<Pairwise>,
+}
+
+/// This function returns the mean best value of each optimizer on each problem of the first set
+/// of results, as a table indexed by problem and then optimizer
+pub fn table(results: &[Results]) -> (Vec<(String, usize)>, Vec<Vec<f64>>) {
+    let summaries: Vec<_> = results.iter().map(|r| r.summary()).collect();
+    let problems: Vec<(String, usize)> = summaries.first().map_or(Vec::new(), |s| s.iter().map(|p| (p.problem.clone(), p.dimension)).collect());
+    let table = problems.iter()
+        .map(|(problem, dimension)| summaries.iter()
+            .map(|s| s.iter().find(|p| p.problem == *problem && p.dimension == *dimension).map_or(f64::INFINITY, |p| p.mean_best))
+            .collect())
+        .collect();
+    (problems, table)
+}
+
+/// This function compares the mean best values of several optimizers over the problems of a suite
+/// with a Friedman test and post-hoc pairwise comparisons at a significance level
+pub fn compare(results: &[Results], alpha: f64) -> Comparison {
+    let solvers = results.iter().map(|r| r.runs.first().map_or(String::new(), |run| run.solver.clone())).collect();
+    let (problems, table) = table(results);
+    let friedman = friedman(&table);
+    let critical_difference = nemenyi_critical_difference(results.len(), problems.len(), alpha);
+    let pairwise = pairwise(&friedman, problems.len(), alpha);
+    Comparison { solvers, problems, table, friedman, critical_difference, pairwise }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Comparison {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("solvers".to_string(), self.solvers.to_json()),
+            ("mean_ranks".to_string(), self.friedman.mean_ranks.to_json()),
+            ("friedman_statistic".to_string(), self.friedman.statistic.to_json()),
+            ("friedman_p_value".to_string(), self.friedman.p_value.to_json()),
+            ("critical_difference".to_string(), self.critical_difference.to_json()),
+            ("pairwise".to_string(), Json::Array(self.pairwise.iter()
+                .map(|p| Json::Object(vec![
+                    ("a".to_string(), self.solvers[p.a].to_json()),
+                    ("b".to_string(), self.solvers[p.b].to_json()),
+                    ("p_value".to_string(), p.p_value.to_json()),
+                    ("adjusted_p_value".to_string(), p.adjusted_p_value.to_json()),
+                ]))
+                .collect())),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod comparison_tests {
+    use super::{normal_cdf, chi_squared_sf, f_sf, ranks, wilcoxon, friedman, nemenyi_critical_difference, holm, pairwise, compare};
+    use crate::harness::{Harness, Budget, problems, random_search};
+    use crate::{Ackley, Booth, Rastrigin};
+
+    #[test]
+    fn distributions() {
+        assert!((normal_cdf(1.959_963_984_540_054) - 0.975).abs() < 1e-9);
+        assert!((normal_cdf(-1.0) - 0.158_655_253_931_457_05).abs() < 1e-9);
+        assert_eq!(normal_cdf(0.0), 0.5);
+        assert!((chi_squared_sf(3.841_458_820_694_124, 1.0) - 0.05).abs() < 1e-9);
+        assert!((chi_squared_sf(8.0, 2.0) - (-4f64).exp()).abs() < 1e-9);
+        assert!((f_sf(4.102_821_015_130_399, 2.0, 10.0) - 0.05).abs() < 1e-8);
+    }
+
+    #[test]
+    fn ranking() {
+        assert_eq!(ranks(&[3.0, 1.0, 3.0, 2.0]), vec![3.5, 1.0, 3.5, 2.0]);
+    }
+
+    #[test]
+    fn signed_rank() {
+        let test = wilcoxon(&[2.0, 3.0, 4.0, 5.0, 6.0, 1.0], &[1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!((test.n, test.positive_rank_sum, test.negative_rank_sum), (5, 15.0, 0.0));
+        assert_eq!(test.p_value, 0.0625);
+        let symmetric = wilcoxon(&[1.0, -2.0, 3.0, -4.0], &[0.0; 4]);
+        assert_eq!(symmetric.p_value, 0.875);
+        let tied = wilcoxon(&[1.0; 30], &[0.0; 30]);
+        assert!(tied.p_value < 1e-5);
+    }
+
+    #[test]
+    fn friedman_nemenyi_holm() {
+        let table = vec![vec![1.0, 2.0, 3.0]; 4];
+        let test = friedman(&table);
+        assert_eq!(test.mean_ranks, vec![1.0, 2.0, 3.0]);
+        assert!((test.statistic - 8.0).abs() < 1e-12);
+        assert!((test.p_value - (-4f64).exp()).abs() < 1e-9);
+        assert!(test.f_statistic.is_infinite());
+        assert!((nemenyi_critical_difference(3, 4, 0.05).unwrap() - 2.343*0.5f64.sqrt()).abs() < 1e-12);
+        assert_eq!(nemenyi_critical_difference(11, 4, 0.05), None);
+        assert_eq!(nemenyi_critical_difference(3, 4, 0.01), None);
+        let adjusted = holm(&[0.01, 0.04, 0.03]);
+        assert!(adjusted.iter().zip([0.03, 0.06, 0.06]).all(|(a, b)| (a - b).abs() < 1e-12));
+        let pairs = pairwise(&test, 4, 0.05);
+        assert_eq!(pairs.iter().map(|p| (p.a, p.b, p.nemenyi)).collect::<Vec<_>>(), vec![(0, 1, Some(false)), (0, 2, Some(true)), (1, 2, Some(false))]);
+        assert!(pairs[1].adjusted_p_value < 0.05 && pairs[0].adjusted_p_value > 0.05);
+    }
+
+    #[test]
+    fn harness() {
+        let mut suite = problems::<Ackley>(&[2, 3]);
+        suite.extend(problems::<Rastrigin>(&[2, 3]));
+        suite.extend(problems::<Booth>(&[2]));
+        let harness = Harness { budget: Budget::PerDimension(50), repetitions: 2, stop_at_target: false, ..Harness::new(suite) };
+        let short = Harness { budget: Budget::Fixed(1), ..harness.clone() };
+        let results = [harness.run("long", &mut random_search), short.run("short", &mut random_search)];
+        let comparison = compare(&results, 0.05);
+        assert_eq!(comparison.solvers, vec!["long".to_string(), "short".to_string()]);
+        assert_eq!(comparison.problems.len(), 5);
+        assert_eq!(comparison.friedman.mean_ranks, vec![1.0, 2.0]);
+        assert_eq!(comparison.pairwise.len(), 1);
+    }
+}
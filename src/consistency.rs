@@ -0,0 +1,140 @@
+//! This module contains a self-consistency check of the single-objective functions against tables
+//! of their values
+//!
+//! Each source is a table of values computed over a grid of points, in the format of the reference
+//! table, and written by `data/consistency/generate.py`. The only bundled table is generated from
+//! `data/reference.py`, the Python implementation of the functions that is maintained alongside
+//! this crate. It covers every function of the registry and catches regressions and transcription
+//! errors between the two, but it is not an independent cross-validation, because both were written
+//! from the same sources. No table from an external library is bundled. Where DEAP or pymoo are
+//! installed, the generator also writes tables from them, which can be read from files and checked
+//! the same way.
+//!
+//! ```
+//! use benchfun::Tolerance;
+//! use benchfun::consistency::{sources, compare};
+//!
+//! for (source, values) in sources() {
+//!     let report = compare(source, &values, Tolerance::new(1e-12, 1e-12));
+//!     assert!(report.passed(), "{:?}", report);
+//! }
+//! ```
+
+use std::path::Path;
+
+use crate::Tolerance;
+use crate::reference::{parse, Mismatch, ReferenceValue};
+use crate::registry::{find, functions};
+
+/// The bundled tables, with the name of their source
+const SOURCES: &[(&str, &str)] = &[
+    ("reference", include_str!("../data/consistency/reference.csv")),
+];
+
+/// This struct summarizes the comparison of the functions with a source
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// The name of the source
+    pub source: String,
+    /// The number of points that were compared
+    pub compared: usize,
+    /// The names in the source that are not in the registry
+    pub unknown: Vec<String>,
+    /// The points where a function differs from the source, with the name of the function
+    pub mismatches: Vec<(String, Mismatch)>,
+}
+
+impl Report {
+    /// This function checks that every function of the source is in the registry and agrees with it
+    pub fn passed(&self) -> bool {
+        self.unknown.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+/// This function returns the bundled sources with their values
+pub fn sources() -> Vec<(&'static str, Vec<ReferenceValue>)> {
+    SOURCES.iter()
+        .map(|(source, table)| (*source, parse(table).unwrap_or_else(|e| panic!("The {} table is invalid: {}", source, e))))
+        .collect()
+}
+
+/// This function reads the values of a source from a file
+pub fn read_source<P: AsRef<Path>>(path: P) -> Result<Vec<ReferenceValue>, String> {
+    parse(&std::fs::read_to_string(path).map_err(|e| e.to_string())?)
+}
+
+/// This function evaluates the functions of the registry at each point of a source, and reports the
+/// points where they are not within a tolerance of the values of the source
+pub fn compare(source: &str, values: &[ReferenceValue], tolerance: Tolerance) -> Report {
+    let mut report = Report { source: source.to_string(), compared: 0, unknown: vec![], mismatches: vec![] };
+    for value in values.iter() {
+        match find(&value.function) {
+            Some(function) => {
+                let actual = (function.f)(value.x.clone());
+                report.compared += 1;
+                if !tolerance.accepts(actual, value.f) {
+                    report.mismatches.push((value.function.clone(), Mismatch { x: value.x.clone(), expected: value.f, actual }));
+                }
+            }
+            None => if !report.unknown.contains(&value.function) {
+                report.unknown.push(value.function.clone());
+            },
+        }
+    }
+    report
+}
+
+/// This function returns the names of the functions of the registry that no bundled source covers
+pub fn uncovered() -> Vec<&'static str> {
+    let sources = sources();
+    functions().iter()
+        .map(|function| function.name)
+        .filter(|name| !sources.iter().any(|(_, values)| values.iter().any(|value| value.function == *name)))
+        .collect()
+}
+
+#[cfg(test)]
+mod consistency_tests {
+    use super::{sources, read_source, compare, uncovered};
+    use crate::Tolerance;
+    use crate::reference::ReferenceValue;
+    use crate::registry::functions;
+
+    #[test]
+    fn bundled() {
+        assert!(uncovered().is_empty(), "{:?} are not checked", uncovered());
+        for (source, values) in sources() {
+            let report = compare(source, &values, Tolerance::new(1e-12, 1e-12));
+            assert!(report.passed(), "{:?}", report);
+            assert_eq!(report.compared, values.len());
+        }
+    }
+
+    #[test]
+    fn grid() {
+        let (_, values) = sources().into_iter().find(|(source, _)| *source == "reference").unwrap();
+        for function in functions() {
+            let counts: Vec<usize> = function.dimension.map_or(vec![2, 5], |d| vec![d]).into_iter()
+                .map(|d| values.iter().filter(|value| value.function == function.name && value.x.len() == d).count())
+                .collect();
+            let expected: Vec<usize> = function.dimension.map_or(vec![25, 32], |d| vec![[5, 25, 27, 81, 32, 64][d - 1]]);
+            assert_eq!(counts, expected, "{}", function.name);
+        }
+    }
+
+    #[test]
+    fn report() {
+        let values = vec![
+            ReferenceValue { function: "Sphere".to_string(), x: vec![1.0, 2.0], f: 5.0 },
+            ReferenceValue { function: "Sphere".to_string(), x: vec![1.0, 1.0], f: 3.0 },
+            ReferenceValue { function: "Nonexistent".to_string(), x: vec![0.0], f: 0.0 },
+        ];
+        let report = compare("test", &values, Tolerance::default());
+        assert!(!report.passed());
+        assert_eq!((report.compared, report.unknown), (2, vec!["Nonexistent".to_string()]));
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!((report.mismatches[0].1.expected, report.mismatches[0].1.actual), (3.0, 2.0));
+        assert!(read_source("data/consistency/reference.csv").unwrap().len() > 2000);
+        assert!(read_source("data/consistency/nonexistent.csv").is_err());
+    }
+}
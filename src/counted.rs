@@ -0,0 +1,205 @@
+//! This module contains a wrapper that counts the evaluations of a benchmark function
+//!
+//! Optimizers are usually compared by the number of evaluations they need, not by wall time. The
+//! counters are atomic, so a single wrapper can be shared between threads that evaluate in parallel.
+//!
+//! ```
+//! use benchfun::Rosenbrock;
+//! use benchfun::counted::Counted;
+//!
+//! let counted = Counted::<Rosenbrock>::new();
+//! counted.f(vec![0.0, 0.0]);
+//! counted.f_batch(&[vec![1.0, 1.0], vec![2.0, 2.0]]);
+//! assert_eq!(counted.counts().objective, 3);
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{SingleObjective, MultiObjective, Constrained, Differentiable, Parameterized};
+
+/// This struct holds a snapshot of evaluation counts
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    /// The number of objective function evaluations
+    pub objective: usize,
+    /// The number of constraint evaluations
+    pub constraint: usize,
+    /// The number of gradient evaluations
+    pub gradient: usize,
+}
+
+/// This struct holds thread-safe evaluation counters
+#[derive(Debug, Default)]
+pub struct Counters {
+    objective: AtomicUsize,
+    constraint: AtomicUsize,
+    gradient: AtomicUsize,
+}
+
+impl Counters {
+    /// This function records objective function evaluations
+    pub fn add_objective(&self, n: usize) {
+        self.objective.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// This function records constraint evaluations
+    pub fn add_constraint(&self, n: usize) {
+        self.constraint.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// This function records gradient evaluations
+    pub fn add_gradient(&self, n: usize) {
+        self.gradient.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// This function returns a snapshot of the counts
+    pub fn counts(&self) -> Counts {
+        Counts {
+            objective: self.objective.load(Ordering::Relaxed),
+            constraint: self.constraint.load(Ordering::Relaxed),
+            gradient: self.gradient.load(Ordering::Relaxed),
+        }
+    }
+
+    /// This function sets all counts back to zero
+    pub fn reset(&self) {
+        self.objective.store(0, Ordering::Relaxed);
+        self.constraint.store(0, Ordering::Relaxed);
+        self.gradient.store(0, Ordering::Relaxed);
+    }
+}
+
+/// This is a wrapper around a benchmark function that counts its evaluations.
+///
+/// Each call to an objective function counts as one objective evaluation per point. A call to
+/// `h` or `g` counts as one constraint evaluation, and a call to `gradient` as one gradient
+/// evaluation.
+#[derive(Debug, Default)]
+pub struct Counted<F> {
+    counters: Counters,
+    function: PhantomData<fn() -> F>,
+}
+
+impl<F> Counted<F> {
+    /// This function creates a wrapper with all counts at zero
+    pub fn new() -> Counted<F> {
+        Counted { counters: Counters::default(), function: PhantomData }
+    }
+
+    /// This function returns a snapshot of the counts
+    pub fn counts(&self) -> Counts {
+        self.counters.counts()
+    }
+
+    /// This function sets all counts back to zero
+    pub fn reset(&self) {
+        self.counters.reset()
+    }
+}
+
+impl<F: SingleObjective> Counted<F> {
+    /// Function for evaluating the objective function
+    pub fn f(&self, x: Vec<f64>) -> f64 {
+        self.counters.add_objective(1);
+        F::f(x)
+    }
+
+    /// This function evaluates the objective function at each of a set of points
+    pub fn f_batch(&self, points: &[Vec<f64>]) -> Vec<f64> {
+        self.counters.add_objective(points.len());
+        F::f_batch(points)
+    }
+}
+
+impl<F: MultiObjective> Counted<F> {
+    /// Function for evaluating the set of objective functions
+    pub fn f_multi(&self, x: Vec<f64>) -> Vec<f64> {
+        self.counters.add_objective(1);
+        F::f(x)
+    }
+}
+
+impl<F: Constrained> Counted<F> {
+    /// Function for evaluating the equality constraints
+    pub fn h(&self, x: Vec<f64>) -> Vec<f64> {
+        self.counters.add_constraint(1);
+        F::h(x)
+    }
+
+    /// Function for evaluating the inequality constraints
+    pub fn g(&self, x: Vec<f64>) -> Vec<f64> {
+        self.counters.add_constraint(1);
+        F::g(x)
+    }
+}
+
+impl<F: Differentiable> Counted<F> {
+    /// Function for evaluating the gradient of the objective function
+    pub fn gradient(&self, x: Vec<f64>) -> Vec<f64> {
+        self.counters.add_gradient(1);
+        F::gradient(x)
+    }
+}
+
+impl<F: SingleObjective> Parameterized for Counted<F> {
+    /// Function for evaluating, which counts as an objective evaluation
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        self.f(x)
+    }
+
+    /// The global minimum is that of the wrapped function
+    fn global_minimum(&self) -> Option<f64> {
+        Some(F::MINIMUM)
+    }
+
+    /// The minimizer is that of the wrapped function
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        Some(F::minimizer(n))
+    }
+}
+
+#[cfg(test)]
+mod counted_tests {
+    use super::{Counted, Counts};
+    use crate::{Parameterized, Wood, RosenbrockConst1, ChankongHaimes, Rastrigin};
+
+    #[test]
+    fn counts() {
+        let counted = Counted::<Wood>::new();
+        counted.f(vec![0.0; 4]);
+        counted.gradient(vec![0.0; 4]);
+        counted.gradient(vec![1.0; 4]);
+        counted.check_global_minimizer(4);
+        assert_eq!(counted.counts(), Counts { objective: 2, constraint: 0, gradient: 2 });
+        counted.reset();
+        assert_eq!(counted.counts(), Counts::default());
+    }
+
+    #[test]
+    fn constraints() {
+        let counted = Counted::<RosenbrockConst1>::new();
+        counted.h(vec![0.0, 0.0]);
+        counted.g(vec![0.0, 0.0]);
+        let multi = Counted::<ChankongHaimes>::new();
+        multi.f_multi(vec![0.0, 0.0]);
+        multi.g(vec![0.0, 0.0]);
+        assert_eq!(counted.counts().constraint, 2);
+        assert_eq!(multi.counts(), Counts { objective: 1, constraint: 1, gradient: 0 });
+    }
+
+    #[test]
+    fn threads() {
+        let counted = Counted::<Rastrigin>::new();
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..1000 {
+                        counted.f(vec![0.5; 3]);
+                    }
+                });
+            }
+        });
+        assert_eq!(counted.counts().objective, 8000);
+    }
+}
@@ -0,0 +1,175 @@
+//! This module contains functions for writing sampled landscapes and evaluation logs to CSV
+//!
+//! Every file starts with a header row and has one row per point. The columns are always in the
+//! same order:
+//!
+//! | Writer                  | Columns                                        |
+//! |-------------------------|------------------------------------------------|
+//! | `write_landscape`       | `x0, ..., x{d-1}, f`                           |
+//! | `write_single_objective`| `x0, ..., x{d-1}, f`                           |
+//! | `write_multi_objective` | `x0, ..., x{d-1}, f0, ..., f{NF-1}`            |
+//! | `write_constrained`     | `x0, ..., x{d-1}, f, h0, ..., g0, ...`         |
+//! | `write_history`         | `evaluation, x0, ..., x{d-1}, f, best`         |
+//! | `write_records`         | `evaluation, x0, ..., x{d-1}, f, violation, best` |
+//!
+//! Values are written with Rust's shortest round-trip formatting, so they can be read back without
+//! loss. Non-finite values are written as `inf`, `-inf` and `NaN`, which pandas and R both
+//! understand.
+//!
+//! ```
+//! use benchfun::{Rastrigin, Rng};
+//! use benchfun::sampling::uniform_in_bounds;
+//! use benchfun::csv::write_single_objective;
+//!
+//! let points = uniform_in_bounds::<Rastrigin>(10, 3, &mut Rng::new(0));
+//! let mut buffer = Vec::new();
+//! write_single_objective::<Rastrigin, _>(&mut buffer, &points).unwrap();
+//! assert!(String::from_utf8(buffer).unwrap().starts_with("x0,x1,x2,f\n"));
+//! ```
+
+use std::io::{Result, Write};
+
+use crate::{Constrained, MultiObjective, SingleObjective};
+use crate::recorder::Record;
+
+/// This function returns numbered column names
+fn columns(prefix: &str, n: usize) -> Vec<String> {
+    (0..n).map(|i| format!("{}{}", prefix, i)).collect()
+}
+
+/// This function returns the dimensionality of a set of points, checking that it is consistent
+fn dimension(points: &[Vec<f64>]) -> usize {
+    let d = points.first().map_or(0, Vec::len);
+    assert!(points.iter().all(|x| x.len() == d), "all points must have the same dimensionality");
+    d
+}
+
+/// This function writes a header row and rows of values
+fn write_table<W: Write, I: IntoIterator<Item = Vec<f64>>>(mut writer: W, header: Vec<String>, rows: I) -> Result<()> {
+    writeln!(writer, "{}", header.join(","))?;
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(f64::to_string).collect();
+        writeln!(writer, "{}", cells.join(","))?;
+    }
+    writer.flush()
+}
+
+/// This function writes points and their precomputed function values
+pub fn write_landscape<W: Write>(writer: W, points: &[Vec<f64>], values: &[f64]) -> Result<()> {
+    assert_eq!(points.len(), values.len(), "there must be one value per point");
+    let mut header = columns("x", dimension(points));
+    header.push("f".to_string());
+    write_table(writer, header, points.iter().zip(values.iter()).map(|(x, f)| {
+        let mut row = x.clone();
+        row.push(*f);
+        row
+    }))
+}
+
+/// This function evaluates a single-objective function at each point and writes the results
+pub fn write_single_objective<F: SingleObjective, W: Write>(writer: W, points: &[Vec<f64>]) -> Result<()> {
+    let values: Vec<f64> = points.iter().map(|x| F::f(x.clone())).collect();
+    write_landscape(writer, points, &values)
+}
+
+/// This function evaluates a multi-objective function at each point and writes the results
+pub fn write_multi_objective<F: MultiObjective, W: Write>(writer: W, points: &[Vec<f64>]) -> Result<()> {
+    let mut header = columns("x", dimension(points));
+    header.extend(columns("f", F::NF));
+    write_table(writer, header, points.iter().map(|x| {
+        let mut row = x.clone();
+        row.extend(F::f(x.clone()));
+        row
+    }))
+}
+
+/// This function evaluates a constrained single-objective function and its equality (`h`) and
+/// inequality (`g`) constraints at each point and writes the results
+pub fn write_constrained<F: SingleObjective + Constrained, W: Write>(writer: W, points: &[Vec<f64>]) -> Result<()> {
+    let mut header = columns("x", dimension(points));
+    header.push("f".to_string());
+    header.extend(columns("h", F::NH));
+    header.extend(columns("g", F::NG));
+    write_table(writer, header, points.iter().map(|x| {
+        let mut row = x.clone();
+        row.push(F::f(x.clone()));
+        row.extend(F::h(x.clone()));
+        row.extend(F::g(x.clone()));
+        row
+    }))
+}
+
+/// This function writes an evaluation history, given as the evaluated points and their function
+/// values in the order they were evaluated, along with the best value found so far
+pub fn write_history<W: Write>(writer: W, history: &[(Vec<f64>, f64)]) -> Result<()> {
+    let points: Vec<Vec<f64>> = history.iter().map(|(x, _)| x.clone()).collect();
+    let mut header = vec!["evaluation".to_string()];
+    header.extend(columns("x", dimension(&points)));
+    header.extend(vec!["f".to_string(), "best".to_string()]);
+    let mut best = f64::INFINITY;
+    write_table(writer, header, history.iter().enumerate().map(|(i, (x, f))| {
+        best = best.min(*f);
+        let mut row = vec![(i + 1) as f64];
+        row.extend(x.iter());
+        row.extend(vec![*f, best]);
+        row
+    }))
+}
+
+/// This function writes the records of a `Recorder`, including their constraint violations
+pub fn write_records<W: Write>(writer: W, records: &[Record]) -> Result<()> {
+    let points: Vec<Vec<f64>> = records.iter().map(|r| r.x.clone()).collect();
+    let mut header = vec!["evaluation".to_string()];
+    header.extend(columns("x", dimension(&points)));
+    header.extend(vec!["f".to_string(), "violation".to_string(), "best".to_string()]);
+    write_table(writer, header, records.iter().map(|r| {
+        let mut row = vec![r.evaluation as f64];
+        row.extend(r.x.iter());
+        row.extend(vec![r.f, r.violation, r.best]);
+        row
+    }))
+}
+
+#[cfg(test)]
+mod csv_tests {
+    use super::{write_landscape, write_single_objective, write_multi_objective, write_constrained, write_history};
+    use crate::{ChankongHaimes, RosenbrockConst1, Sphere};
+
+    fn written<E: std::fmt::Debug>(write: impl FnOnce(&mut Vec<u8>) -> Result<(), E>) -> String {
+        let mut buffer = Vec::new();
+        write(&mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn landscape() {
+        let csv = written(|w| write_landscape(w, &[vec![0.1, -2.0], vec![1e-20, 3.0]], &[0.5, f64::INFINITY]));
+        assert_eq!(csv, "x0,x1,f\n0.1,-2,0.5\n0.00000000000000000001,3,inf\n");
+        assert_eq!(written(|w| write_landscape(w, &[], &[])), "f\n");
+    }
+
+    #[test]
+    fn single_objective() {
+        let csv = written(|w| write_single_objective::<Sphere, _>(w, &[vec![1.0, 2.0, 3.0]]));
+        assert_eq!(csv.lines().next(), Some("x0,x1,x2,f"));
+        assert_eq!(csv.lines().count(), 2);
+    }
+
+    #[test]
+    fn multi_objective() {
+        let csv = written(|w| write_multi_objective::<ChankongHaimes, _>(w, &[vec![1.0, 2.0]]));
+        assert_eq!(csv.lines().next(), Some("x0,x1,f0,f1"));
+    }
+
+    #[test]
+    fn constrained() {
+        let csv = written(|w| write_constrained::<RosenbrockConst1, _>(w, &[vec![1.0, 1.0]]));
+        assert_eq!(csv, "x0,x1,f,g0,g1\n1,1,0,0,0\n");
+    }
+
+    #[test]
+    fn history() {
+        let csv = written(|w| write_history(w, &[(vec![1.0], 4.0), (vec![0.5], 5.0), (vec![0.0], 1.0)]));
+        assert_eq!(csv, "evaluation,x0,f,best\n1,1,4,4\n2,0.5,5,4\n3,0,1,1\n");
+    }
+}
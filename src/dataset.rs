@@ -0,0 +1,200 @@
+//! This module contains a generator of datasets for benchmarking surrogate models
+//!
+//! A dataset is generated from any bounded function by sampling points inside its bounds,
+//! evaluating the function with optional Gaussian noise, and splitting the result into training,
+//! validation and test sets. Everything is reproducible from the seed.
+//!
+//! ```
+//! use benchfun::Branin;
+//! use benchfun::dataset::{DatasetConfig, Sampler};
+//!
+//! let config = DatasetConfig { n_points: 200, dimension: 2, sampler: Sampler::Sobol, noise: 0.1, ..Default::default() };
+//! let dataset = config.generate::<Branin>();
+//! assert_eq!(dataset.train.x.len() + dataset.validation.x.len() + dataset.test.x.len(), 200);
+//! ```
+
+use crate::{Bounded, SingleObjective, Rng};
+#[cfg(feature = "json")]
+use crate::json::{Json, JsonError, ToJson, FromJson};
+use crate::sampling::{uniform_in_bounds, latin_hypercube, scrambled_sobol, scrambled_halton};
+
+/// This is the design used to place the points of a dataset
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sampler {
+    /// Points drawn uniformly from the bounds
+    Uniform,
+    /// A Latin hypercube design
+    LatinHypercube,
+    /// An Owen-scrambled Sobol sequence
+    Sobol,
+    /// A scrambled Halton sequence
+    Halton,
+}
+
+/// This struct configures the generation of a dataset
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatasetConfig {
+    /// The total number of points
+    pub n_points: usize,
+    /// The number of dimensions
+    pub dimension: usize,
+    /// The design used to place the points
+    pub sampler: Sampler,
+    /// The standard deviation of the Gaussian noise added to the function values
+    pub noise: f64,
+    /// The fraction of points held out for validation
+    pub validation_fraction: f64,
+    /// The fraction of points held out for testing
+    pub test_fraction: f64,
+    /// The seed from which everything is generated
+    pub seed: u64,
+}
+
+impl Default for DatasetConfig {
+    fn default() -> Self {
+        DatasetConfig {
+            n_points: 100,
+            dimension: 2,
+            sampler: Sampler::Uniform,
+            noise: 0.0,
+            validation_fraction: 0.15,
+            test_fraction: 0.15,
+            seed: 0,
+        }
+    }
+}
+
+/// This struct holds the inputs and targets of one part of a dataset
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Split {
+    /// The inputs
+    pub x: Vec<Vec<f64>>,
+    /// The targets
+    pub y: Vec<f64>,
+}
+
+/// This struct holds a dataset split into training, validation and test sets
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dataset {
+    /// The training set
+    pub train: Split,
+    /// The validation set
+    pub validation: Split,
+    /// The test set
+    pub test: Split,
+}
+
+impl DatasetConfig {
+    /// This function generates a dataset from a function
+    pub fn generate<F: SingleObjective + Bounded>(&self) -> Dataset {
+        assert!(self.validation_fraction >= 0.0 && self.test_fraction >= 0.0 && self.validation_fraction + self.test_fraction <= 1.0,
+                "the validation and test fractions must be non-negative and sum to at most one");
+        let mut rng = Rng::new(self.seed);
+        let (n, d) = (self.n_points, self.dimension);
+        let points = match self.sampler {
+            Sampler::Uniform => uniform_in_bounds::<F>(n, d, &mut rng),
+            Sampler::LatinHypercube => latin_hypercube::<F>(n, d, false, &mut rng),
+            Sampler::Sobol => scrambled_sobol::<F>(n, d, &mut rng),
+            Sampler::Halton => scrambled_halton::<F>(n, d, 1, &mut rng),
+        };
+        let mut samples: Vec<(Vec<f64>, f64)> = points.into_iter()
+            .map(|x| {
+                let y = F::f(x.clone()) + self.noise*rng.normal();
+                (x, y)
+            })
+            .collect();
+        rng.shuffle(&mut samples);
+
+        let n_test = (self.test_fraction*n as f64).round() as usize;
+        let n_validation = ((self.validation_fraction*n as f64).round() as usize).min(n - n_test);
+        let test = samples.split_off(n - n_test);
+        let validation = samples.split_off(n - n_test - n_validation);
+        let split = |samples: Vec<(Vec<f64>, f64)>| {
+            let (x, y) = samples.into_iter().unzip();
+            Split { x, y }
+        };
+        Dataset { train: split(samples), validation: split(validation), test: split(test) }
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Split {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![("x".to_string(), self.x.to_json()), ("y".to_string(), self.y.to_json())])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Split {
+    fn from_json(json: &Json) -> Result<Split, JsonError> {
+        Ok(Split { x: json.field("x")?, y: json.field("y")? })
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Dataset {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("train".to_string(), self.train.to_json()),
+            ("validation".to_string(), self.validation.to_json()),
+            ("test".to_string(), self.test.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Dataset {
+    fn from_json(json: &Json) -> Result<Dataset, JsonError> {
+        Ok(Dataset { train: json.field("train")?, validation: json.field("validation")?, test: json.field("test")? })
+    }
+}
+
+#[cfg(test)]
+mod dataset_tests {
+    use super::{DatasetConfig, Sampler};
+    use crate::{SingleObjective, Bounded, Rastrigin};
+    #[cfg(feature = "json")]
+    use super::Dataset;
+    #[cfg(feature = "json")]
+    use crate::json::{ToJson, FromJson};
+
+    #[test]
+    fn splits() {
+        let config = DatasetConfig { n_points: 101, dimension: 3, validation_fraction: 0.2, test_fraction: 0.1, ..Default::default() };
+        let dataset = config.generate::<Rastrigin>();
+        assert_eq!((dataset.train.x.len(), dataset.validation.x.len(), dataset.test.x.len()), (71, 20, 10));
+        for split in [&dataset.train, &dataset.validation, &dataset.test].iter() {
+            assert_eq!(split.x.len(), split.y.len());
+            for (x, y) in split.x.iter().zip(split.y.iter()) {
+                assert!(Rastrigin::in_bounds(x.clone()));
+                assert_eq!(*y, Rastrigin::f(x.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn reproducible() {
+        for sampler in [Sampler::Uniform, Sampler::LatinHypercube, Sampler::Sobol, Sampler::Halton].iter() {
+            let config = DatasetConfig { sampler: *sampler, noise: 0.5, seed: 9, ..Default::default() };
+            let dataset = config.generate::<Rastrigin>();
+            assert_eq!(dataset, config.generate::<Rastrigin>());
+            assert_ne!(dataset, DatasetConfig { seed: 10, ..config.clone() }.generate::<Rastrigin>());
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let dataset = DatasetConfig { sampler: Sampler::Sobol, noise: 0.5, seed: 9, ..Default::default() }.generate::<Rastrigin>();
+        assert_eq!(Dataset::from_json(&dataset.to_json()).unwrap(), dataset);
+    }
+
+    #[test]
+    fn noise() {
+        let config = DatasetConfig { n_points: 2000, noise: 2.0, test_fraction: 0.0, validation_fraction: 0.0, ..Default::default() };
+        let train = config.generate::<Rastrigin>().train;
+        let residuals: Vec<f64> = train.x.iter().zip(train.y.iter()).map(|(x, y)| y - Rastrigin::f(x.clone())).collect();
+        let variance = residuals.iter().map(|r| r*r).sum::<f64>()/residuals.len() as f64;
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+    }
+}
@@ -0,0 +1,191 @@
+//! This module contains runtime-to-target analysis of harness results
+//!
+//! Following COCO, each pair of a run and a target precision is a trial, and the runtime of a trial
+//! is the number of evaluations, divided by the dimensionality, that the run needed to come within
+//! the target precision of the global minimum. The empirical cumulative distribution function of
+//! these runtimes gives the fraction of trials that were solved within each budget, and can be
+//! aggregated over any subset of the runs, such as all functions at one dimensionality.
+//!
+//! ```
+//! use benchfun::Ackley;
+//! use benchfun::ecdf::{ecdf, ecdf_by_dimension, budget_grid, coco_targets};
+//! use benchfun::harness::{Harness, Budget, problems, random_search};
+//!
+//! let harness = Harness { budget: Budget::PerDimension(100), repetitions: 3, ..Harness::new(problems::<Ackley>(&[2, 5])) };
+//! let results = harness.run("random search", &mut random_search);
+//! let budgets = budget_grid(100.0, 5);
+//! let overall = ecdf(results.runs.iter(), &coco_targets(), &budgets);
+//! assert!(overall.proportions.windows(2).all(|w| w[0] <= w[1]));
+//! for (d, distribution) in ecdf_by_dimension(&results, &coco_targets(), &budgets) {
+//!     println!("{}D: {} of the trials were solved", d, distribution.proportions.last().unwrap());
+//! }
+//! ```
+
+use crate::harness::{Results, RunResult};
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+
+/// This function returns the 51 target precisions used by COCO, spaced evenly on a logarithmic
+/// scale from 1e2 down to 1e-8
+pub fn coco_targets() -> Vec<f64> {
+    (0..=50).map(|k| 10f64.powf(2.0 - 0.2*k as f64)).collect()
+}
+
+/// This function returns budgets, in evaluations per dimension, spaced evenly on a logarithmic
+/// scale from 1 up to and including `max`, with `per_decade` budgets in each factor of ten
+pub fn budget_grid(max: f64, per_decade: usize) -> Vec<f64> {
+    assert!(max >= 1.0 && per_decade > 0, "The budgets must reach at least 1 with at least one budget per decade.");
+    let n = (max.log10()*per_decade as f64).ceil() as usize;
+    (0..=n).map(|k| 10f64.powf(k as f64/per_decade as f64).min(max)).collect()
+}
+
+/// This function returns the runtime of a run to each target precision, in evaluations per
+/// dimension, or `None` for targets the run did not reach
+pub fn runtimes(run: &RunResult, targets: &[f64]) -> Vec<Option<f64>> {
+    targets.iter()
+        .map(|precision| run.evaluations_to_precision(*precision).map(|e| e as f64/run.dimension as f64))
+        .collect()
+}
+
+/// This struct holds an empirical cumulative distribution function of runtimes
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ecdf {
+    /// The budgets, in evaluations per dimension
+    pub budgets: Vec<f64>,
+    /// The fraction of trials solved within each budget
+    pub proportions: Vec<f64>,
+    /// The number of trials
+    pub trials: usize,
+}
+
+impl Ecdf {
+    /// This function returns the distribution of a set of runtimes at each of a set of budgets,
+    /// where unsolved trials are `None`
+    pub fn new(runtimes: &[Option<f64>], budgets: &[f64]) -> Ecdf {
+        let n = runtimes.len();
+        Ecdf {
+            budgets: budgets.to_vec(),
+            proportions: budgets.iter()
+                .map(|b| if n == 0 { 0.0 } else { runtimes.iter().filter(|r| r.is_some_and(|r| r <= *b)).count() as f64/n as f64 })
+                .collect(),
+            trials: n,
+        }
+    }
+
+    /// This function returns the fraction of trials solved within a budget, in evaluations per dimension
+    pub fn at(&self, budget: f64) -> f64 {
+        self.budgets.iter().zip(self.proportions.iter())
+            .take_while(|(b, _)| **b <= budget)
+            .last()
+            .map_or(0.0, |(_, p)| *p)
+    }
+
+    /// This function returns the area above the distribution on a logarithmic budget axis, between
+    /// the first and last budgets, normalized to lie between 0 and 1. Smaller is better.
+    pub fn area_above(&self) -> f64 {
+        let logs: Vec<f64> = self.budgets.iter().map(|b| b.log10()).collect();
+        let span = logs.last().unwrap_or(&0.0) - logs.first().unwrap_or(&0.0);
+        if span <= 0.0 {
+            return 1.0 - self.proportions.first().unwrap_or(&0.0);
+        }
+        let area: f64 = logs.windows(2).zip(self.proportions.iter())
+            .map(|(w, p)| (w[1] - w[0])*(1.0 - p))
+            .sum();
+        area/span
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Ecdf {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("budgets".to_string(), self.budgets.to_json()),
+            ("proportions".to_string(), self.proportions.to_json()),
+            ("trials".to_string(), self.trials.to_json()),
+        ])
+    }
+}
+
+/// This function returns the distribution of runtimes over a set of runs and target precisions
+pub fn ecdf<'a, I: IntoIterator<Item = &'a RunResult>>(runs: I, targets: &[f64], budgets: &[f64]) -> Ecdf {
+    let runtimes: Vec<Option<f64>> = runs.into_iter().flat_map(|run| runtimes(run, targets)).collect();
+    Ecdf::new(&runtimes, budgets)
+}
+
+/// This function returns the distribution of runtimes over all functions at each dimensionality,
+/// in increasing order of dimensionality
+pub fn ecdf_by_dimension(results: &Results, targets: &[f64], budgets: &[f64]) -> Vec<(usize, Ecdf)> {
+    let mut dims: Vec<usize> = results.runs.iter().map(|r| r.dimension).collect();
+    dims.sort_unstable();
+    dims.dedup();
+    dims.into_iter()
+        .map(|d| (d, ecdf(results.runs.iter().filter(|r| r.dimension == d), targets, budgets)))
+        .collect()
+}
+
+/// This function returns the distribution of runtimes over all dimensionalities of each function,
+/// in the order of the suite
+pub fn ecdf_by_function(results: &Results, targets: &[f64], budgets: &[f64]) -> Vec<(String, Ecdf)> {
+    let mut names: Vec<String> = Vec::new();
+    for run in results.runs.iter() {
+        if !names.contains(&run.problem) {
+            names.push(run.problem.clone());
+        }
+    }
+    names.into_iter()
+        .map(|name| {
+            let distribution = ecdf(results.runs.iter().filter(|r| r.problem == name), targets, budgets);
+            (name, distribution)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod ecdf_tests {
+    use super::{Ecdf, ecdf, ecdf_by_dimension, ecdf_by_function, runtimes, budget_grid, coco_targets};
+    use crate::harness::{Harness, Budget, Evaluator, problems};
+    use crate::{Booth, Rastrigin, Rng};
+
+    #[test]
+    fn grids() {
+        let targets = coco_targets();
+        assert_eq!(targets.len(), 51);
+        assert!((targets[0] - 1e2).abs() < 1e-12 && (targets[50] - 1e-8).abs() < 1e-20);
+        let budgets = budget_grid(500.0, 2);
+        assert_eq!(budgets.len(), 7);
+        assert_eq!((budgets[0], budgets[6]), (1.0, 500.0));
+        assert!((budgets[2] - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn distribution() {
+        let distribution = Ecdf::new(&[Some(1.0), Some(3.0), None, Some(10.0)], &[1.0, 2.0, 5.0, 10.0]);
+        assert_eq!(distribution.proportions, vec![0.25, 0.25, 0.5, 0.75]);
+        assert_eq!((distribution.at(0.5), distribution.at(4.0), distribution.at(100.0)), (0.0, 0.25, 0.75));
+        let solved = Ecdf::new(&[Some(1.0)], &[1.0, 10.0]);
+        assert_eq!(solved.area_above(), 0.0);
+        let unsolved = Ecdf::new(&[None], &[1.0, 10.0]);
+        assert_eq!(unsolved.area_above(), 1.0);
+    }
+
+    #[test]
+    fn aggregation() {
+        let mut suite = problems::<Booth>(&[2]);
+        suite.extend(problems::<Rastrigin>(&[2, 4]));
+        let harness = Harness { budget: Budget::Fixed(4), repetitions: 2, ..Harness::new(suite) };
+        let results = harness.run("descent", &mut |evaluator: &Evaluator, _: &mut Rng| {
+            for k in (0..4).rev() {
+                evaluator.f(vec![k as f64; evaluator.dimension()]);
+            }
+        });
+        assert_eq!(runtimes(&results.runs[0], &[100.0, 10.0, 1.0]), vec![Some(0.5), Some(1.0), None]);
+        let budgets = [1.0, 2.0];
+        let by_dimension = ecdf_by_dimension(&results, &[1e-8], &budgets);
+        assert_eq!(by_dimension.iter().map(|(d, e)| (*d, e.trials)).collect::<Vec<_>>(), vec![(2, 4), (4, 2)]);
+        assert_eq!(by_dimension[1].1.proportions, vec![1.0, 1.0]);
+        let by_function = ecdf_by_function(&results, &[1e-8], &budgets);
+        assert_eq!(by_function[0].0, "Booth");
+        assert_eq!(by_function[0].1.proportions, vec![0.0, 0.0]);
+        assert_eq!(ecdf(results.runs.iter(), &[1e-8], &budgets).trials, 6);
+    }
+}
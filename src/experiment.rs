@@ -0,0 +1,138 @@
+//! This module contains a runner for repeated-run experiments that spreads the runs over threads
+//!
+//! Every run gets its own seed, drawn from the master seed of the harness in a fixed order, and the
+//! results are collected in that same order. The outcome of an experiment therefore depends only on
+//! the master seed, and not on the number of threads or on how the runs were scheduled on them, as
+//! long as the optimizer is deterministic given its random number generator. Each thread builds its
+//! own optimizer with a factory, so optimizers do not need to be shared between threads.
+//!
+//! ```
+//! use benchfun::{Ackley, Rastrigin};
+//! use benchfun::experiment::Experiment;
+//! use benchfun::harness::{Harness, Budget, problems, random_search};
+//!
+//! let mut suite = problems::<Ackley>(&[2, 5]);
+//! suite.extend(problems::<Rastrigin>(&[2]));
+//! let harness = Harness { budget: Budget::PerDimension(100), repetitions: 10, seed: 42, ..Harness::new(suite) };
+//! let outcome = Experiment::new(harness.clone()).run("random search", || random_search);
+//! assert_eq!(outcome.results, harness.run("random search", &mut random_search));
+//! assert_eq!(outcome.histories[0].len(), outcome.results.runs[0].evaluations);
+//! ```
+
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::harness::{Harness, History, Optimizer, Results, RunResult};
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+
+/// This struct configures an experiment
+#[derive(Clone, Debug)]
+pub struct Experiment {
+    /// The problems, budget, repetitions, target and master seed of the experiment
+    pub harness: Harness,
+    /// The number of threads, where 0 uses one thread per available core
+    pub threads: usize,
+    /// Whether to record the history of every run
+    pub record_history: bool,
+}
+
+/// This struct holds the outcome of an experiment
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    /// The results of the runs, in the order of the schedule of the harness
+    pub results: Results,
+    /// The history of each run, in the same order, which are empty unless histories are recorded
+    pub histories: Vec<History>,
+}
+
+impl Experiment {
+    /// This function creates an experiment that records histories and uses every available core
+    pub fn new(harness: Harness) -> Experiment {
+        Experiment { harness, threads: 0, record_history: true }
+    }
+
+    /// This function runs an optimizer on every problem of the suite, building one optimizer per
+    /// thread with `make`
+    pub fn run<O: Optimizer, M: Fn() -> O + Sync>(&self, solver: &str, make: M) -> Outcome {
+        let schedule = self.harness.schedule();
+        let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let threads = if self.threads == 0 { available } else { self.threads }.min(schedule.len()).max(1);
+        let next = AtomicUsize::new(0);
+        let finished: Mutex<Vec<(usize, RunResult, History)>> = Mutex::new(Vec::with_capacity(schedule.len()));
+        std::thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    let mut optimizer = make();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some((p, r, seed)) = schedule.get(i).copied() else { break };
+                        let (result, history) = if self.record_history {
+                            self.harness.run_recorded(solver, &mut optimizer, p, r, seed)
+                        } else {
+                            (self.harness.run_once(solver, &mut optimizer, p, r, seed), Vec::new())
+                        };
+                        finished.lock().unwrap().push((i, result, history));
+                    }
+                });
+            }
+        });
+        let mut finished = finished.into_inner().unwrap();
+        finished.sort_by_key(|(i, _, _)| *i);
+        let (runs, histories) = finished.into_iter().map(|(_, result, history)| (result, history)).unzip();
+        Outcome { results: Results { target: self.harness.target, runs }, histories }
+    }
+}
+
+impl Outcome {
+    /// This function writes the history of a run as CSV, with the columns of `csv::write_history`
+    pub fn write_history_csv<W: Write>(&self, run: usize, w: &mut W) -> std::io::Result<()> {
+        crate::csv::write_history(w, &self.histories[run])
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Outcome {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("results".to_string(), self.results.to_json()),
+            ("histories".to_string(), self.histories.to_json()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod experiment_tests {
+    use super::Experiment;
+    use crate::harness::{Harness, Budget, problems, random_search};
+    use crate::{Booth, Rastrigin};
+
+    #[test]
+    fn reproducible() {
+        let mut suite = problems::<Rastrigin>(&[2, 3]);
+        suite.extend(problems::<Booth>(&[2]));
+        let harness = Harness { budget: Budget::Fixed(30), repetitions: 5, stop_at_target: false, seed: 7, ..Harness::new(suite) };
+        let serial = Experiment { threads: 1, ..Experiment::new(harness.clone()) }.run("random search", || random_search);
+        let parallel = Experiment { threads: 4, ..Experiment::new(harness.clone()) }.run("random search", || random_search);
+        assert_eq!(serial, parallel);
+        assert_eq!(serial.results, harness.run("random search", &mut random_search));
+        assert_eq!(serial.histories.len(), 15);
+        for (run, history) in serial.results.runs.iter().zip(serial.histories.iter()) {
+            assert_eq!(history.len(), 30);
+            assert_eq!(history.iter().map(|(_, f)| *f).fold(f64::INFINITY, f64::min), run.best_f);
+        }
+        let reseeded = Experiment::new(Harness { seed: 8, ..harness }).run("random search", || random_search);
+        assert_ne!(reseeded.results, serial.results);
+    }
+
+    #[test]
+    fn without_history() {
+        let harness = Harness { budget: Budget::Fixed(5), repetitions: 2, ..Harness::new(problems::<Booth>(&[2])) };
+        let outcome = Experiment { record_history: false, ..Experiment::new(harness) }.run("random search", || random_search);
+        assert!(outcome.histories.iter().all(|h| h.is_empty()));
+        let mut csv = Vec::new();
+        outcome.write_history_csv(0, &mut csv).unwrap();
+        assert!(String::from_utf8(csv).unwrap().starts_with("evaluation,"));
+    }
+}
@@ -0,0 +1,99 @@
+//! This module contains a verifier of constraint satisfaction
+//!
+//! A point is feasible if every equality constraint is within a tolerance of zero and every
+//! inequality constraint is at most zero, or within the tolerance of zero. The verifier reports
+//! each violated constraint, so that errors in constraint formulas or in recorded solutions can be
+//! found. The best-known solutions of the constrained functions, which are their global minimizers
+//! or, for multi-objective functions, their Pareto sets, are verified by the tests of this module.
+//!
+//! ```
+//! use benchfun::{RosenbrockConst1, Tolerance};
+//! use benchfun::feasibility::{verify, ConstraintKind};
+//!
+//! assert!(verify::<RosenbrockConst1>(&[1.0, 1.0], Tolerance::default()).is_ok());
+//! let violations = verify::<RosenbrockConst1>(&[2.0, 1.0], Tolerance::default()).unwrap_err();
+//! assert_eq!((violations[0].kind, violations[0].index), (ConstraintKind::Inequality, 0));
+//! ```
+
+use crate::{Constrained, Tolerance};
+use crate::registry::Function;
+
+/// This enum distinguishes equality and inequality constraints
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// An equality constraint, h(x) = 0
+    Equality,
+    /// An inequality constraint, g(x) <= 0
+    Inequality,
+}
+
+/// This struct describes a violated constraint
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Violation {
+    /// The kind of the constraint
+    pub kind: ConstraintKind,
+    /// The index of the constraint among the constraints of its kind
+    pub index: usize,
+    /// The value of the constraint
+    pub value: f64,
+}
+
+/// This function returns the violated constraints among the values of equality and inequality
+/// constraints
+pub fn violations(h: &[f64], g: &[f64], tolerance: Tolerance) -> Vec<Violation> {
+    let equality = h.iter().enumerate()
+        .filter(|(_, hi)| !tolerance.accepts(**hi, 0.0))
+        .map(|(index, hi)| Violation { kind: ConstraintKind::Equality, index, value: *hi });
+    let inequality = g.iter().enumerate()
+        .filter(|(_, gi)| !(**gi <= 0.0 || tolerance.accepts(**gi, 0.0)))
+        .map(|(index, gi)| Violation { kind: ConstraintKind::Inequality, index, value: *gi });
+    equality.chain(inequality).collect()
+}
+
+/// This function verifies that a point satisfies the constraints of a function, returning the
+/// violated constraints otherwise
+pub fn verify<F: Constrained>(x: &[f64], tolerance: Tolerance) -> Result<(), Vec<Violation>> {
+    let violations = violations(&F::h(x.to_vec()), &F::g(x.to_vec()), tolerance);
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+}
+
+/// This function verifies that a point satisfies the constraints of a function of the registry,
+/// which always holds for unconstrained functions
+pub fn verify_function(function: &Function, x: &[f64], tolerance: Tolerance) -> Result<(), Vec<Violation>> {
+    let h = function.equality_constraints.map_or(vec![], |h| h(x.to_vec()));
+    let g = function.inequality_constraints.map_or(vec![], |g| g(x.to_vec()));
+    let violations = violations(&h, &g, tolerance);
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+}
+
+#[cfg(test)]
+mod feasibility_tests {
+    use super::{violations, verify, verify_function, ConstraintKind, Violation};
+    use crate::{ChankongHaimes, RosenbrockConst1, RosenbrockConst2, Tolerance};
+    use crate::registry::{functions, find};
+
+    #[test]
+    fn verifier() {
+        let tolerance = Tolerance::absolute(1e-9);
+        assert!(violations(&[1e-10, -1e-10], &[-5.0, 0.0, 1e-10], tolerance).is_empty());
+        assert_eq!(violations(&[0.0, 1e-3], &[-1.0, 2.0], tolerance), vec![
+            Violation { kind: ConstraintKind::Equality, index: 1, value: 1e-3 },
+            Violation { kind: ConstraintKind::Inequality, index: 1, value: 2.0 },
+        ]);
+        assert_eq!(verify::<RosenbrockConst2>(&[1.5, 1.5], tolerance).unwrap_err().len(), 1);
+        assert!(verify_function(find("Booth").unwrap(), &[100.0, 100.0], tolerance).is_ok());
+    }
+
+    #[test]
+    fn best_known_solutions() {
+        let tolerance = Tolerance::absolute(1e-12);
+        for function in functions().iter().filter(|f| f.properties.constrained) {
+            let d = function.dimension.unwrap();
+            assert_eq!(verify_function(function, &(function.minimizer)(d), tolerance), Ok(()), "{}", function.name);
+        }
+        assert!(verify::<RosenbrockConst1>(&[1.0, 1.0], tolerance).is_ok());
+        for x in ChankongHaimes::pareto_set(50) {
+            assert_eq!(verify::<ChankongHaimes>(&x, tolerance), Ok(()));
+        }
+    }
+}
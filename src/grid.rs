@@ -0,0 +1,153 @@
+//! This module contains functions for evaluating benchmark functions on regular grids, such as for
+//! contour plots and line plots of cross sections
+//!
+//! ```
+//! use benchfun::Himmelblau;
+//! use benchfun::grid::grid_evaluate;
+//!
+//! let grid = grid_evaluate::<Himmelblau>(50);
+//! assert_eq!(grid.z.len(), 50);
+//! assert_eq!(grid.z[0].len(), 50);
+//! ```
+
+use crate::{Bounded, SingleObjective};
+#[cfg(feature = "json")]
+use crate::json::{Json, JsonError, ToJson, FromJson};
+
+/// This struct holds the values of a function on a regular 2-D grid
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    /// The coordinates of the grid along the first axis
+    pub x: Vec<f64>,
+    /// The coordinates of the grid along the second axis
+    pub y: Vec<f64>,
+    /// The function values, where `z[j][i]` is the value at `(x[i], y[j])`
+    pub z: Vec<Vec<f64>>,
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Grid {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("x".to_string(), self.x.to_json()),
+            ("y".to_string(), self.y.to_json()),
+            ("z".to_string(), self.z.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Grid {
+    fn from_json(json: &Json) -> Result<Grid, JsonError> {
+        Ok(Grid { x: json.field("x")?, y: json.field("y")?, z: json.field("z")? })
+    }
+}
+
+/// This function returns `resolution` evenly spaced values from `low` to `high`, inclusive
+fn linspace(low: f64, high: f64, resolution: usize) -> Vec<f64> {
+    assert!(resolution >= 2, "the resolution must be at least 2");
+    (0..resolution).map(|k| low + (high - low)*k as f64/(resolution - 1) as f64).collect()
+}
+
+/// This function evaluates a 2-D function on a `resolution` by `resolution` grid that spans its
+/// bounds.
+pub fn grid_evaluate<F: SingleObjective + Bounded>(resolution: usize) -> Grid {
+    grid_evaluate_slice::<F>(resolution, vec![0.0; 2], 0, 1)
+}
+
+/// This function evaluates a 2-D slice of an N-D function on a `resolution` by `resolution` grid.
+///
+/// The slice passes through `base`, whose length sets the dimensionality, and varies dimensions `i`
+/// and `j` across their bounds while the others stay fixed.
+pub fn grid_evaluate_slice<F: SingleObjective + Bounded>(resolution: usize, base: Vec<f64>, i: usize, j: usize) -> Grid {
+    evaluate_slice(F::f, &F::bounds(base.len()), resolution, base, i, j)
+}
+
+/// This function evaluates a 2-D slice of a function, given as a function pointer with its bounds,
+/// on a `resolution` by `resolution` grid
+pub(crate) fn evaluate_slice(f: fn(Vec<f64>) -> f64, bounds: &[(f64, f64)], resolution: usize, base: Vec<f64>, i: usize, j: usize) -> Grid {
+    assert!(i != j, "the slice dimensions must differ");
+    assert!(i < base.len() && j < base.len(), "the slice dimensions must be within the base point");
+    let x = linspace(bounds[i].0, bounds[i].1, resolution);
+    let y = linspace(bounds[j].0, bounds[j].1, resolution);
+    let z = y.iter()
+        .map(|yj| {
+            x.iter()
+                .map(|xi| {
+                    let mut point = base.clone();
+                    point[i] = *xi;
+                    point[j] = *yj;
+                    f(point)
+                })
+                .collect()
+        })
+        .collect();
+    Grid { x, y, z }
+}
+
+/// This function evaluates a function along the line `point + t*direction` at `resolution` evenly
+/// spaced values of `t` from `t_range.0` to `t_range.1`, returning `(t, f)` pairs.
+pub fn line_evaluate<F: SingleObjective>(point: &[f64], direction: &[f64], t_range: (f64, f64), resolution: usize) -> Vec<(f64, f64)> {
+    assert_eq!(point.len(), direction.len(), "the point and direction must have the same dimensionality");
+    linspace(t_range.0, t_range.1, resolution).into_iter()
+        .map(|t| (t, F::f(point.iter().zip(direction.iter()).map(|(p, v)| p + t*v).collect())))
+        .collect()
+}
+
+/// This function evaluates a function along coordinate axis `i` through `point`, across the bounds
+/// of that axis, returning `(t, f)` pairs where `t` is the value of the coordinate.
+pub fn axis_evaluate<F: SingleObjective + Bounded>(point: &[f64], i: usize, resolution: usize) -> Vec<(f64, f64)> {
+    assert!(i < point.len(), "the axis must be within the point");
+    let (low, high) = F::bounds(point.len())[i];
+    let mut direction = vec![0.0; point.len()];
+    direction[i] = 1.0;
+    let mut base = point.to_vec();
+    base[i] = 0.0;
+    line_evaluate::<F>(&base, &direction, (low, high), resolution)
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::{grid_evaluate, grid_evaluate_slice, line_evaluate, axis_evaluate};
+    use crate::Booth;
+    use crate::{SingleObjective, Bounded, BukinN6, Rastrigin};
+
+    #[test]
+    fn full() {
+        let grid = grid_evaluate::<BukinN6>(11);
+        assert_eq!(grid.x.first(), Some(&-15.0));
+        assert_eq!(grid.x.last(), Some(&-5.0));
+        assert_eq!(grid.y.first(), Some(&-3.0));
+        assert_eq!(grid.y.last(), Some(&3.0));
+        assert_eq!(grid.z[5][0], BukinN6::f(vec![-15.0, 0.0]));
+        assert_eq!(grid.z[10][3], BukinN6::f(vec![grid.x[3], 3.0]));
+    }
+
+    #[test]
+    fn slice() {
+        let grid = grid_evaluate_slice::<Rastrigin>(5, vec![1.0, 2.0, 3.0, 4.0], 3, 1);
+        for (x, expected) in grid.x.iter().zip([-5.12, -2.56, 0.0, 2.56, 5.12].iter()) {
+            assert!((x - expected).abs() < 1e-12);
+        }
+        assert_eq!(grid.z[2][4], Rastrigin::f(vec![1.0, grid.y[2], 3.0, grid.x[4]]));
+        assert!(grid.z.iter().flatten().all(|z| *z >= Rastrigin::MINIMUM));
+        assert!(Rastrigin::in_bounds(vec![grid.x[4], grid.y[0]]));
+    }
+
+    #[test]
+    fn line() {
+        let section = line_evaluate::<Booth>(&[1.0, 1.0], &[1.0, -1.0], (-1.0, 1.0), 3);
+        assert_eq!(section, vec![(-1.0, 18.0), (0.0, 20.0), (1.0, 26.0)]);
+    }
+
+    #[test]
+    fn axis() {
+        let minimizer = BukinN6::minimizer(2);
+        let section = axis_evaluate::<BukinN6>(&minimizer, 0, 11);
+        assert_eq!(section.len(), 11);
+        assert_eq!(section[0].0, -15.0);
+        assert_eq!(section[10].0, -5.0);
+        assert_eq!(section[5], (-10.0, BukinN6::MINIMUM));
+        assert!(section.iter().all(|(_, f)| *f >= BukinN6::MINIMUM));
+    }
+}
@@ -0,0 +1,457 @@
+//! This module contains a harness for benchmarking optimizers
+//!
+//! An optimizer is anything that implements `Optimizer`, including closures that take an
+//! `Evaluator` and an `Rng`. The harness runs it a number of times on each problem of a suite, with
+//! a budget of evaluations per run, and collects the best values found and the number of
+//! evaluations each run needed to reach the target precision.
+//!
+//! ```
+//! use benchfun::{Ackley, Rastrigin};
+//! use benchfun::harness::{Harness, Budget, problems, random_search};
+//!
+//! let mut suite = problems::<Ackley>(&[2, 5]);
+//! suite.extend(problems::<Rastrigin>(&[2]));
+//! let harness = Harness { budget: Budget::PerDimension(100), repetitions: 5, target: 1e-1, ..Harness::new(suite) };
+//! let results = harness.run("random search", &mut random_search);
+//! assert_eq!(results.runs.len(), 15);
+//! for summary in results.summary() {
+//!     println!("{} {}D: success rate {}", summary.problem, summary.dimension, summary.success_rate);
+//! }
+//! ```
+
+use std::cell::RefCell;
+
+use crate::{Bounded, SingleObjective, Rng, Problem};
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+
+/// This function returns a problem for each of several dimensionalities of a function
+pub fn problems<F: SingleObjective + Bounded>(dims: &[usize]) -> Vec<Problem> {
+    dims.iter().map(|d| Problem::new::<F>(*d)).collect()
+}
+
+/// This type is the history of a run, which is every point evaluated with its value, in order
+pub type History = Vec<(Vec<f64>, f64)>;
+
+/// This is the view of a problem that an optimizer gets during a run.
+///
+/// It evaluates the problem while enforcing the budget and tracking the best point. The global
+/// optimum is hidden from the optimizer.
+#[derive(Debug)]
+pub struct Evaluator<'a> {
+    problem: &'a Problem,
+    budget: usize,
+    target: Option<f64>,
+    state: RefCell<RunState>,
+}
+
+/// This struct holds the progress of a run
+#[derive(Clone, Debug, Default)]
+struct RunState {
+    evaluations: usize,
+    best: Option<(Vec<f64>, f64)>,
+    trace: Vec<(usize, f64)>,
+    history: Option<History>,
+}
+
+impl<'a> Evaluator<'a> {
+    /// This function creates an evaluator for a problem with a budget. If `target` is given, the run
+    /// ends as soon as a value at or below it is found.
+    pub fn new(problem: &'a Problem, budget: usize, target: Option<f64>) -> Evaluator<'a> {
+        Evaluator { problem, budget, target, state: RefCell::new(RunState::default()) }
+    }
+
+    /// Function for evaluating, which returns `None` once the run has ended because the budget is
+    /// exhausted or the target has been reached
+    pub fn f(&self, x: Vec<f64>) -> Option<f64> {
+        if self.finished() {
+            return None;
+        }
+        let fx = self.problem.f(x.clone());
+        let mut state = self.state.borrow_mut();
+        state.evaluations += 1;
+        let evaluation = state.evaluations;
+        if let Some(history) = state.history.as_mut() {
+            history.push((x.clone(), fx));
+        }
+        if state.best.as_ref().is_none_or(|(_, best)| fx < *best) {
+            state.best = Some((x, fx));
+            state.trace.push((evaluation, fx));
+        }
+        Some(fx)
+    }
+
+    /// This function returns whether the run has ended
+    pub fn finished(&self) -> bool {
+        let state = self.state.borrow();
+        let reached = match (self.target, &state.best) {
+            (Some(target), Some((_, best))) => *best <= target,
+            _ => false,
+        };
+        state.evaluations >= self.budget || reached
+    }
+
+    /// This function returns the number of dimensions
+    pub fn dimension(&self) -> usize {
+        self.problem.dimension()
+    }
+
+    /// This function returns the bounds of each dimension
+    pub fn bounds(&self) -> &[(f64, f64)] {
+        self.problem.bounds()
+    }
+
+    /// This function returns the budget of evaluations
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// This function returns the number of evaluations so far
+    pub fn evaluations(&self) -> usize {
+        self.state.borrow().evaluations
+    }
+
+    /// This function returns the number of evaluations left in the budget
+    pub fn remaining(&self) -> usize {
+        self.budget.saturating_sub(self.evaluations())
+    }
+
+    /// This function returns the best point found so far and its value
+    pub fn best(&self) -> Option<(Vec<f64>, f64)> {
+        self.state.borrow().best.clone()
+    }
+}
+
+/// This is a trait for optimizers that can be benchmarked by the harness
+pub trait Optimizer {
+    /// This function runs the optimizer on a problem until it stops or the evaluator stops it
+    fn optimize(&mut self, evaluator: &Evaluator, rng: &mut Rng);
+}
+
+impl<G: FnMut(&Evaluator, &mut Rng)> Optimizer for G {
+    fn optimize(&mut self, evaluator: &Evaluator, rng: &mut Rng) {
+        self(evaluator, rng)
+    }
+}
+
+/// This function is a pure random search, which samples points uniformly from the bounds until the
+/// run ends. It is a useful baseline and reference optimizer.
+pub fn random_search(evaluator: &Evaluator, rng: &mut Rng) {
+    loop {
+        let x = evaluator.bounds().iter().map(|(low, high)| low + (high - low)*rng.uniform()).collect();
+        if evaluator.f(x).is_none() {
+            return;
+        }
+    }
+}
+
+/// This enum sets the budget of evaluations of each run
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Budget {
+    /// The same budget for every problem
+    Fixed(usize),
+    /// A budget proportional to the dimensionality of the problem
+    PerDimension(usize),
+}
+
+impl Budget {
+    /// This function returns the budget for a dimensionality
+    pub fn evaluations(&self, d: usize) -> usize {
+        match *self {
+            Budget::Fixed(n) => n,
+            Budget::PerDimension(n) => n*d,
+        }
+    }
+}
+
+/// This struct configures a benchmarking experiment
+#[derive(Clone, Debug)]
+pub struct Harness {
+    /// The problems of the suite
+    pub problems: Vec<Problem>,
+    /// The budget of evaluations of each run
+    pub budget: Budget,
+    /// The number of runs on each problem
+    pub repetitions: usize,
+    /// The target precision. A run succeeds when it finds a value within this distance of the
+    /// global minimum.
+    pub target: f64,
+    /// Whether a run ends as soon as it succeeds
+    pub stop_at_target: bool,
+    /// The seed from which the seeds of all runs are drawn
+    pub seed: u64,
+}
+
+impl Harness {
+    /// This function creates a harness for a suite with the default settings, which are a budget
+    /// of 1000 evaluations per dimension, 15 repetitions, and a target precision of 1e-8
+    pub fn new(problems: Vec<Problem>) -> Harness {
+        Harness { problems, budget: Budget::PerDimension(1000), repetitions: 15, target: 1e-8, stop_at_target: true, seed: 0 }
+    }
+
+    /// This function returns the problem index, repetition and seed of every run, in order
+    pub fn schedule(&self) -> Vec<(usize, usize, u64)> {
+        let mut rng = Rng::new(self.seed);
+        let mut runs = Vec::with_capacity(self.problems.len()*self.repetitions);
+        for p in 0..self.problems.len() {
+            for r in 0..self.repetitions {
+                runs.push((p, r, rng.next_u64()));
+            }
+        }
+        runs
+    }
+
+    /// This function performs a single run of an optimizer
+    pub fn run_once<O: Optimizer + ?Sized>(&self, solver: &str, optimizer: &mut O, problem: usize, repetition: usize, seed: u64) -> RunResult {
+        self.run_with(solver, optimizer, problem, repetition, seed, false).0
+    }
+
+    /// This function performs a single run of an optimizer, and also returns every point it
+    /// evaluated with its value, in order
+    pub fn run_recorded<O: Optimizer + ?Sized>(&self, solver: &str, optimizer: &mut O, problem: usize, repetition: usize, seed: u64) -> (RunResult, History) {
+        let (result, history) = self.run_with(solver, optimizer, problem, repetition, seed, true);
+        (result, history.unwrap_or_default())
+    }
+
+    /// This function performs a single run of an optimizer, optionally recording its history
+    fn run_with<O: Optimizer + ?Sized>(&self, solver: &str, optimizer: &mut O, problem: usize, repetition: usize, seed: u64, record: bool) -> (RunResult, Option<History>) {
+        let problem = &self.problems[problem];
+        let target = problem.minimum() + self.target;
+        let evaluator = Evaluator::new(problem, self.budget.evaluations(problem.dimension()), if self.stop_at_target { Some(target) } else { None });
+        if record {
+            evaluator.state.borrow_mut().history = Some(Vec::new());
+        }
+        optimizer.optimize(&evaluator, &mut Rng::new(seed));
+        let state = evaluator.state.into_inner();
+        let (best_x, best_f) = state.best.unwrap_or((Vec::new(), f64::INFINITY));
+        let result = RunResult {
+            solver: solver.to_string(),
+            problem: problem.name().to_string(),
+            dimension: problem.dimension(),
+            repetition,
+            seed,
+            minimum: problem.minimum(),
+            evaluations_to_target: state.trace.iter().find(|(_, f)| *f <= target).map(|(e, _)| *e),
+            evaluations: state.evaluations,
+            best_f,
+            best_x,
+            trace: state.trace,
+        };
+        (result, state.history)
+    }
+
+    /// This function runs an optimizer on every problem of the suite
+    pub fn run<O: Optimizer + ?Sized>(&self, solver: &str, optimizer: &mut O) -> Results {
+        let runs = self.schedule().into_iter()
+            .map(|(p, r, seed)| self.run_once(solver, optimizer, p, r, seed))
+            .collect();
+        Results { target: self.target, runs }
+    }
+}
+
+/// This struct holds the result of a single run
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunResult {
+    /// The name of the optimizer
+    pub solver: String,
+    /// The name of the function
+    pub problem: String,
+    /// The number of dimensions
+    pub dimension: usize,
+    /// The number of the repetition
+    pub repetition: usize,
+    /// The seed of the run
+    pub seed: u64,
+    /// The global minimum of the problem
+    pub minimum: f64,
+    /// The best value found
+    pub best_f: f64,
+    /// The best point found
+    pub best_x: Vec<f64>,
+    /// The number of evaluations used
+    pub evaluations: usize,
+    /// The number of evaluations needed to reach the target, if it was reached
+    pub evaluations_to_target: Option<usize>,
+    /// The evaluation numbers at which the best value improved, with the improved values
+    pub trace: Vec<(usize, f64)>,
+}
+
+impl RunResult {
+    /// This function returns the best value found after a number of evaluations
+    pub fn best_after(&self, evaluations: usize) -> f64 {
+        self.trace.iter().take_while(|(e, _)| *e <= evaluations).last().map_or(f64::INFINITY, |(_, f)| *f)
+    }
+
+    /// This function returns the number of evaluations needed to come within a precision of the
+    /// global minimum, if the run got that close
+    pub fn evaluations_to_precision(&self, precision: f64) -> Option<usize> {
+        self.trace.iter().find(|(_, f)| *f <= self.minimum + precision).map(|(e, _)| *e)
+    }
+}
+
+/// This struct holds statistics of the runs on one problem
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProblemSummary {
+    /// The name of the function
+    pub problem: String,
+    /// The number of dimensions
+    pub dimension: usize,
+    /// The number of runs
+    pub runs: usize,
+    /// The fraction of runs that reached the target
+    pub success_rate: f64,
+    /// The mean of the best values found
+    pub mean_best: f64,
+    /// The median of the best values found
+    pub median_best: f64,
+    /// The average runtime, which is the total number of evaluations of all runs divided by the
+    /// number of successful runs, or infinity if no run succeeded
+    pub average_runtime: f64,
+}
+
+/// This struct holds the results of an experiment
+#[derive(Clone, Debug, PartialEq)]
+pub struct Results {
+    /// The target precision of the experiment
+    pub target: f64,
+    /// The result of every run
+    pub runs: Vec<RunResult>,
+}
+
+impl Results {
+    /// This function returns statistics of the runs on each problem, in the order of the suite
+    pub fn summary(&self) -> Vec<ProblemSummary> {
+        let mut keys: Vec<(String, usize)> = Vec::new();
+        for run in self.runs.iter() {
+            if !keys.iter().any(|(p, d)| *p == run.problem && *d == run.dimension) {
+                keys.push((run.problem.clone(), run.dimension));
+            }
+        }
+        keys.into_iter()
+            .map(|(problem, dimension)| {
+                let runs: Vec<&RunResult> = self.runs.iter().filter(|r| r.problem == problem && r.dimension == dimension).collect();
+                let successes = runs.iter().filter(|r| r.evaluations_to_target.is_some()).count();
+                let mut bests: Vec<f64> = runs.iter().map(|r| r.best_f).collect();
+                bests.sort_by(|a, b| a.total_cmp(b));
+                let n = bests.len();
+                let spent: usize = runs.iter().map(|r| r.evaluations_to_target.unwrap_or(r.evaluations)).sum();
+                ProblemSummary {
+                    problem,
+                    dimension,
+                    runs: n,
+                    success_rate: successes as f64/n as f64,
+                    mean_best: bests.iter().sum::<f64>()/n as f64,
+                    median_best: if n % 2 == 1 { bests[n/2] } else { 0.5*(bests[n/2 - 1] + bests[n/2]) },
+                    average_runtime: if successes > 0 { spent as f64/successes as f64 } else { f64::INFINITY },
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for RunResult {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("solver".to_string(), self.solver.to_json()),
+            ("problem".to_string(), self.problem.to_json()),
+            ("dimension".to_string(), self.dimension.to_json()),
+            ("repetition".to_string(), self.repetition.to_json()),
+            ("seed".to_string(), self.seed.to_string().to_json()),
+            ("minimum".to_string(), self.minimum.to_json()),
+            ("best_f".to_string(), self.best_f.to_json()),
+            ("best_x".to_string(), self.best_x.to_json()),
+            ("evaluations".to_string(), self.evaluations.to_json()),
+            ("evaluations_to_target".to_string(), self.evaluations_to_target.to_json()),
+            ("trace".to_string(), self.trace.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for ProblemSummary {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("problem".to_string(), self.problem.to_json()),
+            ("dimension".to_string(), self.dimension.to_json()),
+            ("runs".to_string(), self.runs.to_json()),
+            ("success_rate".to_string(), self.success_rate.to_json()),
+            ("mean_best".to_string(), self.mean_best.to_json()),
+            ("median_best".to_string(), self.median_best.to_json()),
+            ("average_runtime".to_string(), self.average_runtime.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Results {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("target".to_string(), self.target.to_json()),
+            ("runs".to_string(), self.runs.to_json()),
+            ("summary".to_string(), self.summary().to_json()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod harness_tests {
+    use super::{Harness, Budget, Evaluator, problems, random_search};
+    use crate::{Booth, Rastrigin, Rng, Problem};
+    #[cfg(feature = "json")]
+    use crate::json::ToJson;
+
+    #[test]
+    fn evaluator() {
+        let problem = Problem::new::<Booth>(2);
+        let evaluator = Evaluator::new(&problem, 3, Some(0.0));
+        assert_eq!(evaluator.f(vec![0.0, 0.0]), Some(74.0));
+        assert_eq!(evaluator.f(vec![1.0, 3.0]), Some(0.0));
+        assert!(evaluator.finished());
+        assert_eq!(evaluator.f(vec![1.0, 3.0]), None);
+        assert_eq!((evaluator.evaluations(), evaluator.remaining()), (2, 1));
+        assert_eq!(evaluator.best(), Some((vec![1.0, 3.0], 0.0)));
+        assert_eq!(problem.counts().objective, 2);
+    }
+
+    #[test]
+    fn budgets() {
+        let harness = Harness { budget: Budget::PerDimension(20), repetitions: 3, stop_at_target: false, ..Harness::new(problems::<Booth>(&[2])) };
+        let results = harness.run("random search", &mut random_search);
+        assert_eq!(results.runs.len(), 3);
+        for run in results.runs.iter() {
+            assert_eq!(run.evaluations, 40);
+            assert!(run.trace.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 > w[1].1));
+            assert_eq!(run.best_after(40), run.best_f);
+            assert_eq!(run.best_after(0), f64::INFINITY);
+        }
+        assert_eq!(results, harness.run("random search", &mut random_search));
+        assert_ne!(results.runs[0].seed, results.runs[1].seed);
+    }
+
+    #[test]
+    fn success() {
+        let mut suite = problems::<Rastrigin>(&[1, 3]);
+        suite.extend(problems::<Booth>(&[2]));
+        let harness = Harness { budget: Budget::Fixed(10), repetitions: 4, target: 1e-8, ..Harness::new(suite) };
+        let mut oracle = |evaluator: &Evaluator, _: &mut Rng| {
+            evaluator.f(vec![1.0; evaluator.dimension()]);
+            evaluator.f(vec![0.0; evaluator.dimension()]);
+        };
+        let results = harness.run("oracle", &mut oracle);
+        let summary = results.summary();
+        assert_eq!(summary.len(), 3);
+        assert_eq!((summary[0].success_rate, summary[0].average_runtime), (1.0, 2.0));
+        assert_eq!((summary[2].problem.as_str(), summary[2].success_rate), ("Booth", 0.0));
+        assert_eq!(summary[2].average_runtime, f64::INFINITY);
+        assert_eq!(results.runs[0].evaluations_to_precision(1.5), Some(1));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let harness = Harness { budget: Budget::Fixed(10), repetitions: 1, target: 1e-8, ..Harness::new(problems::<Booth>(&[2])) };
+        let results = harness.run("random search", &mut random_search);
+        assert!(results.to_json().to_string().starts_with("{\"target\":1e-8,\"runs\":[{\"solver\":\"random search\""));
+    }
+}
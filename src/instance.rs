@@ -0,0 +1,90 @@
+//! This module contains the machinery for shifted instances of benchmark functions
+
+use crate::Rng;
+#[cfg(feature = "json")]
+use crate::json::{Json, JsonError, ToJson, FromJson};
+
+/// This is a shift of the optimum of a benchmark function.
+///
+/// Shifted functions are evaluated at z = x - x_opt, so that every instance of the function has its
+/// optimum at a different location. Instances can be drawn reproducibly from a seed, as in the BBOB
+/// suite.
+#[derive(Clone, Debug)]
+pub struct Shift {
+    /// The location of the optimum of the shifted function
+    pub x_opt: Vec<f64>,
+}
+
+impl Shift {
+    /// This function creates a shift that moves the optimum to a given location
+    pub fn new(x_opt: Vec<f64>) -> Shift {
+        Shift { x_opt }
+    }
+
+    /// This function draws the location of the optimum uniformly from [-4, 4]^n
+    pub fn random(n: usize, seed: u64) -> Shift {
+        let mut rng = Rng::new(seed);
+        Shift { x_opt: (0..n).map(|_| 8.0*rng.uniform() - 4.0).collect() }
+    }
+
+    /// This function returns the shifted point z = x - x_opt
+    pub fn apply(&self, x: &[f64]) -> Vec<f64> {
+        if x.len() != self.x_opt.len() {
+            panic!("A vector with size {} was used with a shift of dimensionality {}.", x.len(), self.x_opt.len());
+        }
+        x.iter().zip(self.x_opt.iter()).map(|(xi, oi)| xi - oi).collect()
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Shift {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![("x_opt".to_string(), self.x_opt.to_json())])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Shift {
+    fn from_json(json: &Json) -> Result<Shift, JsonError> {
+        Ok(Shift { x_opt: json.field("x_opt")? })
+    }
+}
+
+/// This is the oscillation transformation T_osz used in the BBOB suite
+pub fn oscillate(x: f64) -> f64 {
+    if x == 0.0 {
+        return 0.0;
+    }
+    let x_hat = x.abs().ln();
+    let (c1, c2) = if x > 0.0 { (10.0, 7.9) } else { (5.5, 3.1) };
+    x.signum()*(x_hat + 0.049*((c1*x_hat).sin() + (c2*x_hat).sin())).exp()
+}
+
+/// This function returns the i-th diagonal element of the BBOB conditioning matrix Λ^α in n dimensions
+pub fn conditioning(alpha: f64, i: usize, n: usize) -> f64 {
+    if n > 1 {
+        alpha.powf(0.5*(i as f64)/((n - 1) as f64))
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod instance_tests {
+    use super::{Shift, oscillate};
+
+    #[test]
+    fn random_shift() {
+        let shift = Shift::random(137, 3);
+        assert!(shift.x_opt.iter().all(|xi| (-4.0..4.0).contains(xi)));
+        assert_eq!(shift.x_opt, Shift::random(137, 3).x_opt);
+        assert!(shift.apply(&shift.x_opt).iter().all(|zi| *zi == 0.0));
+    }
+
+    #[test]
+    fn oscillation() {
+        assert_eq!(oscillate(0.0), 0.0);
+        assert!((oscillate(1.0) - 1.0).abs() < f64::EPSILON);
+        assert!((oscillate(-1.0) + 1.0).abs() < f64::EPSILON);
+    }
+}
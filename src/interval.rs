@@ -0,0 +1,569 @@
+//! This module contains an interval-arithmetic evaluation of the functions, for developing
+//! branch-and-bound and verified global optimization methods. It is only available with the
+//! `interval` feature.
+//!
+//! An `Interval` is a closed set of reals with floating-point endpoints, and every operation rounds
+//! its endpoints outward, so the result of evaluating a function over a box of intervals is a
+//! guaranteed enclosure of the values of the function over that box. Additions, subtractions and
+//! multiplications are rounded exactly, using error-free transformations. Divisions and square
+//! roots are widened by an ulp, and the exponential and trigonometric functions, which the standard
+//! library computes to within an ulp, are widened by two. The enclosures follow the formulas of the
+//! `f` functions, with their floating-point coefficients taken as exact and with π, e and square
+//! roots enclosed in intervals. They enclose the exact values of those formulas, so the rounded
+//! results of `f` can fall just outside the enclosure of a very narrow box.
+//!
+//! ```
+//! use benchfun::Rastrigin;
+//! use benchfun::interval::{Interval, IntervalObjective};
+//!
+//! let enclosure = Rastrigin::f_interval(vec![Interval::new(-0.1, 0.2); 3]);
+//! assert!(enclosure.contains(0.0) && enclosure.low <= 0.0);
+//! assert!(!Rastrigin::f_interval(vec![Interval::new(0.4, 0.6); 3]).contains(0.0));
+//! ```
+
+use std::f64::consts::{E, PI};
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    SingleObjective, FixedDimensional, Sphere, Rastrigin, Rosenbrock, Ackley, Griewank, Zakharov, Salomon,
+    Exponential, ChungReynolds, BentCigar, Discus, Matyas, Booth, Beale, Himmelblau, ThreeHumpCamel,
+    SixHumpCamel, Easom,
+};
+
+/// This struct is a closed interval of reals
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    /// The lower endpoint
+    pub low: f64,
+    /// The upper endpoint
+    pub high: f64,
+}
+
+/// This function rounds the sum of two numbers down, using the exact error of the rounded sum
+fn add_down(a: f64, b: f64) -> f64 {
+    let (s, e) = two_sum(a, b);
+    if e < 0.0 || e.is_nan() { s.next_down() } else { s }
+}
+
+/// This function rounds the sum of two numbers up
+fn add_up(a: f64, b: f64) -> f64 {
+    let (s, e) = two_sum(a, b);
+    if e > 0.0 || e.is_nan() { s.next_up() } else { s }
+}
+
+/// This function returns the rounded sum of two numbers and its exact error
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    if !s.is_finite() {
+        return (s, if a.is_finite() && b.is_finite() { f64::NAN } else { 0.0 });
+    }
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// This function returns the rounded product of two numbers and its error, which is exact unless
+/// the product is close to underflowing, in which case it is reported as unknown
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a*b;
+    if !p.is_finite() {
+        return (p, if a.is_finite() && b.is_finite() { f64::NAN } else { 0.0 });
+    }
+    if p != 0.0 && p.abs() < 1e-290 || p == 0.0 && a != 0.0 && b != 0.0 {
+        return (p, f64::NAN);
+    }
+    (p, a.mul_add(b, -p))
+}
+
+/// This function rounds the product of two numbers down
+fn mul_down(a: f64, b: f64) -> f64 {
+    let (p, e) = two_product(a, b);
+    if e < 0.0 || e.is_nan() { p.next_down() } else { p }
+}
+
+/// This function rounds the product of two numbers up
+fn mul_up(a: f64, b: f64) -> f64 {
+    let (p, e) = two_product(a, b);
+    if e > 0.0 || e.is_nan() { p.next_up() } else { p }
+}
+
+/// This function returns an interval that encloses a value computed to within a number of ulps
+fn widen(low: f64, high: f64, ulps: usize) -> Interval {
+    let (mut low, mut high) = (low, high);
+    for _ in 0..ulps {
+        low = low.next_down();
+        high = high.next_up();
+    }
+    Interval { low, high }
+}
+
+impl Interval {
+    /// This function creates an interval from its endpoints
+    pub fn new(low: f64, high: f64) -> Interval {
+        if low > high || low.is_nan() || high.is_nan() {
+            panic!("An interval was created with a lower endpoint {} above its upper endpoint {}.", low, high);
+        }
+        Interval { low, high }
+    }
+
+    /// This function creates an interval that only contains a number
+    pub fn point(x: f64) -> Interval {
+        Interval::new(x, x)
+    }
+
+    /// This function creates the interval of every real
+    pub fn entire() -> Interval {
+        Interval { low: f64::NEG_INFINITY, high: f64::INFINITY }
+    }
+
+    /// This function returns an interval that encloses π
+    pub fn pi() -> Interval {
+        Interval { low: PI.next_down(), high: PI.next_up() }
+    }
+
+    /// This function returns an interval that encloses e
+    pub fn e() -> Interval {
+        Interval { low: E.next_down(), high: E.next_up() }
+    }
+
+    /// This function creates a box of intervals from the bounds of each dimension
+    pub fn from_bounds(bounds: &[(f64, f64)]) -> Vec<Interval> {
+        bounds.iter().map(|(low, high)| Interval::new(*low, *high)).collect()
+    }
+
+    /// This function checks if the interval contains a number
+    pub fn contains(&self, x: f64) -> bool {
+        self.low <= x && x <= self.high
+    }
+
+    /// This function returns the width of the interval, rounded up
+    pub fn width(&self) -> f64 {
+        add_up(self.high, -self.low)
+    }
+
+    /// This function returns the midpoint of the interval
+    pub fn midpoint(&self) -> f64 {
+        if self.low.is_infinite() || self.high.is_infinite() {
+            return if self.low == -self.high { 0.0 } else if self.low.is_infinite() { f64::MIN } else { f64::MAX };
+        }
+        self.low/2.0 + self.high/2.0
+    }
+
+    /// This function splits the interval into two halves at its midpoint
+    pub fn bisect(&self) -> (Interval, Interval) {
+        let m = self.midpoint();
+        (Interval { low: self.low, high: m }, Interval { low: m, high: self.high })
+    }
+
+    /// This function returns the absolute value of the interval
+    pub fn abs(self) -> Interval {
+        if self.low >= 0.0 {
+            self
+        } else if self.high <= 0.0 {
+            -self
+        } else {
+            Interval { low: 0.0, high: self.high.max(-self.low) }
+        }
+    }
+
+    /// This function returns the interval raised to a non-negative integer power
+    pub fn powi(self, n: u32) -> Interval {
+        let pow_down = |x: f64| (0..n).fold(1.0, |p, _| mul_down(p, x));
+        let pow_up = |x: f64| (0..n).fold(1.0, |p, _| mul_up(p, x));
+        if n % 2 == 1 {
+            // Odd powers are increasing, and the magnitudes are raised to keep the rounding one-sided
+            let low = if self.low >= 0.0 { pow_down(self.low) } else { -pow_up(-self.low) };
+            let high = if self.high >= 0.0 { pow_up(self.high) } else { -pow_down(-self.high) };
+            Interval { low, high }
+        } else {
+            let magnitude = self.abs();
+            Interval { low: pow_down(magnitude.low), high: pow_up(magnitude.high) }
+        }
+    }
+
+    /// This function returns the square root of the interval, ignoring its negative part
+    pub fn sqrt(self) -> Interval {
+        if self.high < 0.0 {
+            panic!("The square root of an interval {:?} of negative numbers was taken.", self);
+        }
+        let widened = widen(self.low.max(0.0).sqrt(), self.high.sqrt(), 1);
+        Interval { low: widened.low.max(0.0), high: widened.high }
+    }
+
+    /// This function returns the exponential of the interval
+    pub fn exp(self) -> Interval {
+        let widened = widen(self.low.exp(), self.high.exp(), 2);
+        Interval { low: widened.low.max(0.0), high: widened.high }
+    }
+
+    /// This function returns the cosine of the interval
+    pub fn cos(self) -> Interval {
+        self.periodic(f64::cos, 0.0, PI)
+    }
+
+    /// This function returns the sine of the interval
+    pub fn sin(self) -> Interval {
+        self.periodic(f64::sin, PI/2.0, -PI/2.0)
+    }
+
+    /// This function encloses a function with period 2π, a maximum of one at `peak` and a minimum of
+    /// negative one at `trough`. Between extrema, the function is monotonic, so the enclosure is the
+    /// hull of its values at the endpoints and at the extrema inside the interval. Extrema close to
+    /// the endpoints are included to be safe.
+    fn periodic(self, f: fn(f64) -> f64, peak: f64, trough: f64) -> Interval {
+        if self.width() >= 2.0*PI || self.low.abs() > 1e12 || self.high.abs() > 1e12 {
+            return Interval { low: -1.0, high: 1.0 };
+        }
+        let includes = |extremum: f64| {
+            let (a, b) = ((self.low - extremum)/(2.0*PI), (self.high - extremum)/(2.0*PI));
+            (a - 1e-9).ceil() <= (b + 1e-9).floor()
+        };
+        let (fa, fb) = (f(self.low), f(self.high));
+        let widened = widen(fa.min(fb), fa.max(fb), 2);
+        Interval {
+            low: if includes(trough) { -1.0 } else { widened.low.max(-1.0) },
+            high: if includes(peak) { 1.0 } else { widened.high.min(1.0) },
+        }
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(x: f64) -> Interval {
+        Interval::point(x)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval { low: -self.high, high: -self.low }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, other: Interval) -> Interval {
+        Interval { low: add_down(self.low, other.low), high: add_up(self.high, other.high) }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, other: Interval) -> Interval {
+        self + -other
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, other: Interval) -> Interval {
+        let pairs = [(self.low, other.low), (self.low, other.high), (self.high, other.low), (self.high, other.high)];
+        Interval {
+            low: pairs.iter().map(|(a, b)| mul_down(*a, *b)).fold(f64::INFINITY, f64::min),
+            high: pairs.iter().map(|(a, b)| mul_up(*a, *b)).fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+    fn div(self, other: Interval) -> Interval {
+        if other.contains(0.0) {
+            return Interval::entire();
+        }
+        let quotients = [self.low/other.low, self.low/other.high, self.high/other.low, self.high/other.high];
+        widen(quotients.iter().cloned().fold(f64::INFINITY, f64::min), quotients.iter().cloned().fold(f64::NEG_INFINITY, f64::max), 1)
+    }
+}
+
+/// This macro implements an arithmetic operator between an interval and a number, in both orders
+macro_rules! scalar_operator {
+    ($trait:ident, $method:ident) => {
+        impl $trait<f64> for Interval {
+            type Output = Interval;
+            fn $method(self, other: f64) -> Interval {
+                self.$method(Interval::point(other))
+            }
+        }
+
+        impl $trait<Interval> for f64 {
+            type Output = Interval;
+            fn $method(self, other: Interval) -> Interval {
+                Interval::point(self).$method(other)
+            }
+        }
+    };
+}
+
+scalar_operator!(Add, add);
+scalar_operator!(Sub, sub);
+scalar_operator!(Mul, mul);
+scalar_operator!(Div, div);
+
+impl Sum for Interval {
+    fn sum<I: Iterator<Item = Interval>>(iter: I) -> Interval {
+        iter.fold(Interval::point(0.0), |sum, x| sum + x)
+    }
+}
+
+/// This trait evaluates a function over a box of intervals
+pub trait IntervalObjective: SingleObjective {
+    /// This function returns an enclosure of the values of the function over a box
+    fn f_interval(x: Vec<Interval>) -> Interval;
+}
+
+/// This function checks that a box has the dimensionality of a fixed-dimensional function
+fn check_box<F: FixedDimensional>(x: &[Interval]) {
+    if x.len() != F::D {
+        panic!("A vector with size {} was used with a function of dimensionality {}.", x.len(), F::D);
+    }
+}
+
+/// This function returns the sum of the squares of the coordinates of a box
+fn square_sum(x: &[Interval]) -> Interval {
+    x.iter().map(|xi| xi.powi(2)).sum()
+}
+
+impl IntervalObjective for Sphere {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        square_sum(&x)
+    }
+}
+
+impl IntervalObjective for Rastrigin {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        let n = x.len() as f64;
+        10.0*n + x.iter().map(|xi| xi.powi(2) - 10.0*(2.0*(*xi)*Interval::pi()).cos()).sum::<Interval>()
+    }
+}
+
+impl IntervalObjective for Rosenbrock {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        x.windows(2).map(|pair| 100.0*(pair[1] - pair[0].powi(2)).powi(2) + (1.0 - pair[0]).powi(2)).sum()
+    }
+}
+
+impl IntervalObjective for Ackley {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        let n = x.len() as f64;
+        let cosine_sum: Interval = x.iter().map(|xi| (2.0*Interval::pi()*(*xi)).cos()).sum();
+        -20.0*(-0.2*(square_sum(&x)/n).sqrt()).exp() - (cosine_sum/n).exp() + Interval::e() + 20.0
+    }
+}
+
+impl IntervalObjective for Griewank {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        let cosine_prod = x.iter().enumerate()
+            .map(|(i, xi)| (*xi/Interval::point((i + 1) as f64).sqrt()).cos())
+            .fold(Interval::point(1.0), |prod, c| prod*c);
+        1.0 + square_sum(&x)/4000.0 - cosine_prod
+    }
+}
+
+impl IntervalObjective for Zakharov {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        let sum_ixi: Interval = x.iter().enumerate().map(|(i, xi)| 0.5*(*xi)*((i + 1) as f64)).sum();
+        square_sum(&x) + sum_ixi.powi(2) + sum_ixi.powi(4)
+    }
+}
+
+impl IntervalObjective for Salomon {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        let r = square_sum(&x).sqrt();
+        1.0 - (2.0*Interval::pi()*r).cos() + 0.1*r
+    }
+}
+
+impl IntervalObjective for Exponential {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        -(-0.5*square_sum(&x)).exp()
+    }
+}
+
+impl IntervalObjective for ChungReynolds {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        square_sum(&x).powi(2)
+    }
+}
+
+impl IntervalObjective for BentCigar {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        x[0].powi(2) + 1e6*square_sum(&x[1..])
+    }
+}
+
+impl IntervalObjective for Discus {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        1e6*x[0].powi(2) + square_sum(&x[1..])
+    }
+}
+
+impl IntervalObjective for Matyas {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        0.26*(x[0].powi(2) + x[1].powi(2)) - 0.48*x[0]*x[1]
+    }
+}
+
+impl IntervalObjective for Booth {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        (x[0] + 2.0*x[1] - 7.0).powi(2) + (2.0*x[0] + x[1] - 5.0).powi(2)
+    }
+}
+
+impl IntervalObjective for Beale {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        (1.5 - x[0] + x[0]*x[1]).powi(2)
+            + (2.25 - x[0] + x[0]*x[1].powi(2)).powi(2)
+            + (2.625 - x[0] + x[0]*x[1].powi(3)).powi(2)
+    }
+}
+
+impl IntervalObjective for Himmelblau {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        (x[0].powi(2) + x[1] - 11.0).powi(2) + (x[0] + x[1].powi(2) - 7.0).powi(2)
+    }
+}
+
+impl IntervalObjective for ThreeHumpCamel {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        2.0*x[0].powi(2) - 1.05*x[0].powi(4) + x[0].powi(6)/6.0 + x[0]*x[1] + x[1].powi(2)
+    }
+}
+
+impl IntervalObjective for SixHumpCamel {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        (4.0 - 2.1*x[0].powi(2) + x[0].powi(4)/3.0)*x[0].powi(2) + x[0]*x[1] + (-4.0 + 4.0*x[1].powi(2))*x[1].powi(2)
+    }
+}
+
+impl IntervalObjective for Easom {
+    fn f_interval(x: Vec<Interval>) -> Interval {
+        check_box::<Self>(&x);
+        let pi = Interval::pi();
+        -x[0].cos()*x[1].cos()*(-(x[0] - pi).powi(2) - (x[1] - pi).powi(2)).exp()
+    }
+}
+
+/// The interval evaluation of a function
+pub type IntervalFunction = fn(Vec<Interval>) -> Interval;
+
+/// The functions with an interval evaluation, with their names in the registry
+const FUNCTIONS: &[(&str, IntervalFunction)] = &[
+    ("Sphere", Sphere::f_interval),
+    ("Rastrigin", Rastrigin::f_interval),
+    ("Rosenbrock", Rosenbrock::f_interval),
+    ("Ackley", Ackley::f_interval),
+    ("Griewank", Griewank::f_interval),
+    ("Zakharov", Zakharov::f_interval),
+    ("Salomon", Salomon::f_interval),
+    ("Exponential", Exponential::f_interval),
+    ("ChungReynolds", ChungReynolds::f_interval),
+    ("BentCigar", BentCigar::f_interval),
+    ("Discus", Discus::f_interval),
+    ("Matyas", Matyas::f_interval),
+    ("Booth", Booth::f_interval),
+    ("Beale", Beale::f_interval),
+    ("Himmelblau", Himmelblau::f_interval),
+    ("ThreeHumpCamel", ThreeHumpCamel::f_interval),
+    ("SixHumpCamel", SixHumpCamel::f_interval),
+    ("Easom", Easom::f_interval),
+];
+
+/// This function returns the interval evaluation of a function of the registry, if it has one
+pub fn find(name: &str) -> Option<IntervalFunction> {
+    FUNCTIONS.iter().find(|(n, _)| *n == name).map(|(_, f)| *f)
+}
+
+#[cfg(test)]
+mod interval_tests {
+    use super::{find, Interval, IntervalObjective, FUNCTIONS};
+    use crate::{Rng, Sphere, Ackley, Booth, Easom};
+    use crate::registry;
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Interval::new(-1.0, 2.0), Interval::new(3.0, 4.0));
+        assert_eq!(a + b, Interval::new(2.0, 6.0));
+        assert_eq!(a - b, Interval::new(-5.0, -1.0));
+        assert_eq!(a*b, Interval::new(-4.0, 8.0));
+        assert_eq!(a.powi(2), Interval::new(0.0, 4.0));
+        assert_eq!(a.powi(3), Interval::new(-1.0, 8.0));
+        assert_eq!(a.abs(), Interval::new(0.0, 2.0));
+        assert_eq!(a/Interval::new(-1.0, 1.0), Interval::entire());
+        let third = Interval::point(1.0)/3.0;
+        assert!(third.low < 1.0/3.0 && 1.0/3.0 < third.high);
+        let tenth = Interval::point(0.1) + 0.2;
+        assert!(tenth.contains(0.1 + 0.2) && tenth.low < tenth.high);
+        assert!(Interval::pi().contains(std::f64::consts::PI));
+        assert_eq!(Interval::new(0.0, 4.0).bisect(), (Interval::new(0.0, 2.0), Interval::new(2.0, 4.0)));
+    }
+
+    #[test]
+    fn trigonometry() {
+        let c = Interval::new(-0.5, 0.5).cos();
+        assert_eq!(c.high, 1.0);
+        assert!(c.contains(0.5f64.cos()) && c.low > 0.87);
+        assert_eq!(Interval::new(3.0, 3.3).cos().low, -1.0);
+        assert_eq!(Interval::new(0.0, 7.0).sin(), Interval::new(-1.0, 1.0));
+        let s = Interval::new(0.1, 0.2).sin();
+        assert!(s.contains(0.1f64.sin()) && s.contains(0.2f64.sin()) && s.high < 0.2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid() {
+        Interval::new(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dimension() {
+        Booth::f_interval(vec![Interval::point(0.0); 3]);
+    }
+
+    #[test]
+    fn minima() {
+        assert_eq!(Sphere::f_interval(vec![Interval::point(0.0); 4]), Interval::point(0.0));
+        assert!(Ackley::f_interval(vec![Interval::point(0.0); 2]).contains(0.0));
+        assert!(Easom::f_interval(vec![Interval::pi(); 2]).contains(-1.0));
+    }
+
+    #[test]
+    fn enclosures() {
+        let mut rng = Rng::new(0);
+        for (name, f_interval) in FUNCTIONS.iter() {
+            let function = registry::find(name).unwrap();
+            assert!(find(name).is_some());
+            for d in function.dimension.map_or(vec![1, 2, 5], |d| vec![d]) {
+                let bounds = (function.bounds)(d);
+                for _ in 0..50 {
+                    // Each box is a random fraction of the domain, at a random position
+                    let scale = 10f64.powi(-(rng.below(4) as i32));
+                    let boxed: Vec<Interval> = bounds.iter()
+                        .map(|(low, high)| {
+                            let (low, high) = (low.max(-10.0), high.min(10.0));
+                            let a = low + (high - low)*rng.uniform();
+                            Interval::new(a, (a + scale*(high - low)).min(high))
+                        })
+                        .collect();
+                    let enclosure = f_interval(boxed.clone());
+                    for _ in 0..20 {
+                        let x: Vec<f64> = boxed.iter().map(|xi| xi.low + (xi.high - xi.low)*rng.uniform()).collect();
+                        let fx = (function.f)(x.clone());
+                        assert!(enclosure.contains(fx), "{} at {:?} is {}, outside {:?}", name, x, fx, enclosure);
+                    }
+                }
+                let minimizer = (function.minimizer)(d);
+                let at_minimizer = f_interval(minimizer.iter().map(|xi| Interval::point(*xi)).collect());
+                assert!(at_minimizer.contains((function.f)(minimizer)), "{}", name);
+            }
+        }
+        assert!(find("Nonexistent").is_none());
+    }
+}
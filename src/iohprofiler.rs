@@ -0,0 +1,161 @@
+//! This module contains a writer for the IOHprofiler data format
+//!
+//! The output can be zipped and uploaded to the IOHanalyzer web tool. Each function of the suite
+//! gets a JSON meta-data file `IOHprofiler_f{id}_{name}.json` and a folder `data_f{id}_{name}` with
+//! one `.dat` file per dimensionality. Function ids are assigned in the order in which the functions
+//! first appear in the results, starting from 1. Each run in a `.dat` file starts with a header
+//! line and lists the evaluations at which the best value improved.
+//!
+//! ```no_run
+//! use benchfun::Ackley;
+//! use benchfun::harness::{Harness, problems, random_search};
+//! use benchfun::iohprofiler::write_iohprofiler;
+//!
+//! let results = Harness::new(problems::<Ackley>(&[2, 5])).run("random search", &mut random_search);
+//! write_iohprofiler(&results, "ioh-data").unwrap();
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use crate::harness::{Results, RunResult};
+use crate::json::{Json, ToJson};
+
+/// This function returns the distinct functions of a set of results, in order of appearance
+fn functions(results: &Results) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for run in results.runs.iter() {
+        if !names.contains(&run.problem) {
+            names.push(run.problem.clone());
+        }
+    }
+    names
+}
+
+/// This function returns the distinct dimensionalities of the runs on a function, in increasing order
+fn dimensions(results: &Results, function: &str) -> Vec<usize> {
+    let mut dims: Vec<usize> = results.runs.iter().filter(|r| r.problem == function).map(|r| r.dimension).collect();
+    dims.sort_unstable();
+    dims.dedup();
+    dims
+}
+
+/// This function returns the runs on a function at a dimensionality
+fn runs<'a>(results: &'a Results, function: &'a str, d: usize) -> impl Iterator<Item = &'a RunResult> {
+    results.runs.iter().filter(move |r| r.problem == function && r.dimension == d)
+}
+
+/// This function returns the contents of the `.dat` file of a function at a dimensionality
+pub fn dat_file(results: &Results, function: &str, d: usize) -> String {
+    let mut dat = String::new();
+    for run in runs(results, function, d) {
+        dat += "evaluations raw_y\n";
+        for (evaluation, f) in run.trace.iter() {
+            dat += &format!("{} {:e}\n", evaluation, f);
+        }
+    }
+    dat
+}
+
+/// This function returns the JSON meta-data of a function
+pub fn meta_data(results: &Results, function: &str, id: usize) -> Json {
+    let algorithm = results.runs.first().map_or(String::new(), |r| r.solver.clone());
+    let scenarios = dimensions(results, function).into_iter()
+        .map(|d| {
+            let runs = runs(results, function, d)
+                .map(|run| Json::Object(vec![
+                    ("instance".to_string(), 1usize.to_json()),
+                    ("evals".to_string(), run.evaluations.to_json()),
+                    ("best".to_string(), Json::Object(vec![
+                        ("evals".to_string(), run.trace.last().map_or(0, |(e, _)| *e).to_json()),
+                        ("y".to_string(), run.best_f.to_json()),
+                        ("x".to_string(), run.best_x.to_json()),
+                    ])),
+                ]))
+                .collect();
+            Json::Object(vec![
+                ("dimension".to_string(), d.to_json()),
+                ("path".to_string(), format!("data_f{}_{}/IOHprofiler_f{}_DIM{}.dat", id, function, id, d).to_json()),
+                ("runs".to_string(), Json::Array(runs)),
+            ])
+        })
+        .collect();
+    Json::Object(vec![
+        ("version".to_string(), "0.3.3".to_json()),
+        ("suite".to_string(), "benchfun".to_json()),
+        ("function_id".to_string(), id.to_json()),
+        ("function_name".to_string(), function.to_json()),
+        ("maximization".to_string(), false.to_json()),
+        ("algorithm".to_string(), Json::Object(vec![
+            ("name".to_string(), algorithm.to_json()),
+            ("info".to_string(), "".to_json()),
+        ])),
+        ("attributes".to_string(), vec!["evaluations".to_string(), "raw_y".to_string()].to_json()),
+        ("scenarios".to_string(), Json::Array(scenarios)),
+    ])
+}
+
+/// This function writes a set of results in the IOHprofiler format to a folder, creating it if
+/// needed. The results should come from a single optimizer.
+pub fn write_iohprofiler<P: AsRef<Path>>(results: &Results, folder: P) -> std::io::Result<()> {
+    let folder = folder.as_ref();
+    for (i, function) in functions(results).iter().enumerate() {
+        let id = i + 1;
+        let data = folder.join(format!("data_f{}_{}", id, function));
+        fs::create_dir_all(&data)?;
+        for d in dimensions(results, function) {
+            fs::write(data.join(format!("IOHprofiler_f{}_DIM{}.dat", id, d)), dat_file(results, function, d))?;
+        }
+        fs::write(folder.join(format!("IOHprofiler_f{}_{}.json", id, function)), meta_data(results, function, id).to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod iohprofiler_tests {
+    use super::{dat_file, meta_data, write_iohprofiler};
+    use crate::harness::{Harness, Budget, Evaluator, problems};
+    use crate::{Booth, Rastrigin, Rng};
+    use crate::json::Json;
+
+    fn results() -> crate::harness::Results {
+        let mut suite = problems::<Booth>(&[2]);
+        suite.extend(problems::<Rastrigin>(&[3, 2]));
+        let harness = Harness { budget: Budget::Fixed(5), repetitions: 2, ..Harness::new(suite) };
+        harness.run("descent", &mut |evaluator: &Evaluator, _: &mut Rng| {
+            for k in (0..5).rev() {
+                evaluator.f(vec![k as f64; evaluator.dimension()]);
+            }
+        })
+    }
+
+    #[test]
+    fn dat() {
+        let dat = dat_file(&results(), "Booth", 2);
+        let lines: Vec<&str> = dat.lines().collect();
+        assert_eq!(lines.iter().filter(|l| **l == "evaluations raw_y").count(), 2);
+        assert_eq!(lines[1], "1 7.4e1");
+        assert_eq!(lines.len(), 2*(1 + 3));
+    }
+
+    #[test]
+    fn meta() {
+        let meta = meta_data(&results(), "Rastrigin", 2);
+        assert_eq!(meta.field::<String>("function_name").unwrap(), "Rastrigin");
+        let scenarios = match meta.get("scenarios") {
+            Some(Json::Array(scenarios)) => scenarios.clone(),
+            _ => panic!(),
+        };
+        assert_eq!(scenarios.iter().map(|s| s.field::<usize>("dimension").unwrap()).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(scenarios[0].field::<String>("path").unwrap(), "data_f2_Rastrigin/IOHprofiler_f2_DIM2.dat");
+    }
+
+    #[test]
+    fn files() {
+        let folder = std::env::temp_dir().join(format!("benchfun-ioh-{}", std::process::id()));
+        write_iohprofiler(&results(), &folder).unwrap();
+        assert!(folder.join("IOHprofiler_f1_Booth.json").exists());
+        assert!(folder.join("data_f2_Rastrigin/IOHprofiler_f2_DIM3.dat").exists());
+        std::fs::remove_dir_all(folder).unwrap();
+    }
+}
@@ -0,0 +1,512 @@
+//! This module contains a small JSON representation for serializing problem descriptors and
+//! results. It is only available with the `json` feature, which the `server` and `wasm` features
+//! and the IOHprofiler export build on.
+//!
+//! Types that can be written to JSON implement `ToJson`, and types that can be read back implement
+//! `FromJson`. Non-finite numbers have no JSON representation, so they are written as `null` and
+//! read back as NaN. Text that nests arrays and objects more than `MAX_DEPTH` deep is rejected, so
+//! that parsing untrusted input cannot overflow the stack.
+//!
+//! This is not a serde integration. The crate does not depend on serde, so its types do not
+//! implement `Serialize` or `Deserialize`, and only JSON is supported, not YAML.
+//!
+//! ```
+//! use benchfun::Ackley;
+//! use benchfun::json::{Json, ToJson, FromJson};
+//! use benchfun::problem::Descriptor;
+//!
+//! let text = Descriptor::of::<Ackley>(3).to_json().to_string();
+//! let descriptor = Descriptor::from_json(&text.parse::<Json>().unwrap()).unwrap();
+//! assert_eq!(descriptor.name, "Ackley");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/// This is a JSON value
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    /// The `null` value
+    Null,
+    /// A boolean
+    Bool(bool),
+    /// A number
+    Number(f64),
+    /// A string
+    String(String),
+    /// An array of values
+    Array(Vec<Json>),
+    /// An object, with its members in order
+    Object(Vec<(String, Json)>),
+}
+
+/// This is an error encountered while parsing or converting JSON
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonError {
+    /// A description of the problem
+    pub message: String,
+}
+
+impl JsonError {
+    /// This function creates a new error with a message
+    pub fn new<S: Into<String>>(message: S) -> JsonError {
+        JsonError { message: message.into() }
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl Json {
+    /// This function returns the member of an object with a given key, if there is one
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// This function reads the member of an object with a given key, failing if it is missing
+    pub fn field<T: FromJson>(&self, key: &str) -> Result<T, JsonError> {
+        match self.get(key) {
+            Some(value) => T::from_json(value),
+            None => Err(JsonError::new(format!("missing field `{}`", key))),
+        }
+    }
+}
+
+/// This function writes a string with JSON escapes
+fn write_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(x) if !x.is_finite() => write!(f, "null"),
+            Json::Number(x) if x.fract() == 0.0 && x.abs() < 1e15 => write!(f, "{}", *x as i64),
+            Json::Number(x) => write!(f, "{:?}", x),
+            Json::String(s) => write_string(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// The deepest nesting of arrays and objects that the parser accepts
+pub const MAX_DEPTH: usize = 128;
+
+/// This is a recursive descent parser for JSON text
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, message: &str) -> Result<T, JsonError> {
+        Err(JsonError::new(format!("{} at byte {}", message, self.position)))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\n') | Some(b'\r') | Some(b'\t') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), JsonError> {
+        if self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(())
+        } else {
+            self.error(&format!("expected `{}`", literal))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, JsonError> {
+        self.skip_whitespace();
+        match self.bytes.get(self.position) {
+            Some(b'n') => self.expect("null").map(|_| Json::Null),
+            Some(b't') => self.expect("true").map(|_| Json::Bool(true)),
+            Some(b'f') => self.expect("false").map(|_| Json::Bool(false)),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'[') | Some(b'{') if self.depth >= MAX_DEPTH => self.error("nesting too deep"),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'{') => self.nested(Self::object),
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            Some(_) => self.error("unexpected character"),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Json, JsonError>) -> Result<Json, JsonError> {
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn number(&mut self) -> Result<Json, JsonError> {
+        let start = self.position;
+        while let Some(b'-') | Some(b'+') | Some(b'.') | Some(b'e') | Some(b'E') | Some(b'0'..=b'9') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+        let text = std::str::from_utf8(&self.bytes[start..self.position]).unwrap();
+        match text.parse::<f64>() {
+            Ok(x) => Ok(Json::Number(x)),
+            Err(_) => {
+                self.position = start;
+                self.error("invalid number")
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let digits = self.bytes.get(self.position..self.position + 4).and_then(|d| std::str::from_utf8(d).ok());
+        match digits.and_then(|d| u32::from_str_radix(d, 16).ok()) {
+            Some(code) => {
+                self.position += 4;
+                Ok(code)
+            }
+            None => self.error("invalid unicode escape"),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect("\"")?;
+        let mut s = String::new();
+        loop {
+            let start = self.position;
+            while let Some(b) = self.bytes.get(self.position) {
+                if *b == b'"' || *b == b'\\' || *b < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+            s += std::str::from_utf8(&self.bytes[start..self.position]).unwrap();
+            match self.bytes.get(self.position) {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(s);
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let escape = self.bytes.get(self.position).copied();
+                    self.position += 1;
+                    match escape {
+                        Some(b'"') => s.push('"'),
+                        Some(b'\\') => s.push('\\'),
+                        Some(b'/') => s.push('/'),
+                        Some(b'b') => s.push('\u{8}'),
+                        Some(b'f') => s.push('\u{c}'),
+                        Some(b'n') => s.push('\n'),
+                        Some(b'r') => s.push('\r'),
+                        Some(b't') => s.push('\t'),
+                        Some(b'u') => {
+                            let mut code = self.hex4()?;
+                            if (0xd800..0xdc00).contains(&code) {
+                                self.expect("\\u")?;
+                                let low = self.hex4()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return self.error("invalid surrogate pair");
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            match std::char::from_u32(code) {
+                                Some(c) => s.push(c),
+                                None => return self.error("invalid unicode escape"),
+                            }
+                        }
+                        _ => return self.error("invalid escape"),
+                    }
+                }
+                Some(_) => return self.error("control character in string"),
+                None => return self.error("unterminated string"),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, JsonError> {
+        self.expect("[")?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.position) == Some(&b']') {
+            self.position += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.bytes.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                }
+                _ => return self.error("expected `,` or `]`"),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, JsonError> {
+        self.expect("{")?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.position) == Some(&b'}') {
+            self.position += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(":")?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.bytes.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return self.error("expected `,` or `}`"),
+            }
+        }
+    }
+}
+
+impl FromStr for Json {
+    type Err = JsonError;
+
+    fn from_str(s: &str) -> Result<Json, JsonError> {
+        let mut parser = Parser { bytes: s.as_bytes(), position: 0, depth: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position < parser.bytes.len() {
+            return parser.error("trailing characters");
+        }
+        Ok(value)
+    }
+}
+
+/// This is a trait for types that can be written as JSON
+pub trait ToJson {
+    /// This function converts the value to JSON
+    fn to_json(&self) -> Json;
+}
+
+/// This is a trait for types that can be read from JSON
+pub trait FromJson: Sized {
+    /// This function converts JSON to a value
+    fn from_json(json: &Json) -> Result<Self, JsonError>;
+}
+
+impl ToJson for Json {
+    fn to_json(&self) -> Json {
+        self.clone()
+    }
+}
+
+impl ToJson for f64 {
+    fn to_json(&self) -> Json {
+        Json::Number(*self)
+    }
+}
+
+impl FromJson for f64 {
+    fn from_json(json: &Json) -> Result<f64, JsonError> {
+        match json {
+            Json::Number(x) => Ok(*x),
+            Json::Null => Ok(f64::NAN),
+            _ => Err(JsonError::new("expected a number")),
+        }
+    }
+}
+
+impl ToJson for usize {
+    fn to_json(&self) -> Json {
+        Json::Number(*self as f64)
+    }
+}
+
+impl FromJson for usize {
+    fn from_json(json: &Json) -> Result<usize, JsonError> {
+        match json {
+            Json::Number(x) if *x >= 0.0 && x.fract() == 0.0 => Ok(*x as usize),
+            _ => Err(JsonError::new("expected a non-negative integer")),
+        }
+    }
+}
+
+impl ToJson for bool {
+    fn to_json(&self) -> Json {
+        Json::Bool(*self)
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(json: &Json) -> Result<bool, JsonError> {
+        match json {
+            Json::Bool(b) => Ok(*b),
+            _ => Err(JsonError::new("expected a boolean")),
+        }
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> Json {
+        Json::String(self.clone())
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> Json {
+        Json::String(self.to_string())
+    }
+}
+
+impl FromJson for String {
+    fn from_json(json: &Json) -> Result<String, JsonError> {
+        match json {
+            Json::String(s) => Ok(s.clone()),
+            _ => Err(JsonError::new("expected a string")),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> Json {
+        Json::Array(self.iter().map(ToJson::to_json).collect())
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(json: &Json) -> Result<Vec<T>, JsonError> {
+        match json {
+            Json::Array(values) => values.iter().map(T::from_json).collect(),
+            _ => Err(JsonError::new("expected an array")),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> Json {
+        self.as_ref().map_or(Json::Null, ToJson::to_json)
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(json: &Json) -> Result<Option<T>, JsonError> {
+        match json {
+            Json::Null => Ok(None),
+            value => T::from_json(value).map(Some),
+        }
+    }
+}
+
+impl<A: ToJson, B: ToJson> ToJson for (A, B) {
+    fn to_json(&self) -> Json {
+        Json::Array(vec![self.0.to_json(), self.1.to_json()])
+    }
+}
+
+impl<A: FromJson, B: FromJson> FromJson for (A, B) {
+    fn from_json(json: &Json) -> Result<(A, B), JsonError> {
+        match json {
+            Json::Array(values) if values.len() == 2 => Ok((A::from_json(&values[0])?, B::from_json(&values[1])?)),
+            _ => Err(JsonError::new("expected an array of two values")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::{Json, ToJson, FromJson, MAX_DEPTH};
+    use crate::{Shift, Himmelblau};
+    use crate::grid::{grid_evaluate, Grid};
+
+    #[test]
+    fn write() {
+        let json = Json::Object(vec![
+            ("a".to_string(), vec![1.0, 0.5, 1e-20, f64::NAN].to_json()),
+            ("b\n\"".to_string(), Json::Bool(true)),
+            ("c".to_string(), Some("\u{1}é".to_string()).to_json()),
+        ]);
+        assert_eq!(json.to_string(), "{\"a\":[1,0.5,1e-20,null],\"b\\n\\\"\":true,\"c\":\"\\u0001é\"}");
+    }
+
+    #[test]
+    fn parse() {
+        let json: Json = " {\"x\" : [1, -2.5e3, true, null], \"s\": \"a\\u00e9\\ud83d\\ude00\\/\"} ".parse().unwrap();
+        assert_eq!(json.field::<Vec<Option<f64>>>("x").ok(), None);
+        assert_eq!(json.get("x"), Some(&Json::Array(vec![Json::Number(1.0), Json::Number(-2500.0), Json::Bool(true), Json::Null])));
+        assert_eq!(json.field::<String>("s").unwrap(), "aé😀/");
+        assert!(json.field::<f64>("missing").is_err());
+        assert!("[1, 2".parse::<Json>().is_err());
+        assert!("{} x".parse::<Json>().is_err());
+        assert!("\"\\x\"".parse::<Json>().is_err());
+    }
+
+    #[test]
+    fn depth() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(nested(MAX_DEPTH).parse::<Json>().is_ok());
+        assert_eq!(nested(MAX_DEPTH + 1).parse::<Json>().unwrap_err().message, format!("nesting too deep at byte {}", MAX_DEPTH));
+        assert!("{\"a\":".repeat(200_000).parse::<Json>().is_err());
+        assert!("[".repeat(200_000).parse::<Json>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let values = vec![(0.1, 2.0), (-1e300, 3.0_f64.sqrt())];
+        let text = values.to_json().to_string();
+        assert_eq!(Vec::<(f64, f64)>::from_json(&text.parse().unwrap()).unwrap(), values);
+        assert_eq!(usize::from_json(&Json::Number(1.5)).ok(), None);
+    }
+
+    #[test]
+    fn round_trip_types() {
+        let shift = Shift::random(4, 3);
+        assert_eq!(Shift::from_json(&shift.to_json()).unwrap().x_opt, shift.x_opt);
+        let grid = grid_evaluate::<Himmelblau>(5);
+        assert_eq!(Grid::from_json(&grid.to_json().to_string().parse().unwrap()).unwrap(), grid);
+    }
+}
@@ -0,0 +1,179 @@
+//! This module contains a wrapper that makes benchmark functions artificially expensive
+//!
+//! Real objective functions are often simulations that take seconds or hours. Adding a delay to
+//! each evaluation of an analytic function lets schedulers and asynchronous optimizers be tested
+//! under realistic timing, while keeping the known optimum of the function.
+//!
+//! ```
+//! use std::time::Duration;
+//! use benchfun::Booth;
+//! use benchfun::latency::{Delay, Expensive};
+//!
+//! let expensive = Expensive::<Booth>::new(Delay::Fixed(Duration::from_millis(2)), 0);
+//! let start = std::time::Instant::now();
+//! expensive.f(vec![1.0, 3.0]);
+//! assert!(start.elapsed() >= Duration::from_millis(2));
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::{SingleObjective, Parameterized, Rng};
+
+/// This enum describes the delay added to each evaluation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Delay {
+    /// The same delay for every evaluation
+    Fixed(Duration),
+    /// A delay drawn uniformly between a minimum and a maximum
+    Uniform(Duration, Duration),
+    /// A delay drawn from an exponential distribution with a given mean
+    Exponential(Duration),
+}
+
+impl Delay {
+    /// This function draws a delay
+    pub fn sample(&self, rng: &mut Rng) -> Duration {
+        match *self {
+            Delay::Fixed(delay) => delay,
+            Delay::Uniform(low, high) => low + (high.saturating_sub(low)).mul_f64(rng.uniform()),
+            Delay::Exponential(mean) => mean.mul_f64(-(1.0 - rng.uniform()).ln()),
+        }
+    }
+}
+
+/// This is a wrapper around a benchmark function that delays each evaluation.
+///
+/// `f` blocks the calling thread for the delay. Asynchronous code should instead draw the delay
+/// with `next_delay`, wait for it with its own timer, and then call `f_immediate`.
+#[derive(Debug)]
+pub struct Expensive<F> {
+    delay: Delay,
+    rng: Mutex<Rng>,
+    function: PhantomData<fn() -> F>,
+}
+
+impl<F> Expensive<F> {
+    /// This function creates a wrapper with a delay, drawing random delays from a seed
+    pub fn new(delay: Delay, seed: u64) -> Expensive<F> {
+        Expensive { delay, rng: Mutex::new(Rng::new(seed)), function: PhantomData }
+    }
+
+    /// This function draws the delay of the next evaluation
+    pub fn next_delay(&self) -> Duration {
+        self.delay.sample(&mut self.rng.lock().unwrap())
+    }
+}
+
+impl<F: SingleObjective> Expensive<F> {
+    /// Function for evaluating, which blocks for the delay before returning
+    pub fn f(&self, x: Vec<f64>) -> f64 {
+        std::thread::sleep(self.next_delay());
+        F::f(x)
+    }
+
+    /// Function for evaluating without the delay, for callers that wait for it themselves
+    pub fn f_immediate(&self, x: Vec<f64>) -> f64 {
+        F::f(x)
+    }
+}
+
+impl<F: SingleObjective> Parameterized for Expensive<F> {
+    /// Function for evaluating, which blocks for the delay before returning
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        self.f(x)
+    }
+
+    /// The global minimum is that of the wrapped function
+    fn global_minimum(&self) -> Option<f64> {
+        Some(F::MINIMUM)
+    }
+
+    /// The minimizer is that of the wrapped function
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        Some(F::minimizer(n))
+    }
+}
+
+/// This is a future that completes after a delay, without depending on any particular async
+/// runtime. It is only available with the `async` feature.
+///
+/// The first time the future is polled before its deadline, it starts a helper thread that wakes
+/// the task at the deadline.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct Sleep {
+    deadline: std::time::Instant,
+    waker: Option<std::sync::Arc<Mutex<Option<std::task::Waker>>>>,
+}
+
+#[cfg(feature = "async")]
+impl Sleep {
+    /// This function creates a future that completes after a delay
+    pub fn new(delay: Duration) -> Sleep {
+        Sleep { deadline: std::time::Instant::now() + delay, waker: None }
+    }
+}
+
+#[cfg(feature = "async")]
+impl std::future::Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, context: &mut std::task::Context) -> std::task::Poll<()> {
+        let now = std::time::Instant::now();
+        if now >= self.deadline {
+            return std::task::Poll::Ready(());
+        }
+        match &self.waker {
+            Some(waker) => *waker.lock().unwrap() = Some(context.waker().clone()),
+            None => {
+                let waker = std::sync::Arc::new(Mutex::new(Some(context.waker().clone())));
+                let shared = waker.clone();
+                let remaining = self.deadline - now;
+                std::thread::spawn(move || {
+                    std::thread::sleep(remaining);
+                    if let Some(waker) = shared.lock().unwrap().take() {
+                        waker.wake();
+                    }
+                });
+                self.waker = Some(waker);
+            }
+        }
+        std::task::Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod latency_tests {
+    use super::{Delay, Expensive};
+    use crate::{Parameterized, Booth, Rng};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn delays() {
+        let mut rng = Rng::new(15);
+        let (low, high) = (Duration::from_millis(10), Duration::from_millis(20));
+        for _ in 0..1000 {
+            let delay = Delay::Uniform(low, high).sample(&mut rng);
+            assert!(low <= delay && delay <= high);
+        }
+        let mean = Duration::from_millis(10);
+        let total: Duration = (0..10_000).map(|_| Delay::Exponential(mean).sample(&mut rng)).sum();
+        assert!((total.as_secs_f64()/10_000.0 - 0.01).abs() < 0.0005);
+        assert_eq!(Delay::Fixed(mean).sample(&mut rng), mean);
+    }
+
+    #[test]
+    fn blocking() {
+        let expensive = Expensive::<Booth>::new(Delay::Uniform(Duration::from_millis(1), Duration::from_millis(3)), 16);
+        let start = Instant::now();
+        for _ in 0..5 {
+            expensive.check_global_minimizer(2);
+        }
+        assert!(start.elapsed() >= Duration::from_millis(5));
+        assert_eq!(expensive.f_immediate(vec![1.0, 3.0]), 0.0);
+        let repeated = Expensive::<Booth>::new(Delay::Exponential(Duration::from_millis(1)), 16);
+        assert_eq!(repeated.next_delay(), Expensive::<Booth>::new(Delay::Exponential(Duration::from_millis(1)), 16).next_delay());
+    }
+}
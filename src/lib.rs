@@ -11,11 +11,62 @@ pub mod multi;
 pub use multi::*;
 pub mod single;
 pub use single::*;
+pub mod rng;
+pub use rng::Rng;
+pub mod instance;
+pub use instance::Shift;
+pub mod tolerance;
+pub use tolerance::Tolerance;
+pub mod checked;
+pub use checked::NonFiniteError;
+pub mod sampling;
+pub mod grid;
+pub mod csv;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod problem;
+pub mod registry;
+pub mod total;
+pub mod reference;
+pub mod consistency;
+pub mod testing;
+pub mod feasibility;
+pub mod catalog;
+pub use problem::Problem;
+pub mod dataset;
+pub mod arrays;
+pub mod counted;
+pub mod cached;
+pub mod recorder;
+pub mod stats;
+pub mod latency;
+pub mod timing;
+pub mod harness;
+pub mod experiment;
+#[cfg(feature = "json")]
+pub mod iohprofiler;
+pub mod ecdf;
+pub mod profiles;
+pub mod comparison;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "plot")]
+pub mod plot;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "interval")]
+pub mod interval;
 
 
 /// This is a trait that ensures consistent implementation of single objective benchmark functions
 pub trait SingleObjective  {
-    /// The global minimum is constant and zero
+    /// The global minimum
     const MINIMUM: f64;
 
     /// Function for evaluating the objective function
@@ -24,9 +75,140 @@ pub trait SingleObjective  {
     /// This function returns the minimizer (argument that will return the global minimum)
     fn minimizer(n: usize) -> Vec<f64>;
 
-    /// This function is used for testing, and checks the correctness of the minimizer
+    /// This function evaluates the objective function, and returns an error instead of a value that
+    /// is NaN or infinite, such as at a point outside of the domain of the function
+    fn f_checked(x: Vec<f64>) -> Result<f64, NonFiniteError> {
+        let value = Self::f(x.clone());
+        NonFiniteError::check(&x, &[value]).map(|_| value)
+    }
+
+    /// This function evaluates the objective function at each of a set of points, such as a
+    /// generation of a population-based optimizer. With the `parallel` feature, the points are
+    /// evaluated across threads.
+    fn f_batch(points: &[Vec<f64>]) -> Vec<f64> {
+        #[cfg(feature = "parallel")]
+        return parallel::f_batch::<Self>(points, parallel::DEFAULT_CHUNK_SIZE);
+        #[cfg(not(feature = "parallel"))]
+        points.iter().map(|x| Self::f(x.clone())).collect()
+    }
+
+    /// This function evaluates the objective function at each row of a row-major matrix of points
+    /// with `d` columns
+    fn f_batch_flat(points: &[f64], d: usize) -> Vec<f64> {
+        assert!(d > 0 && points.len().is_multiple_of(d), "The flattened points must have a whole number of rows of length {}.", d);
+        Self::f_batch(&points.chunks_exact(d).map(<[f64]>::to_vec).collect::<Vec<_>>())
+    }
+
+    /// This function returns the global minimum for a given dimensionality. It only needs to be
+    /// overridden by functions whose global minimum depends on the dimensionality.
+    fn minimum(_n: usize) -> f64 {
+        Self::MINIMUM
+    }
+
+    /// This function is used for testing, and checks that the minimizer attains the global minimum
+    /// to within the default tolerance
     fn check_minimizer(d: usize) {
-        assert!(Self::f(Self::minimizer(d)) - Self::MINIMUM < f64::EPSILON)
+        Self::check_minimizer_with(d, Tolerance::default())
+    }
+
+    /// This function is used for testing, and checks that the minimizer attains the global minimum
+    /// exactly, for functions where this holds in floating point
+    fn check_minimizer_strict(d: usize) {
+        Self::check_minimizer_with(d, Tolerance::exact())
+    }
+
+    /// This function is used for testing, and checks the correctness of the minimizer to within an
+    /// absolute tolerance, for functions whose global minimum is only known numerically
+    fn check_minimizer_within(d: usize, tolerance: f64) {
+        Self::check_minimizer_with(d, Tolerance::absolute(tolerance))
+    }
+
+    /// This function is used for testing, and checks that the minimizer attains the global minimum
+    /// to within an absolute and relative tolerance
+    fn check_minimizer_with(d: usize, tolerance: Tolerance) {
+        let (value, minimum) = (Self::f(Self::minimizer(d)), Self::minimum(d));
+        assert!(tolerance.accepts(value, minimum), "The minimizer attains {} instead of {} in {} dimensions.", value, minimum, d)
+    }
+}
+
+/// This is a trait that ensures consistent implementation of benchmark functions with analytic gradients
+pub trait Differentiable: SingleObjective {
+    /// Function for evaluating the gradient of the objective function
+    fn gradient(x: Vec<f64>) -> Vec<f64>;
+
+    /// This function is used for testing, and checks the gradient against central finite differences
+    fn check_gradient(x: Vec<f64>, tolerance: f64) {
+        let gradient = Self::gradient(x.clone());
+        assert_eq!(gradient.len(), x.len());
+        let h = 1e-6;
+        for (i, gi) in gradient.iter().enumerate() {
+            let mut forward = x.clone();
+            let mut backward = x.clone();
+            forward[i] += h;
+            backward[i] -= h;
+            let estimate = (Self::f(forward) - Self::f(backward))/(2.0*h);
+            assert!((gi - estimate).abs() < tolerance*(1.0 + gi.abs()))
+        }
+    }
+}
+
+/// This is a trait that ensures consistent implementation of benchmark functions with several global minimizers
+///
+/// For these functions, `SingleObjective::minimizer` returns one canonical representative, and this
+/// trait records how many global minimizers there are and where they are.
+pub trait MultipleMinimizers: SingleObjective {
+    /// This function returns the number of global minimizers, saturating at `usize::MAX`
+    fn minimizer_count(n: usize) -> usize;
+
+    /// This function returns every global minimizer, including the canonical one
+    fn all_minimizers(n: usize) -> Vec<Vec<f64>>;
+
+    /// This function is used for testing, and checks the correctness of every minimizer
+    fn check_all_minimizers(d: usize) {
+        Self::check_all_minimizers_within(d, f64::EPSILON)
+    }
+
+    /// This function is used for testing, and checks the correctness of every minimizer to within a
+    /// tolerance, for functions whose global minimum is only known numerically
+    fn check_all_minimizers_within(d: usize, tolerance: f64) {
+        let minimizers = Self::all_minimizers(d);
+        assert_eq!(minimizers.len(), Self::minimizer_count(d));
+        assert!(minimizers.contains(&Self::minimizer(d)));
+        for x in minimizers {
+            assert!((Self::f(x) - Self::minimum(d)).abs() < tolerance)
+        }
+    }
+}
+
+/// This is a trait that ensures consistent implementation of stochastic benchmark functions
+///
+/// For these functions, `SingleObjective::f` evaluates the noise-free part of the function, and
+/// `f_noisy` adds noise drawn from a seedable random number generator.
+pub trait Stochastic: SingleObjective {
+    /// Function for evaluating the objective function with noise
+    fn f_noisy(x: Vec<f64>, rng: &mut Rng) -> f64;
+}
+
+/// This is a trait that ensures consistent implementation of parameterized benchmark functions
+///
+/// The parameters of these functions are stored in the fields of their struct, so each value of the
+/// struct is an instance of the function. Where such a function also implements `SingleObjective`,
+/// that implementation uses the default parameters.
+pub trait Parameterized {
+    /// Function for evaluating this instance of the objective function
+    fn evaluate(&self, x: Vec<f64>) -> f64;
+
+    /// The global minimum of this instance, if it is known
+    fn global_minimum(&self) -> Option<f64>;
+
+    /// This function returns the minimizer of this instance, if it is known
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>>;
+
+    /// This function is used for testing, and checks the correctness of the minimizer of this instance
+    fn check_global_minimizer(&self, d: usize) {
+        let minimum = self.global_minimum().expect("The global minimum of this instance is not known.");
+        let minimizer = self.global_minimizer(d).expect("The minimizer of this instance is not known.");
+        assert!((self.evaluate(minimizer) - minimum).abs() < f64::EPSILON)
     }
 }
 
@@ -37,24 +219,72 @@ pub trait MultiObjective {
 
     /// Function for evaluating the set of objective functions
     fn f(x: Vec<f64>) -> Vec<f64>;
+
+    /// This function evaluates the objective functions, and returns an error if any of them is NaN or
+    /// infinite
+    fn f_checked(x: Vec<f64>) -> Result<Vec<f64>, NonFiniteError> {
+        let values = Self::f(x.clone());
+        NonFiniteError::check(&x, &values).map(|_| values)
+    }
+
+    /// This function evaluates the objective functions at each of a set of points. With the
+    /// `parallel` feature, the points are evaluated across threads.
+    fn f_batch(points: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        #[cfg(feature = "parallel")]
+        return parallel::f_batch_multi::<Self>(points, parallel::DEFAULT_CHUNK_SIZE);
+        #[cfg(not(feature = "parallel"))]
+        points.iter().map(|x| Self::f(x.clone())).collect()
+    }
+
+    /// This function evaluates the objective functions at each row of a row-major matrix of points
+    /// with `d` columns, returning a row-major matrix with `NF` columns
+    fn f_batch_flat(points: &[f64], d: usize) -> Vec<f64> {
+        assert!(d > 0 && points.len().is_multiple_of(d), "The flattened points must have a whole number of rows of length {}.", d);
+        Self::f_batch(&points.chunks_exact(d).map(<[f64]>::to_vec).collect::<Vec<_>>()).concat()
+    }
 }
 
 /// This is a trait that ensures consistent implementation of bounded benchmark functions
 pub trait Bounded {
-    /// The bounds of the canonical optimization problem
+    /// The bounds of the canonical optimization problem. For functions with different bounds for
+    /// each dimension, this is the smallest interval that contains all of them.
     const BOUNDS: (f64, f64);
 
+    /// This function returns the bounds of each dimension. It only needs to be overridden by
+    /// functions with different bounds for each dimension.
+    fn bounds(n: usize) -> Vec<(f64, f64)> {
+        vec![Self::BOUNDS; n]
+    }
+
     /// Function to check bounds
     fn in_bounds(x: Vec<f64>) -> bool {
         let mut in_bounds = true;
-        for element in x {
-            if (element < Self::BOUNDS.0) || (element > Self::BOUNDS.1) {
+        for (element, bounds) in x.iter().zip(Self::bounds(x.len())) {
+            if (*element < bounds.0) || (*element > bounds.1) {
                 in_bounds = false;
                 break;
             }
         }
         in_bounds
     }
+
+    /// This function is used for testing, and checks that there are finite, ordered bounds for each
+    /// dimension, and that `BOUNDS` contains all of them
+    fn check_bounds(d: usize) {
+        let bounds = Self::bounds(d);
+        assert_eq!(bounds.len(), d, "There are bounds for {} dimensions instead of {}.", bounds.len(), d);
+        for (i, (low, high)) in bounds.iter().enumerate() {
+            assert!(low.is_finite() && high.is_finite() && low < high, "The bounds ({}, {}) of dimension {} are not finite and ordered.", low, high, i);
+            assert!(Self::BOUNDS.0 <= *low && *high <= Self::BOUNDS.1, "The bounds ({}, {}) of dimension {} are outside {:?}.", low, high, i, Self::BOUNDS);
+        }
+    }
+
+    /// This function is used for testing, and checks that the minimizer is within the bounds
+    fn check_minimizer_in_bounds(d: usize) where Self: SingleObjective {
+        let minimizer = Self::minimizer(d);
+        assert_eq!(minimizer.len(), d, "The minimizer has {} dimensions instead of {}.", minimizer.len(), d);
+        assert!(Self::in_bounds(minimizer.clone()), "The minimizer {:?} is outside the bounds {:?}.", minimizer, Self::bounds(d));
+    }
 }
 
 /// This is a trait that ensures consistent implementation of unbounded benchmark functions
@@ -102,6 +332,19 @@ pub trait UnConstrained {
     const CONSTRAINED: bool = false;
 }
 
+/// This is a trait that ensures consistent implementation of smooth benchmark functions
+pub trait Smooth {
+    /// This constant indicates that the function is differentiable everywhere
+    const SMOOTH: bool = true;
+}
+
+/// This is a trait that ensures consistent implementation of non-smooth benchmark functions
+pub trait NonSmooth {
+    /// This constant indicates that the function is not differentiable everywhere, so gradient
+    /// information may be missing or misleading
+    const SMOOTH: bool = false;
+}
+
 /// This is a trait that ensures consistent implementation of N-dimensional benchmark functions
 pub trait NDimensional {
     /// This is a constant containing the correct dimensionality for the function
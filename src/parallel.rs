@@ -0,0 +1,92 @@
+//! This module contains parallel batch evaluation. It is only available with the `parallel`
+//! feature, which also makes `f_batch` evaluate points across threads.
+//!
+//! Points are split into chunks, and the chunks are handed out to one worker thread per available
+//! core. Larger chunks reduce the overhead of distributing work and suit cheap functions, while
+//! smaller chunks balance the load better for expensive ones.
+//!
+//! ```
+//! use benchfun::{Whitley, Rng};
+//! use benchfun::sampling::uniform_in_bounds;
+//!
+//! let points = uniform_in_bounds::<Whitley>(1000, 10, &mut Rng::new(0));
+//! let values = benchfun::parallel::f_batch::<Whitley>(&points, 16);
+//! assert_eq!(values.len(), 1000);
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::{SingleObjective, MultiObjective};
+
+/// The chunk size used by `f_batch` when the `parallel` feature is enabled
+pub const DEFAULT_CHUNK_SIZE: usize = 64;
+
+/// This function applies a function to every item of a slice across threads, handing out chunks of
+/// `chunk_size` items at a time, and returns the results in order
+pub fn map_chunks<T: Sync, R: Send, G: Fn(&T) -> R + Sync>(items: &[T], chunk_size: usize, g: G) -> Vec<R> {
+    assert!(chunk_size > 0, "the chunk size must be positive");
+    let n_chunks = items.len().div_ceil(chunk_size);
+    let n_threads = std::thread::available_parallelism().map_or(1, |n| n.get()).min(n_chunks);
+    if n_threads <= 1 {
+        return items.iter().map(g).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new(Vec::with_capacity(n_chunks));
+    std::thread::scope(|scope| {
+        for _ in 0..n_threads {
+            scope.spawn(|| loop {
+                let chunk = next.fetch_add(1, Ordering::Relaxed);
+                if chunk >= n_chunks {
+                    break;
+                }
+                let start = chunk*chunk_size;
+                let results: Vec<R> = items[start..(start + chunk_size).min(items.len())].iter().map(&g).collect();
+                finished.lock().unwrap().push((chunk, results));
+            });
+        }
+    });
+    let mut finished = finished.into_inner().unwrap();
+    finished.sort_unstable_by_key(|(chunk, _)| *chunk);
+    finished.into_iter().flat_map(|(_, results)| results).collect()
+}
+
+/// This function evaluates a single-objective function at each of a set of points across threads
+pub fn f_batch<F: SingleObjective + ?Sized>(points: &[Vec<f64>], chunk_size: usize) -> Vec<f64> {
+    let f: fn(Vec<f64>) -> f64 = F::f;
+    map_chunks(points, chunk_size, |x| f(x.clone()))
+}
+
+/// This function evaluates a multi-objective function at each of a set of points across threads
+pub fn f_batch_multi<F: MultiObjective + ?Sized>(points: &[Vec<f64>], chunk_size: usize) -> Vec<Vec<f64>> {
+    let f: fn(Vec<f64>) -> Vec<f64> = F::f;
+    map_chunks(points, chunk_size, |x| f(x.clone()))
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::{map_chunks, f_batch, f_batch_multi};
+    use crate::{SingleObjective, MultiObjective, Rastrigin, ChankongHaimes, Rng};
+    use crate::sampling::uniform_in_bounds;
+
+    #[test]
+    fn order() {
+        let items: Vec<usize> = (0..1000).collect();
+        for chunk_size in [1, 7, 64, 1000, 5000].iter() {
+            assert_eq!(map_chunks(&items, *chunk_size, |i| 2*i), (0..1000).map(|i| 2*i).collect::<Vec<usize>>());
+        }
+        assert!(map_chunks(&[] as &[usize], 3, |i| *i).is_empty());
+    }
+
+    #[test]
+    fn batch() {
+        let points = uniform_in_bounds::<Rastrigin>(500, 5, &mut Rng::new(12));
+        let serial: Vec<f64> = points.iter().map(|x| Rastrigin::f(x.clone())).collect();
+        assert_eq!(f_batch::<Rastrigin>(&points, 10), serial);
+        assert_eq!(Rastrigin::f_batch(&points), serial);
+        let multi: Vec<Vec<f64>> = points.iter().map(|x| ChankongHaimes::f(x[..2].to_vec())).collect();
+        let pairs: Vec<Vec<f64>> = points.iter().map(|x| x[..2].to_vec()).collect();
+        assert_eq!(f_batch_multi::<ChankongHaimes>(&pairs, 3), multi);
+    }
+}
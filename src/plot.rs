@@ -0,0 +1,255 @@
+//! This module contains functions for rendering heatmaps and contour plots of 2-D benchmark
+//! functions. It is only available with the `plot` feature.
+//!
+//! Function values are shown on a logarithmic color scale relative to the smallest value on the
+//! grid, so that the basins of functions with large ranges remain visible. The canonical minimizer
+//! is marked in red.
+//!
+//! ```
+//! use benchfun::Himmelblau;
+//! use benchfun::plot::render_svg;
+//!
+//! let svg = render_svg::<Himmelblau>(50, 10);
+//! assert!(svg.starts_with("<svg"));
+//! ```
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::{Bounded, SingleObjective};
+use crate::grid::{grid_evaluate, Grid};
+use crate::arrays::crc32;
+
+/// The anchor colors of the colormap, which approximates viridis
+const COLORMAP: [(f64, f64, f64); 5] = [
+    (68.0, 1.0, 84.0),
+    (59.0, 82.0, 139.0),
+    (33.0, 145.0, 140.0),
+    (94.0, 201.0, 98.0),
+    (253.0, 231.0, 37.0),
+];
+
+/// The width and height of a rendered SVG, in pixels
+const SVG_SIZE: f64 = 500.0;
+
+/// This function maps a value from [0, 1] to a color
+fn color(v: f64) -> [u8; 3] {
+    let t = v.clamp(0.0, 1.0)*(COLORMAP.len() - 1) as f64;
+    let k = (t.floor() as usize).min(COLORMAP.len() - 2);
+    let (a, b) = (COLORMAP[k], COLORMAP[k + 1]);
+    let s = t - k as f64;
+    [(a.0 + s*(b.0 - a.0)).round() as u8, (a.1 + s*(b.1 - a.1)).round() as u8, (a.2 + s*(b.2 - a.2)).round() as u8]
+}
+
+/// This function rescales the grid values to [0, 1] on a logarithmic scale
+fn normalize(grid: &Grid) -> Vec<Vec<f64>> {
+    let finite = grid.z.iter().flatten().filter(|z| z.is_finite());
+    let low = finite.clone().fold(f64::INFINITY, |a, b| a.min(*b));
+    let high = finite.fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+    let range = (high - low).ln_1p();
+    grid.z.iter()
+        .map(|row| row.iter().map(|z| if range > 0.0 { (z - low).ln_1p()/range } else { 0.0 }).collect())
+        .collect()
+}
+
+/// This function returns the position of the canonical minimizer in grid units, where grid node
+/// `(i, j)` is at `(i, j)`
+fn minimizer_position<F: SingleObjective + Bounded>(resolution: usize) -> (f64, f64) {
+    let bounds = F::bounds(2);
+    let minimizer = F::minimizer(2);
+    let scale = (resolution - 1) as f64;
+    (
+        (minimizer[0] - bounds[0].0)/(bounds[0].1 - bounds[0].0)*scale,
+        (minimizer[1] - bounds[1].0)/(bounds[1].1 - bounds[1].0)*scale,
+    )
+}
+
+/// This function returns the contour line segments of a normalized grid at a level, in grid units
+fn contour_segments(values: &[Vec<f64>], level: f64) -> Vec<((f64, f64), (f64, f64))> {
+    let mut segments = Vec::new();
+    for j in 0..values.len() - 1 {
+        for i in 0..values[j].len() - 1 {
+            let corners = [
+                ((i as f64, j as f64), values[j][i]),
+                ((i as f64 + 1.0, j as f64), values[j][i + 1]),
+                ((i as f64 + 1.0, j as f64 + 1.0), values[j + 1][i + 1]),
+                ((i as f64, j as f64 + 1.0), values[j + 1][i]),
+            ];
+            let mut crossings = Vec::with_capacity(4);
+            for k in 0..4 {
+                let ((xa, ya), va) = corners[k];
+                let ((xb, yb), vb) = corners[(k + 1) % 4];
+                if (va < level) != (vb < level) {
+                    let s = (level - va)/(vb - va);
+                    crossings.push((xa + s*(xb - xa), ya + s*(yb - ya)));
+                }
+            }
+            for pair in crossings.chunks_exact(2) {
+                segments.push((pair[0], pair[1]));
+            }
+        }
+    }
+    segments
+}
+
+/// This function renders a heatmap of a 2-D function with `levels` contour lines as an SVG
+/// document, evaluating the function on a `resolution` by `resolution` grid.
+pub fn render_svg<F: SingleObjective + Bounded>(resolution: usize, levels: usize) -> String {
+    let grid = grid_evaluate::<F>(resolution);
+    let values = normalize(&grid);
+    let cell = SVG_SIZE/resolution as f64;
+    let to_pixels = |(x, y): (f64, f64)| ((x + 0.5)*cell, SVG_SIZE - (y + 0.5)*cell);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\" shape-rendering=\"crispEdges\">\n",
+        SVG_SIZE
+    );
+    for (j, row) in values.iter().enumerate() {
+        for (i, v) in row.iter().enumerate() {
+            let [r, g, b] = color(*v);
+            let (x, y) = to_pixels((i as f64, j as f64));
+            svg += &format!(
+                "<rect x=\"{:.3}\" y=\"{:.3}\" width=\"{:.3}\" height=\"{:.3}\" fill=\"rgb({},{},{})\"/>\n",
+                x - cell/2.0, y - cell/2.0, cell, cell, r, g, b
+            );
+        }
+    }
+    for k in 1..=levels {
+        let level = k as f64/(levels + 1) as f64;
+        let mut path = String::new();
+        for (a, b) in contour_segments(&values, level) {
+            let (a, b) = (to_pixels(a), to_pixels(b));
+            path += &format!("M{:.3} {:.3}L{:.3} {:.3}", a.0, a.1, b.0, b.1);
+        }
+        if !path.is_empty() {
+            svg += &format!("<path d=\"{}\" fill=\"none\" stroke=\"white\" stroke-opacity=\"0.6\" shape-rendering=\"auto\"/>\n", path);
+        }
+    }
+    let (x, y) = to_pixels(minimizer_position::<F>(resolution));
+    svg += &format!(
+        "<path class=\"minimizer\" d=\"M{:.3} {:.3}l12 12m0 -12l-12 12\" stroke=\"red\" stroke-width=\"3\" shape-rendering=\"auto\"/>\n",
+        x - 6.0, y - 6.0
+    );
+    svg += "</svg>\n";
+    svg
+}
+
+/// This function writes the SVG rendered by `render_svg` to a file
+pub fn save_svg<F: SingleObjective + Bounded, P: AsRef<Path>>(path: P, resolution: usize, levels: usize) -> std::io::Result<()> {
+    File::create(path)?.write_all(render_svg::<F>(resolution, levels).as_bytes())
+}
+
+/// This function wraps data in an uncompressed zlib stream
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        stream.push(if blocks.peek().is_none() { 1 } else { 0 });
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    stream.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    stream
+}
+
+/// This function appends a chunk to a PNG file
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// This function renders a heatmap of a 2-D function as a PNG image, with one pixel for each point
+/// of a `resolution` by `resolution` grid.
+pub fn render_png<F: SingleObjective + Bounded>(resolution: usize) -> Vec<u8> {
+    let grid = grid_evaluate::<F>(resolution);
+    let values = normalize(&grid);
+    let mut pixels: Vec<Vec<[u8; 3]>> = values.iter().rev()
+        .map(|row| row.iter().map(|v| color(*v)).collect())
+        .collect();
+    let (x, y) = minimizer_position::<F>(resolution);
+    let (x, y) = (x.round() as isize, (resolution - 1) as isize - y.round() as isize);
+    let arm = (resolution/50).max(1) as isize;
+    for k in -arm..=arm {
+        for (px, py) in [(x + k, y + k), (x + k, y - k)].iter() {
+            if (0..resolution as isize).contains(px) && (0..resolution as isize).contains(py) {
+                pixels[*py as usize][*px as usize] = [255, 0, 0];
+            }
+        }
+    }
+
+    let mut raw = Vec::with_capacity(resolution*(3*resolution + 1));
+    for row in pixels {
+        raw.push(0);
+        raw.extend(row.into_iter().flatten());
+    }
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(resolution as u32).to_be_bytes());
+    header.extend_from_slice(&(resolution as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    push_chunk(&mut png, b"IHDR", &header);
+    push_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// This function writes the PNG rendered by `render_png` to a file
+pub fn save_png<F: SingleObjective + Bounded, P: AsRef<Path>>(path: P, resolution: usize) -> std::io::Result<()> {
+    File::create(path)?.write_all(&render_png::<F>(resolution))
+}
+
+#[cfg(test)]
+mod plot_tests {
+    use super::{render_svg, render_png, contour_segments, color};
+    use crate::arrays::crc32;
+    use crate::{Himmelblau, Booth};
+
+    #[test]
+    fn colormap() {
+        assert_eq!(color(0.0), [68, 1, 84]);
+        assert_eq!(color(1.0), [253, 231, 37]);
+        assert_eq!(color(2.0), [253, 231, 37]);
+    }
+
+    #[test]
+    fn contours() {
+        let values = vec![vec![0.0, 0.0], vec![0.0, 1.0]];
+        assert_eq!(contour_segments(&values, 0.5), vec![((1.0, 0.5), (0.5, 1.0))]);
+    }
+
+    #[test]
+    fn svg() {
+        let svg = render_svg::<Himmelblau>(20, 5);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect").count(), 400);
+        assert!(svg.contains("class=\"minimizer\""));
+        assert!(svg.matches("<path").count() > 1);
+    }
+
+    #[test]
+    fn png() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        let png = render_png::<Booth>(64);
+        assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 64, 0, 0, 0, 64]);
+        assert_eq!(u32::from_be_bytes([png[29], png[30], png[31], png[32]]), crc32(&png[12..29]));
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+}
@@ -0,0 +1,279 @@
+//! This module contains runtime descriptions of benchmark problems
+//!
+//! The functions in this crate are described by traits at compile time. The types in this module
+//! capture the same information as plain values, so that it can be stored, compared and
+//! serialized, and so that problems of different types can be collected and evaluated together.
+//!
+//! ```
+//! use benchfun::{Ackley, Branin, Problem};
+//!
+//! let problems = vec![Problem::new::<Ackley>(5), Problem::new::<Branin>(2)];
+//! for problem in problems.iter() {
+//!     assert_eq!(problem.f(problem.minimizer()), problem.minimum());
+//! }
+//! assert_eq!(problems[0].counts().objective, 1);
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use crate::{Bounded, SingleObjective, Rng};
+use crate::counted::{Counters, Counts};
+#[cfg(feature = "json")]
+use crate::json::{Json, JsonError, ToJson, FromJson};
+use crate::latency::Delay;
+
+/// This function returns the name of a benchmark function, which is the name of its type
+pub fn name<F: ?Sized>() -> &'static str {
+    let full = std::any::type_name::<F>();
+    let base = full.split('<').next().unwrap_or(full);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+/// This is a description of a bounded single-objective problem at a given dimensionality
+#[derive(Clone, Debug, PartialEq)]
+pub struct Descriptor {
+    /// The name of the function
+    pub name: String,
+    /// The number of dimensions
+    pub dimension: usize,
+    /// The bounds of each dimension
+    pub bounds: Vec<(f64, f64)>,
+    /// The global minimum
+    pub minimum: f64,
+    /// The global minimizer
+    pub minimizer: Vec<f64>,
+}
+
+impl Descriptor {
+    /// This function describes a function at a given dimensionality
+    pub fn of<F: SingleObjective + Bounded>(d: usize) -> Descriptor {
+        Descriptor {
+            name: name::<F>().to_string(),
+            dimension: d,
+            bounds: F::bounds(d),
+            minimum: F::minimum(d),
+            minimizer: F::minimizer(d),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Descriptor {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("name".to_string(), self.name.to_json()),
+            ("dimension".to_string(), self.dimension.to_json()),
+            ("bounds".to_string(), self.bounds.to_json()),
+            ("minimum".to_string(), self.minimum.to_json()),
+            ("minimizer".to_string(), self.minimizer.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Descriptor {
+    fn from_json(json: &Json) -> Result<Descriptor, JsonError> {
+        Ok(Descriptor {
+            name: json.field("name")?,
+            dimension: json.field("dimension")?,
+            bounds: json.field("bounds")?,
+            minimum: json.field("minimum")?,
+            minimizer: json.field("minimizer")?,
+        })
+    }
+}
+
+/// This is a bounded single-objective problem at a given dimensionality, which can be evaluated
+/// without knowing the type of its function.
+///
+/// Evaluations are counted, and clones of a problem share their counts. A problem can optionally
+/// delay its evaluations, as in the `latency` module.
+#[derive(Clone, Debug)]
+pub struct Problem {
+    descriptor: Descriptor,
+    objective: fn(Vec<f64>) -> f64,
+    counters: Arc<Counters>,
+    latency: Option<(Delay, Arc<Mutex<Rng>>)>,
+}
+
+impl Problem {
+    /// This function creates a problem from a function at a given dimensionality
+    pub fn new<F: SingleObjective + Bounded>(d: usize) -> Problem {
+        Problem { descriptor: Descriptor::of::<F>(d), objective: F::f, counters: Arc::default(), latency: None }
+    }
+
+    /// This function adds a delay to each evaluation, drawing random delays from a seed
+    pub fn with_latency(self, delay: Delay, seed: u64) -> Problem {
+        Problem { latency: Some((delay, Arc::new(Mutex::new(Rng::new(seed))))), ..self }
+    }
+
+    /// This function returns the description of the problem
+    pub fn descriptor(&self) -> &Descriptor {
+        &self.descriptor
+    }
+
+    /// This function returns the name of the function
+    pub fn name(&self) -> &str {
+        &self.descriptor.name
+    }
+
+    /// This function returns the number of dimensions
+    pub fn dimension(&self) -> usize {
+        self.descriptor.dimension
+    }
+
+    /// This function returns the bounds of each dimension
+    pub fn bounds(&self) -> &[(f64, f64)] {
+        &self.descriptor.bounds
+    }
+
+    /// This function returns the global minimum
+    pub fn minimum(&self) -> f64 {
+        self.descriptor.minimum
+    }
+
+    /// This function returns the global minimizer
+    pub fn minimizer(&self) -> Vec<f64> {
+        self.descriptor.minimizer.clone()
+    }
+
+    /// This function returns the number of evaluations so far
+    pub fn counts(&self) -> Counts {
+        self.counters.counts()
+    }
+
+    /// This function sets the evaluation counts back to zero
+    pub fn reset_counts(&self) {
+        self.counters.reset()
+    }
+
+    /// This function draws the delay of the next evaluation, which is zero without latency
+    pub fn next_delay(&self) -> std::time::Duration {
+        match &self.latency {
+            Some((delay, rng)) => delay.sample(&mut rng.lock().unwrap()),
+            None => std::time::Duration::ZERO,
+        }
+    }
+
+    /// This function evaluates the function and counts the evaluation, without any delay
+    fn evaluate_now(&self, x: Vec<f64>) -> f64 {
+        self.counters.add_objective(1);
+        (self.objective)(x)
+    }
+
+    /// Function for evaluating, which counts the evaluation and blocks for its delay
+    pub fn f(&self, x: Vec<f64>) -> f64 {
+        let delay = self.next_delay();
+        if delay > std::time::Duration::ZERO {
+            std::thread::sleep(delay);
+        }
+        self.evaluate_now(x)
+    }
+
+    /// This function evaluates the function at each of a set of points
+    pub fn f_batch(&self, points: &[Vec<f64>]) -> Vec<f64> {
+        points.iter().map(|x| self.f(x.clone())).collect()
+    }
+
+    /// Function for evaluating asynchronously, which counts the evaluation and waits for its delay
+    /// without blocking the executor. The future does not depend on any particular async runtime.
+    #[cfg(feature = "async")]
+    pub async fn f_async(&self, x: Vec<f64>) -> f64 {
+        let delay = self.next_delay();
+        if delay > std::time::Duration::ZERO {
+            crate::latency::Sleep::new(delay).await;
+        }
+        self.evaluate_now(x)
+    }
+}
+
+#[cfg(test)]
+mod problem_tests {
+    use super::{name, Descriptor, Problem};
+    use crate::{Ackley, BukinN6, Branin, Booth, SingleObjective};
+    use crate::latency::Delay;
+    use std::time::{Duration, Instant};
+    #[cfg(feature = "json")]
+    use crate::json::{ToJson, FromJson};
+
+    #[test]
+    fn names() {
+        assert_eq!(name::<Ackley>(), "Ackley");
+        assert_eq!(name::<Vec<Ackley>>(), "Vec");
+    }
+
+    #[test]
+    fn descriptor() {
+        let descriptor = Descriptor::of::<BukinN6>(2);
+        assert_eq!(descriptor.bounds, vec![(-15.0, -5.0), (-3.0, 3.0)]);
+        assert_eq!(descriptor.minimizer, vec![-10.0, 1.0]);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let descriptor = Descriptor::of::<BukinN6>(2);
+        let json = descriptor.to_json();
+        assert_eq!(json.to_string(), "{\"name\":\"BukinN6\",\"dimension\":2,\"bounds\":[[-15,-5],[-3,3]],\"minimum\":0,\"minimizer\":[-10,1]}");
+        assert_eq!(Descriptor::from_json(&json).unwrap(), descriptor);
+        let branin = Descriptor::of::<Branin>(2);
+        assert_eq!(Descriptor::from_json(&branin.to_json().to_string().parse().unwrap()).unwrap(), branin);
+    }
+
+    #[test]
+    fn problem() {
+        let problem = Problem::new::<Branin>(2);
+        assert_eq!(problem.name(), "Branin");
+        assert_eq!(problem.f(vec![1.0, 2.0]), Branin::f(vec![1.0, 2.0]));
+        let clone = problem.clone();
+        clone.f_batch(&[problem.minimizer(), problem.minimizer()]);
+        assert_eq!(problem.counts().objective, 3);
+        problem.reset_counts();
+        assert_eq!(clone.counts().objective, 0);
+    }
+
+    #[test]
+    fn latency() {
+        let problem = Problem::new::<Booth>(2).with_latency(Delay::Fixed(Duration::from_millis(3)), 0);
+        let start = Instant::now();
+        assert_eq!(problem.f(vec![1.0, 3.0]), 0.0);
+        assert!(start.elapsed() >= Duration::from_millis(3));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn asynchronous() {
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake};
+
+        struct Unpark(std::thread::Thread);
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark()
+            }
+        }
+        fn block_on<T>(future: impl Future<Output = T>) -> T {
+            let mut future = Box::pin(future);
+            let waker = Arc::new(Unpark(std::thread::current())).into();
+            let mut context = Context::from_waker(&waker);
+            loop {
+                match future.as_mut().poll(&mut context) {
+                    Poll::Ready(value) => return value,
+                    Poll::Pending => std::thread::park(),
+                }
+            }
+        }
+
+        let problem = Problem::new::<Booth>(2).with_latency(Delay::Fixed(Duration::from_millis(20)), 0);
+        let start = Instant::now();
+        let values = block_on(async {
+            let (a, b) = (problem.f_async(vec![1.0, 3.0]), problem.f_async(vec![0.0, 0.0]));
+            vec![a.await, b.await]
+        });
+        assert_eq!(values, vec![0.0, 74.0]);
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert_eq!(problem.counts().objective, 2);
+        assert_eq!(block_on(Problem::new::<Booth>(2).f_async(vec![1.0, 3.0])), 0.0);
+    }
+}
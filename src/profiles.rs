@@ -0,0 +1,164 @@
+//! This module contains performance and data profiles for comparing optimizers
+//!
+//! These are the profiles of Moré and Wild (2009). Each run of a repetition of a problem is a
+//! problem of the profiles, and is solved by an optimizer once its best value satisfies the
+//! convergence test `f(x) <= f_L + tau*(f_0 - f_L)`, where `f_0` is the value of the first evaluation
+//! (the smallest first value over the optimizers, so that the test is the same for all of them) and
+//! `f_L` is a reference value. The performance profile of an optimizer is the fraction of problems it
+//! solves within a factor `alpha` of the evaluations of the fastest optimizer, and the data profile
+//! is the fraction it solves within `kappa` simplex gradients, that is `kappa*(d + 1)` evaluations.
+//!
+//! ```
+//! use benchfun::{Ackley, Rastrigin};
+//! use benchfun::harness::{Harness, Budget, problems, random_search};
+//! use benchfun::profiles::{Profiles, Reference};
+//!
+//! let mut suite = problems::<Ackley>(&[2, 3]);
+//! suite.extend(problems::<Rastrigin>(&[2]));
+//! let harness = Harness { budget: Budget::PerDimension(50), repetitions: 3, ..Harness::new(suite) };
+//! let random = harness.run("random search", &mut random_search);
+//! let center = harness.run("center", &mut |evaluator: &benchfun::harness::Evaluator, _: &mut benchfun::Rng| {
+//!     evaluator.f(vec![0.0; evaluator.dimension()]);
+//! });
+//! let profiles = Profiles::new(&[random, center], 1e-3, Reference::Best);
+//! let performance = profiles.performance_profile(&[1.0, 2.0, 4.0, 8.0]);
+//! let data = profiles.data_profile(&[1.0, 10.0, 100.0]);
+//! assert_eq!(performance.len(), 2);
+//! assert_eq!(data[1], vec![1.0, 1.0, 1.0]);
+//! ```
+
+use crate::harness::{Results, RunResult};
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+
+/// This enum lists the reference values `f_L` of the convergence test
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reference {
+    /// The global minimum of the function
+    Minimum,
+    /// The best value found by any of the optimizers
+    Best,
+}
+
+/// This struct holds the number of evaluations each optimizer needed to solve each problem
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profiles {
+    /// The names of the optimizers
+    pub solvers: Vec<String>,
+    /// The function, number of dimensions and repetition of each problem
+    pub problems: Vec<(String, usize, usize)>,
+    /// The number of evaluations needed by each optimizer on each problem, indexed by problem and
+    /// then optimizer, or `None` if the optimizer did not solve the problem
+    pub evaluations: Vec<Vec<Option<usize>>>,
+}
+
+impl Profiles {
+    /// This function applies the convergence test with tolerance `tau` to the results of a set of
+    /// optimizers on the same suite. Problems are taken from the first set of results, and count as
+    /// unsolved for optimizers without a run on them.
+    pub fn new(results: &[Results], tau: f64, reference: Reference) -> Profiles {
+        assert!(tau > 0.0 && tau < 1.0, "The tolerance must be between 0 and 1.");
+        let solvers = results.iter().map(|r| r.runs.first().map_or(String::new(), |run| run.solver.clone())).collect();
+        let mut problems = Vec::new();
+        let mut evaluations = Vec::new();
+        for run in results.first().map_or(&[][..], |r| &r.runs[..]) {
+            let runs: Vec<Option<&RunResult>> = results.iter()
+                .map(|r| r.runs.iter().find(|other| other.problem == run.problem && other.dimension == run.dimension && other.repetition == run.repetition))
+                .collect();
+            let first = runs.iter().flatten().filter_map(|r| r.trace.first()).map(|(_, f)| *f).fold(f64::INFINITY, f64::min);
+            let lower = match reference {
+                Reference::Minimum => run.minimum,
+                Reference::Best => runs.iter().flatten().map(|r| r.best_f).fold(f64::INFINITY, f64::min),
+            };
+            let threshold = lower + tau*(first - lower);
+            evaluations.push(runs.iter()
+                .map(|r| r.and_then(|r| r.trace.iter().find(|(_, f)| *f <= threshold).map(|(e, _)| *e)))
+                .collect());
+            problems.push((run.problem.clone(), run.dimension, run.repetition));
+        }
+        Profiles { solvers, problems, evaluations }
+    }
+
+    /// This function returns the performance profile of each optimizer at each ratio `alpha`
+    pub fn performance_profile(&self, alphas: &[f64]) -> Vec<Vec<f64>> {
+        let ratios: Vec<Vec<Option<f64>>> = self.evaluations.iter()
+            .map(|row| {
+                let fastest = row.iter().flatten().min().copied();
+                row.iter().map(|e| e.zip(fastest).map(|(e, fastest)| e as f64/fastest as f64)).collect()
+            })
+            .collect();
+        self.profile(alphas, |p, s| ratios[p][s])
+    }
+
+    /// This function returns the data profile of each optimizer at each number of simplex gradients `kappa`
+    pub fn data_profile(&self, kappas: &[f64]) -> Vec<Vec<f64>> {
+        self.profile(kappas, |p, s| self.evaluations[p][s].map(|e| e as f64/(self.problems[p].1 + 1) as f64))
+    }
+
+    /// This function returns the fraction of problems with a cost at most each of a set of levels, for each optimizer
+    fn profile<G: Fn(usize, usize) -> Option<f64>>(&self, levels: &[f64], cost: G) -> Vec<Vec<f64>> {
+        let n = self.problems.len();
+        (0..self.solvers.len())
+            .map(|s| levels.iter()
+                .map(|level| {
+                    let solved = (0..n).filter(|p| cost(*p, s).is_some_and(|c| c <= *level)).count();
+                    if n == 0 { 0.0 } else { solved as f64/n as f64 }
+                })
+                .collect())
+            .collect()
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Profiles {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("solvers".to_string(), self.solvers.to_json()),
+            ("problems".to_string(), Json::Array(self.problems.iter()
+                .map(|(problem, dimension, repetition)| Json::Object(vec![
+                    ("problem".to_string(), problem.to_json()),
+                    ("dimension".to_string(), dimension.to_json()),
+                    ("repetition".to_string(), repetition.to_json()),
+                ]))
+                .collect())),
+            ("evaluations".to_string(), self.evaluations.to_json()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod profiles_tests {
+    use super::{Profiles, Reference};
+    use crate::harness::{Harness, Budget, Evaluator, Results, problems};
+    use crate::{Booth, Rng};
+
+    fn results(points: &'static [f64]) -> Results {
+        let harness = Harness { budget: Budget::Fixed(points.len()), repetitions: 1, ..Harness::new(problems::<Booth>(&[2])) };
+        harness.run(&format!("{:?}", points), &mut |evaluator: &Evaluator, _: &mut Rng| {
+            for k in points.iter() {
+                evaluator.f(vec![*k, 3.0*k]);
+            }
+        })
+    }
+
+    #[test]
+    fn convergence() {
+        // Booth is 74*(k - 1)^2 at (k, 3k), so 74, 18.5, 2.96 and 0.185 for the points below
+        let slow = results(&[0.0, 0.5, 0.8, 0.95]);
+        let fast = results(&[0.0, 0.8]);
+        let profiles = Profiles::new(&[slow.clone(), fast.clone()], 0.1, Reference::Minimum);
+        assert_eq!(profiles.problems, vec![("Booth".to_string(), 2, 0)]);
+        assert_eq!(profiles.evaluations, vec![vec![Some(3), Some(2)]]);
+        let strict = Profiles::new(&[slow.clone(), fast.clone()], 1e-2, Reference::Minimum);
+        assert_eq!(strict.evaluations, vec![vec![Some(4), None]]);
+        let relative = Profiles::new(&[slow, fast], 1e-2, Reference::Best);
+        assert_eq!(relative.evaluations, vec![vec![Some(4), None]]);
+    }
+
+    #[test]
+    fn profiles() {
+        let profiles = Profiles::new(&[results(&[0.0, 0.5, 0.8]), results(&[0.0, 0.8])], 0.1, Reference::Minimum);
+        assert_eq!(profiles.performance_profile(&[1.0, 1.5]), vec![vec![0.0, 1.0], vec![1.0, 1.0]]);
+        assert_eq!(profiles.data_profile(&[0.5, 1.0]), vec![vec![0.0, 1.0], vec![0.0, 1.0]]);
+    }
+}
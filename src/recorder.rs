@@ -0,0 +1,277 @@
+//! This module contains a wrapper that records the evaluation history of a benchmark function
+//!
+//! Every evaluation is logged with its constraint violation and the best feasible value found so
+//! far, so that convergence curves can be drawn directly from the record. For long runs, the
+//! record can be thinned to bound its memory use.
+//!
+//! ```
+//! use benchfun::Himmelblau;
+//! use benchfun::recorder::{Recorder, Thinning};
+//!
+//! let recorder = Recorder::<Himmelblau>::new(Thinning::Improvements);
+//! recorder.f(vec![0.0, 0.0]);
+//! recorder.f(vec![1.0, 1.0]);
+//! recorder.f(vec![3.0, 2.0]);
+//! assert_eq!(recorder.best(), Some((vec![3.0, 2.0], 0.0)));
+//! ```
+
+use std::io::{Result, Write};
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::{SingleObjective, Constrained};
+#[cfg(feature = "json")]
+use crate::json::{Json, JsonError, ToJson, FromJson};
+
+/// This struct holds one recorded evaluation
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// The number of the evaluation, starting from one
+    pub evaluation: usize,
+    /// The evaluated point
+    pub x: Vec<f64>,
+    /// The objective function value
+    pub f: f64,
+    /// The total constraint violation, which is zero for feasible points
+    pub violation: f64,
+    /// The best objective function value of any feasible point so far
+    pub best: f64,
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Record {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("evaluation".to_string(), self.evaluation.to_json()),
+            ("x".to_string(), self.x.to_json()),
+            ("f".to_string(), self.f.to_json()),
+            ("violation".to_string(), self.violation.to_json()),
+            ("best".to_string(), self.best.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Record {
+    fn from_json(json: &Json) -> std::result::Result<Record, JsonError> {
+        Ok(Record {
+            evaluation: json.field("evaluation")?,
+            x: json.field("x")?,
+            f: json.field("f")?,
+            violation: json.field("violation")?,
+            best: json.field("best").map(|best: Option<f64>| best.unwrap_or(f64::INFINITY))?,
+        })
+    }
+}
+
+/// This enum selects which evaluations are kept in a record
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Thinning {
+    /// Every evaluation is kept
+    All,
+    /// Only evaluations that improve the best feasible value are kept
+    Improvements,
+    /// Every n-th evaluation is kept
+    Every(usize),
+    /// Evaluations are kept at this many logarithmically spaced points per decade of the evaluation
+    /// count, along with every improvement, so that memory grows only logarithmically
+    Logarithmic(usize),
+    /// Only the most recent n evaluations are kept
+    Last(usize),
+}
+
+/// This struct holds the mutable state of a recorder
+#[derive(Debug)]
+struct State {
+    evaluations: usize,
+    best: Option<(Vec<f64>, f64)>,
+    next_logarithmic: usize,
+    records: Vec<Record>,
+}
+
+/// This function returns the total constraint violation of a point
+pub fn violation<F: Constrained>(x: Vec<f64>) -> f64 {
+    let h: f64 = F::h(x.clone()).iter().map(|hi| hi.abs()).sum();
+    let g: f64 = F::g(x).iter().map(|gi| gi.max(0.0)).sum();
+    h + g
+}
+
+/// This is a wrapper around a benchmark function that records its evaluations
+#[derive(Debug)]
+pub struct Recorder<F> {
+    thinning: Thinning,
+    state: Mutex<State>,
+    function: PhantomData<fn() -> F>,
+}
+
+impl<F> Recorder<F> {
+    /// This function creates an empty recorder
+    pub fn new(thinning: Thinning) -> Recorder<F> {
+        match thinning {
+            Thinning::Every(0) | Thinning::Logarithmic(0) | Thinning::Last(0) => panic!("The thinning parameter must be positive."),
+            _ => {}
+        }
+        Recorder {
+            thinning,
+            state: Mutex::new(State { evaluations: 0, best: None, next_logarithmic: 0, records: Vec::new() }),
+            function: PhantomData,
+        }
+    }
+
+    /// This function records an evaluation that was made elsewhere
+    pub fn record(&self, x: Vec<f64>, f: f64, violation: f64) {
+        let mut state = self.state.lock().unwrap();
+        state.evaluations += 1;
+        let evaluation = state.evaluations;
+        let improved = violation <= 0.0 && state.best.as_ref().is_none_or(|(_, best)| f < *best);
+        if improved {
+            state.best = Some((x.clone(), f));
+        }
+        let keep = match self.thinning {
+            Thinning::All | Thinning::Last(_) => true,
+            Thinning::Improvements => improved,
+            Thinning::Every(n) => evaluation.is_multiple_of(n),
+            Thinning::Logarithmic(per_decade) => {
+                let target = |j: usize| 10f64.powf(j as f64/per_decade as f64) - 1e-9;
+                let due = evaluation as f64 >= target(state.next_logarithmic);
+                while evaluation as f64 >= target(state.next_logarithmic) {
+                    state.next_logarithmic += 1;
+                }
+                due || improved
+            }
+        };
+        if keep {
+            let best = state.best.as_ref().map_or(f64::INFINITY, |(_, best)| *best);
+            state.records.push(Record { evaluation, x, f, violation, best });
+            if let Thinning::Last(n) = self.thinning {
+                if state.records.len() > n {
+                    state.records.remove(0);
+                }
+            }
+        }
+    }
+
+    /// This function returns the number of evaluations, including those that were thinned out
+    pub fn evaluations(&self) -> usize {
+        self.state.lock().unwrap().evaluations
+    }
+
+    /// This function returns the best feasible point and its value, if any feasible point has been
+    /// evaluated
+    pub fn best(&self) -> Option<(Vec<f64>, f64)> {
+        self.state.lock().unwrap().best.clone()
+    }
+
+    /// This function returns the kept records
+    pub fn records(&self) -> Vec<Record> {
+        self.state.lock().unwrap().records.clone()
+    }
+
+    /// This function writes the kept records to CSV, as described in the `csv` module
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<()> {
+        crate::csv::write_records(writer, &self.records())
+    }
+
+    /// This function returns the kept records as JSON, along with the evaluation count and the best
+    /// point
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Json {
+        let best = self.best();
+        Json::Object(vec![
+            ("evaluations".to_string(), self.evaluations().to_json()),
+            ("best_x".to_string(), best.as_ref().map(|(x, _)| x.clone()).to_json()),
+            ("best_f".to_string(), best.map(|(_, f)| f).to_json()),
+            ("records".to_string(), self.records().to_json()),
+        ])
+    }
+}
+
+impl<F: SingleObjective> Recorder<F> {
+    /// Function for evaluating, which records the evaluation
+    pub fn f(&self, x: Vec<f64>) -> f64 {
+        let fx = F::f(x.clone());
+        self.record(x, fx, 0.0);
+        fx
+    }
+}
+
+impl<F: SingleObjective + Constrained> Recorder<F> {
+    /// Function for evaluating a constrained function, which records the evaluation along with its
+    /// constraint violation and returns both
+    pub fn f_constrained(&self, x: Vec<f64>) -> (f64, f64) {
+        let fx = F::f(x.clone());
+        let v = violation::<F>(x.clone());
+        self.record(x, fx, v);
+        (fx, v)
+    }
+}
+
+#[cfg(test)]
+mod recorder_tests {
+    use super::{Recorder, Thinning};
+    use crate::{Booth, RosenbrockConst1};
+    #[cfg(feature = "json")]
+    use super::Record;
+    #[cfg(feature = "json")]
+    use crate::json::FromJson;
+
+    fn run(thinning: Thinning, n: usize) -> Recorder<Booth> {
+        let recorder = Recorder::<Booth>::new(thinning);
+        for i in 0..n {
+            recorder.f(vec![(i % 7) as f64 - 2.0, 3.0]);
+        }
+        recorder
+    }
+
+    #[test]
+    fn all() {
+        let recorder = run(Thinning::All, 10);
+        let records = recorder.records();
+        assert_eq!(records.len(), 10);
+        assert_eq!(records.iter().map(|r| r.evaluation).collect::<Vec<_>>(), (1..=10).collect::<Vec<_>>());
+        assert!(records.windows(2).all(|w| w[1].best <= w[0].best));
+        assert_eq!(recorder.best(), Some((vec![1.0, 3.0], 0.0)));
+    }
+
+    #[test]
+    fn thinning() {
+        let improvements = run(Thinning::Improvements, 100).records();
+        assert!(improvements.windows(2).all(|w| w[1].f < w[0].f));
+        assert_eq!(improvements.last().unwrap().f, 0.0);
+        assert_eq!(run(Thinning::Every(10), 100).records().len(), 10);
+        let last = run(Thinning::Last(5), 100).records();
+        assert_eq!(last.iter().map(|r| r.evaluation).collect::<Vec<_>>(), vec![96, 97, 98, 99, 100]);
+        let logarithmic = run(Thinning::Logarithmic(2), 10_000);
+        assert_eq!(logarithmic.evaluations(), 10_000);
+        assert!(logarithmic.records().len() < 20);
+        assert!(logarithmic.records().iter().any(|r| r.evaluation == 10_000));
+    }
+
+    #[test]
+    fn constrained() {
+        let recorder = Recorder::<RosenbrockConst1>::new(Thinning::All);
+        assert_eq!(recorder.f_constrained(vec![1.5, 1.5]), (56.5, 1.0));
+        assert_eq!(recorder.f_constrained(vec![1.0, 1.0]), (0.0, 0.0));
+        assert_eq!(recorder.records()[0].best, f64::INFINITY);
+        assert_eq!(recorder.records()[1].best, 0.0);
+    }
+
+    #[test]
+    fn export() {
+        let recorder = run(Thinning::All, 3);
+        let mut buffer = Vec::new();
+        recorder.write_csv(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "evaluation,x0,x1,f,violation,best\n1,-2,3,45,0,45\n2,-1,3,20,0,20\n3,0,3,5,0,5\n");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let recorder = run(Thinning::All, 3);
+        let json = recorder.to_json();
+        assert_eq!(json.field::<usize>("evaluations").unwrap(), 3);
+        assert_eq!(Vec::<Record>::from_json(json.get("records").unwrap()).unwrap(), recorder.records());
+        let empty = Recorder::<Booth>::new(Thinning::All).to_json().to_string();
+        assert_eq!(empty, "{\"evaluations\":0,\"best_x\":null,\"best_f\":null,\"records\":[]}");
+    }
+}
@@ -0,0 +1,140 @@
+//! This module contains reference values of the single-objective functions
+//!
+//! For every function of the registry, the reference table holds its value at its global minimizer
+//! and at three points spread over its domain, with N-dimensional functions described in 2 and 5
+//! dimensions. The values were computed by `data/reference.py`, which implements each function
+//! independently from the formula given by its source, so checking the functions of this crate
+//! against the table catches errors in their formulas.
+//!
+//! ```
+//! use benchfun::Tolerance;
+//! use benchfun::registry::find;
+//! use benchfun::reference::{values, mismatches};
+//!
+//! assert_eq!(values("Zakharov").len(), 8);
+//! assert!(mismatches(find("Zakharov").unwrap(), Tolerance::new(1e-12, 1e-12)).is_empty());
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::Tolerance;
+use crate::registry::Function;
+
+/// The reference table, with a row for each point
+const TABLE: &str = include_str!("../data/reference.csv");
+
+/// This struct holds the value of a function at a point
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferenceValue {
+    /// The name of the function
+    pub function: String,
+    /// The point
+    pub x: Vec<f64>,
+    /// The value of the function at the point
+    pub f: f64,
+}
+
+/// This struct describes a point where a function differs from its reference value
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    /// The point
+    pub x: Vec<f64>,
+    /// The reference value
+    pub expected: f64,
+    /// The value of the function
+    pub actual: f64,
+}
+
+/// This function returns every reference value, grouped by function in the order of the registry
+pub fn reference_values() -> &'static [ReferenceValue] {
+    static VALUES: OnceLock<Vec<ReferenceValue>> = OnceLock::new();
+    VALUES.get_or_init(|| parse(TABLE).unwrap_or_else(|e| panic!("The reference table is invalid: {}", e)))
+}
+
+/// This function parses a table of values with the columns `function`, `x` and `f`, where the
+/// coordinates of `x` are separated by spaces, and returns a description of the first invalid row
+pub fn parse(table: &str) -> Result<Vec<ReferenceValue>, String> {
+    table.lines().skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            let number = |s: &str| s.trim().parse::<f64>().map_err(|_| format!("invalid number {} in row {}", s, line));
+            if fields.len() != 3 {
+                return Err(format!("invalid row {}", line));
+            }
+            Ok(ReferenceValue {
+                function: fields[0].to_string(),
+                x: fields[1].split_whitespace().map(number).collect::<Result<_, _>>()?,
+                f: number(fields[2])?,
+            })
+        })
+        .collect()
+}
+
+/// This function returns the reference values of a function, which are empty for unknown names
+pub fn values(name: &str) -> Vec<&'static ReferenceValue> {
+    reference_values().iter().filter(|value| value.function == name).collect()
+}
+
+/// This function evaluates a function at each of its reference points, and returns the points where
+/// it is not within a tolerance of the reference value
+pub fn mismatches(function: &Function, tolerance: Tolerance) -> Vec<Mismatch> {
+    values(function.name).into_iter()
+        .map(|value| Mismatch { x: value.x.clone(), expected: value.f, actual: (function.f)(value.x.clone()) })
+        .filter(|mismatch| !tolerance.accepts(mismatch.actual, mismatch.expected))
+        .collect()
+}
+
+#[cfg(test)]
+mod reference_tests {
+    use super::{reference_values, values, mismatches, parse, ReferenceValue};
+    use crate::Tolerance;
+    use crate::registry::{functions, find, Function};
+
+    #[test]
+    fn complete() {
+        for function in functions() {
+            let values = values(function.name);
+            let dims: Vec<usize> = function.dimension.map_or(vec![2, 5], |d| vec![d]);
+            assert_eq!(values.len(), 4*dims.len(), "{}", function.name);
+            for (value, d) in values.iter().zip(dims.iter().flat_map(|d| vec![*d; 4])) {
+                assert_eq!(value.x.len(), d);
+            }
+            for (xi, minimizer) in values[0].x.iter().zip((function.minimizer)(dims[0])) {
+                assert!(Tolerance::default().accepts(*xi, minimizer), "{}", function.name);
+            }
+            assert!(Tolerance::new(1e-12, 1e-12).accepts(values[0].f, (function.minimum)(dims[0])), "{}", function.name);
+        }
+        assert_eq!(reference_values().len(), functions().iter().map(|f| if f.dimension.is_some() { 4 } else { 8 }).sum::<usize>());
+        assert!(values("Nonexistent").is_empty());
+    }
+
+    #[test]
+    fn tables() {
+        assert_eq!(parse("function,x,f\nBooth,1 3,0\n\n").unwrap(), vec![ReferenceValue { function: "Booth".to_string(), x: vec![1.0, 3.0], f: 0.0 }]);
+        assert!(parse("function,x,f\nBooth,1 3").is_err());
+        assert!(parse("function,x,f\nBooth,1 a,0").is_err());
+    }
+
+    #[test]
+    fn formulas() {
+        for function in functions() {
+            let mismatches = mismatches(function, Tolerance::new(1e-12, 1e-12));
+            assert!(mismatches.is_empty(), "{} differs from its reference values: {:?}", function.name, mismatches);
+        }
+    }
+
+    #[test]
+    fn regressions() {
+        // The Zakharov function once weighted its coordinates from zero instead of one
+        let zakharov = find("Zakharov").unwrap();
+        let wrong = Function {
+            f: |x| {
+                let weighted: f64 = x.iter().enumerate().map(|(i, xi)| 0.5*(i as f64)*xi).sum();
+                x.iter().map(|xi| xi.powi(2)).sum::<f64>() + weighted.powi(2) + weighted.powi(4)
+            },
+            ..*zakharov
+        };
+        assert_eq!(mismatches(&wrong, Tolerance::new(1e-12, 1e-12)).len(), 6);
+    }
+}
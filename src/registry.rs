@@ -0,0 +1,287 @@
+//! This module contains a registry of the single-objective functions of the crate
+//!
+//! The functions in this crate are types, so they cannot be looked up by name at runtime. The
+//! registry lists every single-objective function as a value, with plain function pointers for its
+//! objective function, bounds, global minimum and minimizer, in a fixed order that gives each
+//! function a stable index.
+//!
+//! ```
+//! use benchfun::registry::{functions, find};
+//!
+//! let ackley = find("Ackley").unwrap();
+//! assert!(ackley.supports(5));
+//! assert_eq!((ackley.f)((ackley.minimizer)(5)), (ackley.minimum)(5));
+//! assert!(functions().iter().any(|f| f.dimension == Some(2)));
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::{Bounded, Constrained, FixedDimensional, NonFiniteError, SingleObjective, UnBounded};
+use crate::grid::{evaluate_slice, Grid};
+use crate::problem::{name, Descriptor};
+use crate::single::*;
+
+/// This struct describes a single-objective function of the registry
+#[derive(Clone, Copy, Debug)]
+pub struct Function {
+    /// The name of the function
+    pub name: &'static str,
+    /// The dimensionality of fixed-dimensional functions, or `None` for N-dimensional functions
+    pub dimension: Option<usize>,
+    /// Function for evaluating
+    pub f: fn(Vec<f64>) -> f64,
+    /// The global minimum at a dimensionality
+    pub minimum: fn(usize) -> f64,
+    /// The global minimizer at a dimensionality
+    pub minimizer: fn(usize) -> Vec<f64>,
+    /// The bounds of each dimension at a dimensionality, which are infinite for unbounded functions
+    pub bounds: fn(usize) -> Vec<(f64, f64)>,
+    /// Function for evaluating the equality constraints of constrained functions
+    pub equality_constraints: Option<fn(Vec<f64>) -> Vec<f64>>,
+    /// Function for evaluating the inequality constraints of constrained functions
+    pub inequality_constraints: Option<fn(Vec<f64>) -> Vec<f64>>,
+    /// The properties of the function
+    pub properties: Properties,
+    /// The source the function is borrowed from, as a URL where there is one
+    pub reference: &'static str,
+}
+
+/// This struct holds the properties of a function
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Properties {
+    /// Whether the function is differentiable everywhere, if this is recorded
+    pub smooth: Option<bool>,
+    /// Whether the function has constraints
+    pub constrained: bool,
+    /// Whether the function has a noisy version
+    pub stochastic: bool,
+    /// Whether the function has an analytic gradient
+    pub differentiable: bool,
+    /// Whether the function has several global minimizers
+    pub multiple_minimizers: bool,
+}
+
+/// This function returns infinite bounds for each dimension
+fn infinite(n: usize) -> Vec<(f64, f64)> {
+    vec![(f64::NEG_INFINITY, f64::INFINITY); n]
+}
+
+impl Function {
+    /// This function describes a bounded function
+    fn bounded<F: SingleObjective + Bounded>(dimension: Option<usize>) -> Function {
+        Function { name: name::<F>(), dimension, f: F::f, minimum: F::minimum, minimizer: F::minimizer, bounds: F::bounds, equality_constraints: None, inequality_constraints: None, properties: Properties::default(), reference: "" }
+    }
+
+    /// This function describes an unbounded function
+    fn unbounded<F: SingleObjective + UnBounded>(dimension: Option<usize>) -> Function {
+        Function { name: name::<F>(), dimension, f: F::f, minimum: F::minimum, minimizer: F::minimizer, bounds: infinite, equality_constraints: None, inequality_constraints: None, properties: Properties::default(), reference: "" }
+    }
+
+    /// This function records whether the function is smooth
+    fn smooth(mut self, smooth: bool) -> Function {
+        self.properties.smooth = Some(smooth);
+        self
+    }
+
+    /// This function records the constraints of a constrained function
+    fn constrained<F: Constrained>(mut self) -> Function {
+        self.properties.constrained = true;
+        self.equality_constraints = Some(F::equality_constraints);
+        self.inequality_constraints = Some(F::inequality_constraints);
+        self
+    }
+
+    /// This function records that the function has a noisy version
+    fn stochastic(mut self) -> Function {
+        self.properties.stochastic = true;
+        self
+    }
+
+    /// This function records that the function has an analytic gradient
+    fn differentiable(mut self) -> Function {
+        self.properties.differentiable = true;
+        self
+    }
+
+    /// This function records that the function has several global minimizers
+    fn multiple_minimizers(mut self) -> Function {
+        self.properties.multiple_minimizers = true;
+        self
+    }
+
+    /// This function records the source of the function
+    fn reference(mut self, reference: &'static str) -> Function {
+        self.reference = reference;
+        self
+    }
+
+    /// This function returns whether the function can be evaluated at a dimensionality
+    pub fn supports(&self, d: usize) -> bool {
+        d > 0 && self.dimension.is_none_or(|fixed| fixed == d)
+    }
+
+    /// This function describes the function at a dimensionality
+    pub fn descriptor(&self, d: usize) -> Descriptor {
+        Descriptor { name: self.name.to_string(), dimension: d, bounds: (self.bounds)(d), minimum: (self.minimum)(d), minimizer: (self.minimizer)(d) }
+    }
+
+    /// This function evaluates the function, and returns an error if it evaluates to NaN or an infinity
+    pub fn f_checked(&self, x: Vec<f64>) -> Result<f64, NonFiniteError> {
+        let value = (self.f)(x.clone());
+        NonFiniteError::check(&x, &[value]).map(|_| value)
+    }
+
+    /// This function evaluates the function on a `resolution` by `resolution` grid that spans its
+    /// bounds in 2-D, or returns `None` if it is not bounded or cannot be evaluated in 2-D
+    pub fn grid(&self, resolution: usize) -> Option<Grid> {
+        let bounds = (self.bounds)(2);
+        if !self.supports(2) || bounds.iter().any(|(low, high)| !low.is_finite() || !high.is_finite()) {
+            return None;
+        }
+        Some(evaluate_slice(self.f, &bounds, resolution, vec![0.0; 2], 0, 1))
+    }
+}
+
+/// This function returns every single-objective function, in a fixed order
+pub fn functions() -> &'static [Function] {
+    static FUNCTIONS: OnceLock<Vec<Function>> = OnceLock::new();
+    FUNCTIONS.get_or_init(|| vec![
+        Function::unbounded::<Sphere>(None).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Rastrigin>(None).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Rosenbrock>(None).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Ackley>(None).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Matyas>(Some(Matyas::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Griewank>(None).reference("http://benchmarkfcns.xyz/benchmarkfcns/griewankfcn.html"),
+        Function::bounded::<Ridge>(None).reference("http://benchmarkfcns.xyz/benchmarkfcns/ridgefcn.html"),
+        Function::bounded::<Zakharov>(None).reference("http://benchmarkfcns.xyz/benchmarkfcns/zakharov.html"),
+        Function::bounded::<Salomon>(None).reference("http://benchmarkfcns.xyz/benchmarkfcns/salomonfcn.html"),
+        Function::unbounded::<RosenbrockConst1>(Some(RosenbrockConst1::D)).constrained::<RosenbrockConst1>().reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::unbounded::<RosenbrockConst2>(Some(RosenbrockConst2::D)).constrained::<RosenbrockConst2>().reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Katsuura>(None).smooth(false).reference("CEC 2014 benchmark suite"),
+        Function::bounded::<Weierstrass>(None).smooth(false).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Quartic>(None).stochastic().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Step>(None).smooth(false).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Exponential>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Brown>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<ChungReynolds>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Csendes>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Qing>(None).multiple_minimizers().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Quintic>(None).smooth(false).multiple_minimizers().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<ExpandedSchafferF6>(None).reference("CEC 2014 benchmark suite"),
+        Function::bounded::<SchafferF7>(None).reference("CEC 2013 benchmark suite"),
+        Function::bounded::<BentCigar>(None).reference("https://hal.inria.fr/inria-00362633"),
+        Function::bounded::<Discus>(None).reference("https://hal.inria.fr/inria-00362633"),
+        Function::bounded::<SharpRidge>(None).smooth(false).reference("https://hal.inria.fr/inria-00362633"),
+        Function::bounded::<Ellipsoidal>(None).reference("https://hal.inria.fr/inria-00362633"),
+        Function::bounded::<LunacekBiRastrigin>(None).reference("https://hal.inria.fr/inria-00362633"),
+        Function::bounded::<CosineMixture>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Pinter>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<SineEnvelope>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Vincent>(None).multiple_minimizers().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<DebN1>(None).multiple_minimizers().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<DebDeceptive>(None).reference("Deb and Goldberg (1989)"),
+        Function::bounded::<Periodic>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Whitley>(None).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Beale>(Some(Beale::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Booth>(Some(Booth::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<BukinN6>(Some(BukinN6::D)).smooth(false).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<CrossInTray>(Some(CrossInTray::D)).smooth(false).multiple_minimizers().reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<DropWave>(Some(DropWave::D)).reference("https://www.sfu.ca/~ssurjano/drop.html"),
+        Function::bounded::<Easom>(Some(Easom::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Eggholder>(Some(Eggholder::D)).smooth(false).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<GoldsteinPrice>(Some(GoldsteinPrice::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<Himmelblau>(Some(Himmelblau::D)).multiple_minimizers().reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<HolderTable>(Some(HolderTable::D)).smooth(false).multiple_minimizers().reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<LeviN13>(Some(LeviN13::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<McCormick>(Some(McCormick::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<SchafferN2>(Some(SchafferN2::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<SchafferN4>(Some(SchafferN4::D)).smooth(false).multiple_minimizers().reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<ThreeHumpCamel>(Some(ThreeHumpCamel::D)).reference("https://en.wikipedia.org/wiki/Test_functions_for_optimization"),
+        Function::bounded::<SixHumpCamel>(Some(SixHumpCamel::D)).multiple_minimizers().reference("https://www.sfu.ca/~ssurjano/camel6.html"),
+        Function::bounded::<Branin>(Some(Branin::D)).multiple_minimizers().reference("https://www.sfu.ca/~ssurjano/branin.html"),
+        Function::bounded::<Shubert>(Some(Shubert::D)).multiple_minimizers().reference("https://www.sfu.ca/~ssurjano/shubert.html"),
+        Function::bounded::<ShekelFoxholes>(Some(ShekelFoxholes::D)).reference("https://www.sfu.ca/~ssurjano/dejong5.html"),
+        Function::bounded::<Langermann>(Some(Langermann::D)).reference("https://www.sfu.ca/~ssurjano/langer.html"),
+        Function::bounded::<BohachevskyN1>(Some(BohachevskyN1::D)).reference("https://www.sfu.ca/~ssurjano/boha.html"),
+        Function::bounded::<BohachevskyN2>(Some(BohachevskyN2::D)).reference("https://www.sfu.ca/~ssurjano/boha.html"),
+        Function::bounded::<BohachevskyN3>(Some(BohachevskyN3::D)).reference("https://www.sfu.ca/~ssurjano/boha.html"),
+        Function::bounded::<Bird>(Some(Bird::D)).multiple_minimizers().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Adjiman>(Some(Adjiman::D)).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<BartelsConn>(Some(BartelsConn::D)).smooth(false).reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Hartmann3>(Some(Hartmann3::D)).reference("https://www.sfu.ca/~ssurjano/hart3.html"),
+        Function::bounded::<Hartmann6>(Some(Hartmann6::D)).reference("https://www.sfu.ca/~ssurjano/hart6.html"),
+        Function::bounded::<Shekel>(Some(Shekel::D)).reference("https://www.sfu.ca/~ssurjano/shekel.html"),
+        Function::bounded::<Colville>(Some(Colville::D)).smooth(true).differentiable().reference("https://www.sfu.ca/~ssurjano/colville.html"),
+        Function::bounded::<Wood>(Some(Wood::D)).smooth(true).differentiable().reference("https://arxiv.org/abs/1308.4008"),
+        Function::bounded::<Forrester>(Some(Forrester::D)).reference("https://www.sfu.ca/~ssurjano/forretal08.html"),
+        Function::bounded::<GramacyLee>(Some(GramacyLee::D)).reference("https://www.sfu.ca/~ssurjano/grlee12.html"),
+        Function::bounded::<Damavandi>(Some(Damavandi::D)).reference("https://arxiv.org/abs/1308.4008"),
+    ])
+}
+
+/// This function returns the index of a function in the registry
+pub fn index(name: &str) -> Option<usize> {
+    functions().iter().position(|f| f.name == name)
+}
+
+/// This function looks up a function by name
+pub fn find(name: &str) -> Option<&'static Function> {
+    functions().iter().find(|f| f.name == name)
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::{functions, find, index, Properties};
+    use crate::Constrained;
+
+    #[test]
+    fn smoothness() {
+        // Smooth and NonSmooth are marker traits, so their impls are read from the source
+        let source = include_str!("single.rs");
+        for function in functions() {
+            let expected = if source.contains(&format!("impl Smooth for {} {{}}", function.name)) {
+                Some(true)
+            } else if source.contains(&format!("impl NonSmooth for {} {{}}", function.name)) {
+                Some(false)
+            } else {
+                None
+            };
+            assert_eq!(function.properties.smooth, expected, "{}", function.name);
+        }
+    }
+
+    #[test]
+    fn complete() {
+        let functions = functions();
+        assert_eq!(functions.len(), 70);
+        for (i, function) in functions.iter().enumerate() {
+            assert_eq!(index(function.name), Some(i));
+            let d = function.dimension.unwrap_or(3);
+            assert!(function.supports(d) && !function.supports(0));
+            assert_eq!((function.minimizer)(d).len(), d, "{}", function.name);
+            assert_eq!((function.bounds)(d).len(), d);
+            assert!(!function.reference.is_empty());
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let booth = find("Booth").unwrap();
+        assert_eq!(booth.dimension, Some(2));
+        assert!(!booth.supports(3));
+        assert_eq!((booth.f)(vec![1.0, 3.0]), 0.0);
+        assert_eq!(booth.descriptor(2).bounds, vec![(-10.0, 10.0); 2]);
+        assert_eq!((find("Sphere").unwrap().bounds)(1), vec![(f64::NEG_INFINITY, f64::INFINITY)]);
+        assert!(find("Nonexistent").is_none());
+        assert_eq!(booth.reference, "https://en.wikipedia.org/wiki/Test_functions_for_optimization");
+        assert_eq!(find("Wood").unwrap().properties, Properties { smooth: Some(true), differentiable: true, ..Default::default() });
+        assert_eq!(find("Colville").unwrap().properties, find("Wood").unwrap().properties);
+        let constrained = find("RosenbrockConst1").unwrap();
+        assert!(constrained.properties.constrained);
+        assert_eq!((constrained.inequality_constraints.unwrap())(vec![1.0, 1.0]), crate::RosenbrockConst1::g(vec![1.0, 1.0]));
+        assert!(booth.equality_constraints.is_none() && booth.inequality_constraints.is_none());
+        assert_eq!(booth.grid(10), Some(crate::grid::grid_evaluate::<crate::Booth>(10)));
+        assert!(find("Sphere").unwrap().grid(10).is_none());
+        assert!(find("Hartmann3").unwrap().grid(10).is_none());
+    }
+}
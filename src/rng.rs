@@ -0,0 +1,98 @@
+//! This module contains a small seedable random number generator
+
+/// This is a seedable pseudo-random number generator.
+///
+/// The generator is xoshiro256\*\*, with its state initialized from the seed by SplitMix64. It is not
+/// suitable for cryptography, but it is fast and makes every stochastic part of this crate
+/// reproducible from a single seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// This function creates a new generator from a seed
+    pub fn new(seed: u64) -> Rng {
+        let mut z = seed;
+        let mut state = [0u64; 4];
+        for s in state.iter_mut() {
+            z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut t = z;
+            t = (t ^ (t >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            t = (t ^ (t >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *s = t ^ (t >> 31);
+        }
+        Rng { state }
+    }
+
+    /// This function returns the next random 64-bit integer
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+        result
+    }
+
+    /// This function returns a random number drawn uniformly from [0, 1)
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64/(1u64 << 53) as f64
+    }
+
+    /// This function returns a random integer drawn uniformly from 0, 1, ..., n - 1
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128*n as u128) >> 64) as usize
+    }
+
+    /// This function returns a random number drawn from the standard normal distribution
+    pub fn normal(&mut self) -> f64 {
+        let u = 1.0 - self.uniform();
+        let v = self.uniform();
+        (-2.0*u.ln()).sqrt()*(2.0*std::f64::consts::PI*v).cos()
+    }
+
+    /// This function shuffles a slice in place
+    pub fn shuffle<T>(&mut self, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            v.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod rng_tests {
+    use super::Rng;
+
+    #[test]
+    fn reproducible() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn uniform_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..10_000 {
+            let u = rng.uniform();
+            assert!((0.0..1.0).contains(&u));
+            assert!(rng.below(10) < 10);
+        }
+    }
+
+    #[test]
+    fn normal_moments() {
+        let mut rng = Rng::new(11);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.normal()).collect();
+        let mean = samples.iter().sum::<f64>()/samples.len() as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>()/samples.len() as f64;
+        assert!(mean.abs() < 0.02);
+        assert!((variance - 1.0).abs() < 0.02);
+    }
+}
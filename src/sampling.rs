@@ -0,0 +1,411 @@
+//! This module contains functions for sampling points inside the bounds of benchmark functions
+//!
+//! Every sampler takes the number of dimensions explicitly, since N-dimensional functions do not
+//! have a fixed dimensionality, and draws any randomness from a seedable `Rng`:
+//!
+//! ```
+//! use benchfun::{Rastrigin, Rng, Bounded};
+//! use benchfun::sampling::uniform_in_bounds;
+//!
+//! let points = uniform_in_bounds::<Rastrigin>(100, 5, &mut Rng::new(0));
+//! assert!(points.into_iter().all(Rastrigin::in_bounds));
+//! ```
+
+use crate::{Bounded, Rng};
+
+/// This function maps points from the unit hypercube into the bounds of a function
+fn scale_to_bounds<F: Bounded>(unit: Vec<Vec<f64>>, d: usize) -> Vec<Vec<f64>> {
+    let bounds = F::bounds(d);
+    unit.into_iter()
+        .map(|u| u.iter().zip(bounds.iter()).map(|(ui, (low, high))| low + (high - low)*ui).collect())
+        .collect()
+}
+
+/// This function returns the smallest distance between any two points
+fn min_pairwise_distance(points: &[Vec<f64>]) -> f64 {
+    let mut min = f64::INFINITY;
+    for (i, a) in points.iter().enumerate() {
+        for b in points[i + 1..].iter() {
+            let dist = a.iter().zip(b.iter()).map(|(ai, bi)| (ai - bi).powi(2)).sum::<f64>();
+            min = min.min(dist);
+        }
+    }
+    min.sqrt()
+}
+
+/// This function returns points drawn uniformly from the bounds of a function
+pub fn uniform_in_bounds<F: Bounded>(n_points: usize, d: usize, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let unit = (0..n_points).map(|_| (0..d).map(|_| rng.uniform()).collect()).collect();
+    scale_to_bounds::<F>(unit, d)
+}
+
+/// This function returns a Latin hypercube design on the unit hypercube
+fn unit_latin_hypercube(n_points: usize, d: usize, centered: bool, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let mut points = vec![Vec::with_capacity(d); n_points];
+    let mut strata: Vec<usize> = (0..n_points).collect();
+    for _ in 0..d {
+        rng.shuffle(&mut strata);
+        for (point, stratum) in points.iter_mut().zip(strata.iter()) {
+            let offset = if centered { 0.5 } else { rng.uniform() };
+            point.push((*stratum as f64 + offset)/n_points as f64);
+        }
+    }
+    points
+}
+
+/// This function returns a Latin hypercube design inside the bounds of a function.
+///
+/// Each dimension of the bounds is divided into `n_points` equally sized strata, and each stratum
+/// contains exactly one point. If `centered` is true, points are placed at the center of their
+/// strata instead of at a random position within them.
+pub fn latin_hypercube<F: Bounded>(n_points: usize, d: usize, centered: bool, rng: &mut Rng) -> Vec<Vec<f64>> {
+    scale_to_bounds::<F>(unit_latin_hypercube(n_points, d, centered, rng), d)
+}
+
+/// This function returns a maximin Latin hypercube design inside the bounds of a function.
+///
+/// A number of `candidates` Latin hypercube designs are generated, and the one with the largest
+/// minimum distance between its points (measured in the unit hypercube) is returned.
+pub fn maximin_latin_hypercube<F: Bounded>(n_points: usize, d: usize, centered: bool, candidates: usize, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let mut best = unit_latin_hypercube(n_points, d, centered, rng);
+    let mut best_distance = min_pairwise_distance(&best);
+    for _ in 1..candidates {
+        let candidate = unit_latin_hypercube(n_points, d, centered, rng);
+        let distance = min_pairwise_distance(&candidate);
+        if distance > best_distance {
+            best = candidate;
+            best_distance = distance;
+        }
+    }
+    scale_to_bounds::<F>(best, d)
+}
+
+/// The primitive polynomials and initial direction numbers of the Sobol sequence, from Joe and Kuo's
+/// `new-joe-kuo-6.21201` table. Each entry holds the degree, the encoded coefficients, and the
+/// initial direction numbers of one dimension after the first.
+const SOBOL_PARAMETERS: [(u32, u32, [u32; 7]); 20] = [
+    (1, 0, [1, 0, 0, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0, 0, 0]),
+    (3, 2, [1, 1, 1, 0, 0, 0, 0]),
+    (4, 1, [1, 1, 3, 3, 0, 0, 0]),
+    (4, 4, [1, 3, 5, 13, 0, 0, 0]),
+    (5, 2, [1, 1, 5, 5, 17, 0, 0]),
+    (5, 4, [1, 1, 5, 5, 5, 0, 0]),
+    (5, 7, [1, 1, 7, 11, 19, 0, 0]),
+    (5, 11, [1, 1, 5, 1, 1, 0, 0]),
+    (5, 13, [1, 1, 1, 3, 11, 0, 0]),
+    (5, 14, [1, 3, 5, 5, 31, 0, 0]),
+    (6, 1, [1, 3, 3, 9, 7, 49, 0]),
+    (6, 13, [1, 1, 1, 15, 21, 21, 0]),
+    (6, 16, [1, 3, 1, 13, 27, 49, 0]),
+    (6, 19, [1, 1, 1, 15, 7, 5, 0]),
+    (6, 22, [1, 3, 1, 15, 13, 25, 0]),
+    (6, 25, [1, 1, 5, 5, 19, 61, 0]),
+    (7, 1, [1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, [1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// The largest number of dimensions supported by the Sobol sampler
+pub const SOBOL_MAX_DIMENSION: usize = SOBOL_PARAMETERS.len() + 1;
+
+/// This function returns the 32 direction numbers of a dimension of the Sobol sequence
+fn sobol_direction_numbers(dimension: usize) -> [u32; 32] {
+    let mut v = [0u32; 32];
+    if dimension == 0 {
+        for (k, vk) in v.iter_mut().enumerate() {
+            *vk = 1 << (31 - k);
+        }
+        return v;
+    }
+    let (s, a, m) = SOBOL_PARAMETERS[dimension - 1];
+    let s = s as usize;
+    for k in 0..32 {
+        v[k] = if k < s {
+            m[k] << (31 - k)
+        } else {
+            let mut vk = v[k - s] ^ (v[k - s] >> s);
+            for l in 1..s {
+                if (a >> (s - 1 - l)) & 1 == 1 {
+                    vk ^= v[k - l];
+                }
+            }
+            vk
+        };
+    }
+    v
+}
+
+/// This function returns the first points of the Sobol sequence as 32-bit integers
+fn sobol_integers(n_points: usize, d: usize) -> Vec<Vec<u32>> {
+    assert!(d <= SOBOL_MAX_DIMENSION, "the Sobol sampler supports at most {} dimensions", SOBOL_MAX_DIMENSION);
+    assert!(n_points as u64 <= 1 << 32, "the Sobol sampler supports at most 2^32 points");
+    let directions: Vec<[u32; 32]> = (0..d).map(sobol_direction_numbers).collect();
+    (0..n_points)
+        .map(|i| {
+            let gray = i ^ (i >> 1);
+            directions.iter()
+                .map(|v| v.iter().enumerate().filter(|(k, _)| (gray >> k) & 1 == 1).fold(0, |x, (_, vk)| x ^ vk))
+                .collect()
+        })
+        .collect()
+}
+
+/// This function applies a hash-based nested uniform (Owen) scramble to a 32-bit sample, following
+/// Burley's "Practical Hash-based Owen Scrambling" (2020)
+fn owen_scramble(x: u32, seed: u32) -> u32 {
+    let mut x = x.reverse_bits().wrapping_add(seed);
+    x ^= x.wrapping_mul(0x6c50_b47c);
+    x ^= x.wrapping_mul(0xb82f_1e52);
+    x ^= x.wrapping_mul(0xc7af_e638);
+    x ^= x.wrapping_mul(0x8d22_f6e6);
+    x.reverse_bits()
+}
+
+/// This function returns the first points of the Sobol sequence inside the bounds of a function.
+///
+/// The sequence uses Gray code ordering and starts with the lower corner of the bounds. Its
+/// space-filling properties are best when `n_points` is a power of two. At most
+/// `SOBOL_MAX_DIMENSION` dimensions are supported.
+pub fn sobol<F: Bounded>(n_points: usize, d: usize) -> Vec<Vec<f64>> {
+    let unit = sobol_integers(n_points, d).into_iter()
+        .map(|x| x.into_iter().map(|xi| xi as f64/(1u64 << 32) as f64).collect())
+        .collect();
+    scale_to_bounds::<F>(unit, d)
+}
+
+/// This function returns the first points of an Owen-scrambled Sobol sequence inside the bounds of
+/// a function.
+///
+/// Scrambling randomizes the sequence while preserving its stratification, so that independent
+/// randomizations can be used for error estimates. Each point is placed at a random position within
+/// its finest stratum.
+pub fn scrambled_sobol<F: Bounded>(n_points: usize, d: usize, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let seeds: Vec<u32> = (0..d).map(|_| rng.next_u64() as u32).collect();
+    let unit = sobol_integers(n_points, d).into_iter()
+        .map(|x| {
+            x.into_iter().zip(seeds.iter())
+                .map(|(xi, seed)| (owen_scramble(xi, *seed) as f64 + rng.uniform())/(1u64 << 32) as f64)
+                .collect()
+        })
+        .collect();
+    scale_to_bounds::<F>(unit, d)
+}
+
+/// This function returns the first `n` prime numbers
+fn primes(n: usize) -> Vec<usize> {
+    let mut primes: Vec<usize> = Vec::with_capacity(n);
+    let mut candidate = 2;
+    while primes.len() < n {
+        if primes.iter().take_while(|p| *p * *p <= candidate).all(|p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+/// This function returns the radical inverse of an index in a base, optionally permuting its digits
+fn radical_inverse(mut index: usize, base: usize, permutation: Option<&[usize]>) -> f64 {
+    let mut result = 0.0;
+    let mut scale = 1.0/base as f64;
+    while index > 0 {
+        let digit = index % base;
+        result += scale*permutation.map_or(digit, |p| p[digit]) as f64;
+        index /= base;
+        scale /= base as f64;
+    }
+    result
+}
+
+/// This function returns random digit permutations for each base, keeping zero fixed so that
+/// trailing zeros are left unchanged
+fn digit_permutations(bases: &[usize], rng: &mut Rng) -> Vec<Vec<usize>> {
+    bases.iter()
+        .map(|b| {
+            let mut permutation: Vec<usize> = (0..*b).collect();
+            rng.shuffle(&mut permutation[1..]);
+            permutation
+        })
+        .collect()
+}
+
+/// This function returns a Halton design on the unit hypercube
+fn unit_halton(n_points: usize, d: usize, leap: usize, permutations: Option<&[Vec<usize>]>) -> Vec<Vec<f64>> {
+    assert!(leap > 0, "the leap must be positive");
+    let bases = primes(d);
+    (0..n_points)
+        .map(|i| {
+            bases.iter().enumerate()
+                .map(|(j, b)| radical_inverse(i*leap, *b, permutations.map(|p| p[j].as_slice())))
+                .collect()
+        })
+        .collect()
+}
+
+/// This function returns the first points of the Halton sequence inside the bounds of a function.
+///
+/// Dimension `j` uses the radical inverse in the `j`-th prime base, and the sequence starts with
+/// the lower corner of the bounds. Only every `leap`-th point of the sequence is kept, which breaks
+/// up the correlation between high dimensions when `leap` is a prime larger than the bases in use.
+/// A `leap` of 1 gives the standard sequence.
+pub fn halton<F: Bounded>(n_points: usize, d: usize, leap: usize) -> Vec<Vec<f64>> {
+    scale_to_bounds::<F>(unit_halton(n_points, d, leap, None), d)
+}
+
+/// This function returns the first points of a scrambled Halton sequence inside the bounds of a
+/// function.
+///
+/// The digits of each dimension are scrambled by a random permutation, which removes the linear
+/// patterns that the standard sequence shows in high dimensions.
+pub fn scrambled_halton<F: Bounded>(n_points: usize, d: usize, leap: usize, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let permutations = digit_permutations(&primes(d), rng);
+    scale_to_bounds::<F>(unit_halton(n_points, d, leap, Some(&permutations)), d)
+}
+
+/// This function returns a Hammersley design on the unit hypercube
+fn unit_hammersley(n_points: usize, d: usize, permutations: Option<&[Vec<usize>]>) -> Vec<Vec<f64>> {
+    assert!(d > 0, "the Hammersley set needs at least one dimension");
+    unit_halton(n_points, d - 1, 1, permutations).into_iter().enumerate()
+        .map(|(i, x)| {
+            let mut point = vec![i as f64/n_points as f64];
+            point.extend(x);
+            point
+        })
+        .collect()
+}
+
+/// This function returns a Hammersley set inside the bounds of a function.
+///
+/// The first dimension is evenly spaced, and the rest follow the Halton sequence. Unlike the other
+/// low-discrepancy designs, the set depends on `n_points` and cannot be extended.
+pub fn hammersley<F: Bounded>(n_points: usize, d: usize) -> Vec<Vec<f64>> {
+    scale_to_bounds::<F>(unit_hammersley(n_points, d, None), d)
+}
+
+/// This function returns a scrambled Hammersley set inside the bounds of a function, scrambling the
+/// Halton dimensions in the same way as `scrambled_halton`.
+pub fn scrambled_hammersley<F: Bounded>(n_points: usize, d: usize, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let permutations = digit_permutations(&primes(d.saturating_sub(1)), rng);
+    scale_to_bounds::<F>(unit_hammersley(n_points, d, Some(&permutations)), d)
+}
+
+#[cfg(test)]
+mod sampling_tests {
+    use super::{uniform_in_bounds, latin_hypercube, maximin_latin_hypercube, min_pairwise_distance,
+                sobol, scrambled_sobol, sobol_integers, owen_scramble, SOBOL_MAX_DIMENSION,
+                halton, scrambled_halton, hammersley, scrambled_hammersley, primes, unit_halton};
+    use crate::{Bounded, Rng, Rastrigin, BukinN6};
+
+    /// This function checks that 2^m integer samples fall in distinct strata of width 2^-m along
+    /// every dimension
+    fn check_stratified(points: &[Vec<u32>], m: u32) {
+        for i in 0..points[0].len() {
+            let mut strata: Vec<u32> = points.iter().map(|x| x[i] >> (32 - m)).collect();
+            strata.sort_unstable();
+            assert_eq!(strata, (0..1 << m).collect::<Vec<u32>>());
+        }
+    }
+
+    #[test]
+    fn uniform() {
+        let points = uniform_in_bounds::<Rastrigin>(1000, 3, &mut Rng::new(1));
+        assert_eq!(points.len(), 1000);
+        assert!(points.iter().all(|x| x.len() == 3 && Rastrigin::in_bounds(x.clone())));
+        assert_eq!(points, uniform_in_bounds::<Rastrigin>(1000, 3, &mut Rng::new(1)));
+    }
+
+    #[test]
+    fn uniform_per_dimension() {
+        let points = uniform_in_bounds::<BukinN6>(1000, 2, &mut Rng::new(1));
+        assert!(points.into_iter().all(BukinN6::in_bounds));
+    }
+
+    #[test]
+    fn latin_hypercube_strata() {
+        let points = latin_hypercube::<BukinN6>(20, 2, false, &mut Rng::new(2));
+        assert!(points.iter().all(|x| BukinN6::in_bounds(x.clone())));
+        for (i, (low, high)) in BukinN6::bounds(2).into_iter().enumerate() {
+            let mut strata: Vec<usize> = points.iter()
+                .map(|x| ((x[i] - low)/(high - low)*20.0) as usize)
+                .collect();
+            strata.sort_unstable();
+            assert_eq!(strata, (0..20).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn latin_hypercube_centered() {
+        let points = latin_hypercube::<Rastrigin>(4, 3, true, &mut Rng::new(3));
+        let mut coordinates: Vec<f64> = points.iter().map(|x| x[1]).collect();
+        coordinates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (c, expected) in coordinates.iter().zip([-3.84, -1.28, 1.28, 3.84].iter()) {
+            assert!((c - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn latin_hypercube_maximin() {
+        let single = maximin_latin_hypercube::<Rastrigin>(10, 2, false, 1, &mut Rng::new(4));
+        let best = maximin_latin_hypercube::<Rastrigin>(10, 2, false, 50, &mut Rng::new(4));
+        assert!(min_pairwise_distance(&best) >= min_pairwise_distance(&single));
+    }
+
+    #[test]
+    fn sobol_reference() {
+        let points = sobol::<Rastrigin>(8, 2);
+        let expected = [[0.0, 0.0], [0.5, 0.5], [0.75, 0.25], [0.25, 0.75],
+                        [0.375, 0.375], [0.875, 0.875], [0.625, 0.125], [0.125, 0.625]];
+        for (x, e) in points.iter().zip(expected.iter()) {
+            assert!((x[0] - (-5.12 + 10.24*e[0])).abs() < 1e-12);
+            assert!((x[1] - (-5.12 + 10.24*e[1])).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn sobol_stratified() {
+        check_stratified(&sobol_integers(256, SOBOL_MAX_DIMENSION), 8);
+    }
+
+    #[test]
+    fn sobol_scrambled() {
+        let points = scrambled_sobol::<Rastrigin>(256, 10, &mut Rng::new(5));
+        assert!(points.iter().all(|x| Rastrigin::in_bounds(x.clone())));
+        assert_ne!(points, scrambled_sobol::<Rastrigin>(256, 10, &mut Rng::new(6)));
+        let scrambled: Vec<Vec<u32>> = sobol_integers(256, 10).into_iter()
+            .map(|x| x.into_iter().enumerate().map(|(i, xi)| owen_scramble(xi, 12_345*i as u32)).collect())
+            .collect();
+        check_stratified(&scrambled, 8);
+    }
+
+    #[test]
+    fn halton_reference() {
+        assert_eq!(primes(6), vec![2, 3, 5, 7, 11, 13]);
+        let points = unit_halton(4, 2, 1, None);
+        let expected = [[0.0, 0.0], [0.5, 1.0/3.0], [0.25, 2.0/3.0], [0.75, 1.0/9.0]];
+        for (x, e) in points.iter().zip(expected.iter()) {
+            assert!((x[0] - e[0]).abs() < 1e-15 && (x[1] - e[1]).abs() < 1e-15);
+        }
+        let leaped = unit_halton(4, 2, 3, None);
+        assert_eq!(leaped[2], unit_halton(7, 2, 1, None)[6]);
+    }
+
+    #[test]
+    fn halton_bounds() {
+        let points = halton::<BukinN6>(100, 2, 1);
+        assert!(points.iter().all(|x| BukinN6::in_bounds(x.clone())));
+        let scrambled = scrambled_halton::<Rastrigin>(100, 8, 17, &mut Rng::new(7));
+        assert!(scrambled.iter().all(|x| x.len() == 8 && Rastrigin::in_bounds(x.clone())));
+        assert_eq!(scrambled, scrambled_halton::<Rastrigin>(100, 8, 17, &mut Rng::new(7)));
+    }
+
+    #[test]
+    fn hammersley_stratified() {
+        for points in [hammersley::<Rastrigin>(9, 3), scrambled_hammersley::<Rastrigin>(9, 3, &mut Rng::new(8))].iter() {
+            assert!(points.iter().all(|x| Rastrigin::in_bounds(x.clone())));
+            let mut strata: Vec<usize> = points.iter().map(|x| ((x[2] + 5.12)/10.24*3.0 + 1e-9) as usize).collect();
+            strata.sort_unstable();
+            assert_eq!(strata, vec![0, 0, 0, 1, 1, 1, 2, 2, 2]);
+        }
+    }
+}
@@ -0,0 +1,450 @@
+//! This module contains an HTTP server that evaluates the functions of the registry, so optimizers
+//! written in other languages or running on other machines can use them. It is only available with
+//! the `server` feature.
+//!
+//! Requests and responses are JSON. The metadata of the functions can be read freely, but
+//! evaluations are made through sessions, which fix a function and dimensionality and count
+//! every evaluation on the server, refusing evaluations beyond an optional budget. The endpoints are
+//!
+//! - `GET /functions`, which returns the catalog of functions
+//! - `GET /functions/<name>?dimension=<d>`, which describes a function, where the dimensionality
+//!   can be left out for fixed-dimensional functions
+//! - `POST /sessions` with `{"function": <name>, "dimension": <d>, "budget": <n>}`, where the
+//!   dimensionality and budget are optional, which starts a session and returns its id
+//! - `GET /sessions/<id>`, which returns the counts and the best point of a session
+//! - `DELETE /sessions/<id>`, which ends a session and returns its final state
+//! - `POST /sessions/<id>/evaluate` with `{"x": <point>}` or `{"points": [<point>, ...]}`, which
+//!   returns the value `f` or the values `f` of the points
+//! - `POST /sessions/<id>/constraints` with `{"x": <point>}`, which returns the `equality` and
+//!   `inequality` constraints of a constrained function
+//!
+//! Errors are returned with a status code and an `error` message, and a session that would exceed
+//! its budget is answered with `429 Too Many Requests` without evaluating anything. Bodies larger
+//! than `MAX_BODY` bytes are refused with `413 Payload Too Large`, and at most `MAX_SESSIONS`
+//! sessions can be open at once, beyond which new sessions are refused with
+//! `503 Service Unavailable` until one is closed.
+//!
+//! ```
+//! use benchfun::server::Server;
+//!
+//! let server = Server::new();
+//! let session = server.handle("POST", "/sessions", r#"{"function": "Booth", "budget": 10}"#);
+//! assert_eq!(session.status, 201);
+//! let response = server.handle("POST", "/sessions/0/evaluate", r#"{"x": [1.0, 3.0]}"#);
+//! assert_eq!(response.body.to_string(), r#"{"f":0,"evaluations":1,"remaining":9}"#);
+//! ```
+//!
+//! To serve on a port, call `Server::new().serve("127.0.0.1:8000")`.
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read, Result, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::catalog::catalog;
+use crate::json::{FromJson, Json, ToJson};
+use crate::registry::{find, Function};
+
+/// The largest request body that is read, in bytes
+pub const MAX_BODY: usize = 1 << 20;
+
+/// The largest size of the request line and headers that is read, in bytes
+pub const MAX_HEADERS: usize = 1 << 16;
+
+/// The largest number of sessions that can be open at once
+pub const MAX_SESSIONS: usize = 1024;
+
+/// The longest time a connection waits for a client to send or receive data
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// This struct holds the status code and JSON body of a response
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    /// The HTTP status code
+    pub status: u16,
+    /// The body of the response
+    pub body: Json,
+}
+
+impl Response {
+    /// This function creates a successful response
+    fn ok(body: Json) -> Response {
+        Response { status: 200, body }
+    }
+
+    /// This function creates an error response with a message
+    fn error<S: Into<String>>(status: u16, message: S) -> Response {
+        Response { status, body: Json::Object(vec![("error".to_string(), Json::String(message.into()))]) }
+    }
+}
+
+/// This struct holds the state of an evaluation session
+#[derive(Clone, Debug)]
+struct Session {
+    /// The function being evaluated
+    function: &'static Function,
+    /// The dimensionality of the function
+    dimension: usize,
+    /// The largest number of evaluations allowed, if there is a limit
+    budget: Option<usize>,
+    /// The number of evaluations of the objective function
+    evaluations: usize,
+    /// The number of evaluations of the constraints
+    constraint_evaluations: usize,
+    /// The best point evaluated so far and its value
+    best: Option<(Vec<f64>, f64)>,
+}
+
+impl ToJson for Session {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("function".to_string(), self.function.name.to_json()),
+            ("dimension".to_string(), self.dimension.to_json()),
+            ("budget".to_string(), self.budget.to_json()),
+            ("evaluations".to_string(), self.evaluations.to_json()),
+            ("constraint_evaluations".to_string(), self.constraint_evaluations.to_json()),
+            ("best_x".to_string(), self.best.as_ref().map(|(x, _)| x.clone()).to_json()),
+            ("best_f".to_string(), self.best.as_ref().map(|(_, f)| *f).to_json()),
+        ])
+    }
+}
+
+/// This struct is a server for evaluating the functions of the registry, which keeps the sessions
+/// and their evaluation counts
+#[derive(Debug, Default)]
+pub struct Server {
+    /// The open sessions by id, and the id of the next session
+    sessions: Mutex<(BTreeMap<usize, Session>, usize)>,
+}
+
+/// This function reads an optional member of a JSON object
+fn optional<T: FromJson>(json: &Json, key: &str) -> std::result::Result<Option<T>, String> {
+    json.get(key).map_or(Ok(None), |value| Option::<T>::from_json(value).map_err(|e| format!("`{}`: {}", key, e)))
+}
+
+/// This function evaluates a function, converting panics into an error response
+fn evaluate<T, G: FnOnce() -> T>(g: G) -> std::result::Result<T, Response> {
+    catch_unwind(AssertUnwindSafe(g)).map_err(|_| Response::error(500, "the function failed to evaluate"))
+}
+
+impl Server {
+    /// This function creates a server without sessions
+    pub fn new() -> Server {
+        Server::default()
+    }
+
+    /// This function answers a request with a method, a target such as `/functions?dimension=2`
+    /// and a body, which is ignored for `GET` and `DELETE` requests
+    pub fn handle(&self, method: &str, target: &str, body: &str) -> Response {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let body = || {
+            let text = if body.trim().is_empty() { "{}" } else { body };
+            text.parse::<Json>().map_err(|e| Response::error(400, format!("invalid JSON: {}", e)))
+        };
+        let result = match (method, segments.as_slice()) {
+            ("GET", ["functions"]) => Ok(Response::ok(catalog(&[]).to_json())),
+            ("GET", ["functions", name]) => self.describe(name, query),
+            ("POST", ["sessions"]) => body().and_then(|body| self.open(&body)),
+            ("GET", ["sessions", id]) => self.session(id, |session| Ok(Response::ok(session.to_json()))),
+            ("DELETE", ["sessions", id]) => self.close(id),
+            ("POST", ["sessions", id, "evaluate"]) => body().and_then(|body| self.evaluate(id, &body)),
+            ("POST", ["sessions", id, "constraints"]) => body().and_then(|body| self.constraints(id, &body)),
+            (_, ["functions"]) | (_, ["functions", _]) | (_, ["sessions"]) | (_, ["sessions", _]) | (_, ["sessions", _, "evaluate"]) | (_, ["sessions", _, "constraints"]) => {
+                Err(Response::error(405, format!("{} is not allowed on {}", method, path)))
+            }
+            _ => Err(Response::error(404, format!("there is no endpoint at {}", path))),
+        };
+        result.unwrap_or_else(|error| error)
+    }
+
+    /// This function describes a function at the dimensionality given in a query string
+    fn describe(&self, name: &str, query: &str) -> std::result::Result<Response, Response> {
+        let function = find(name).ok_or_else(|| Response::error(404, format!("there is no function named {}", name)))?;
+        let dimension = match query.split('&').find_map(|pair| pair.strip_prefix("dimension=")) {
+            Some(d) => Some(d.parse::<usize>().map_err(|_| Response::error(400, "the dimension must be a non-negative integer"))?),
+            None => None,
+        };
+        let d = Server::dimension(function, dimension)?;
+        evaluate(|| Response::ok(function.descriptor(d).to_json()))
+    }
+
+    /// This function checks a requested dimensionality, which defaults to the dimensionality of
+    /// fixed-dimensional functions
+    fn dimension(function: &Function, dimension: Option<usize>) -> std::result::Result<usize, Response> {
+        match dimension.or(function.dimension) {
+            Some(d) if function.supports(d) => Ok(d),
+            Some(d) => Err(Response::error(400, format!("{} does not support {} dimensions", function.name, d))),
+            None => Err(Response::error(400, format!("a dimension is needed for {}", function.name))),
+        }
+    }
+
+    /// This function starts a session
+    fn open(&self, body: &Json) -> std::result::Result<Response, Response> {
+        let name: String = body.field("function").map_err(|e| Response::error(400, e.to_string()))?;
+        let function = find(&name).ok_or_else(|| Response::error(404, format!("there is no function named {}", name)))?;
+        let dimension = Server::dimension(function, optional(body, "dimension").map_err(|e| Response::error(400, e))?)?;
+        let budget = optional(body, "budget").map_err(|e| Response::error(400, e))?;
+        let descriptor = evaluate(|| function.descriptor(dimension))?;
+        let mut sessions = self.sessions.lock().unwrap();
+        if sessions.0.len() >= MAX_SESSIONS {
+            return Err(Response::error(503, format!("there are already {} open sessions", MAX_SESSIONS)));
+        }
+        let id = sessions.1;
+        sessions.1 += 1;
+        sessions.0.insert(id, Session { function, dimension, budget, evaluations: 0, constraint_evaluations: 0, best: None });
+        Ok(Response { status: 201, body: Json::Object(vec![("session".to_string(), id.to_json()), ("descriptor".to_string(), descriptor.to_json())]) })
+    }
+
+    /// This function applies a function to a session, if it exists
+    fn session<T, G: FnOnce(&mut Session) -> std::result::Result<T, Response>>(&self, id: &str, g: G) -> std::result::Result<T, Response> {
+        let mut sessions = self.sessions.lock().unwrap();
+        match id.parse::<usize>().ok().and_then(|id| sessions.0.get_mut(&id)) {
+            Some(session) => g(session),
+            None => Err(Response::error(404, format!("there is no session {}", id))),
+        }
+    }
+
+    /// This function ends a session
+    fn close(&self, id: &str) -> std::result::Result<Response, Response> {
+        let mut sessions = self.sessions.lock().unwrap();
+        match id.parse::<usize>().ok().and_then(|id| sessions.0.remove(&id)) {
+            Some(session) => Ok(Response::ok(session.to_json())),
+            None => Err(Response::error(404, format!("there is no session {}", id))),
+        }
+    }
+
+    /// This function reads the points of an evaluation request, and whether a single point was given
+    fn points(body: &Json, dimension: usize) -> std::result::Result<(Vec<Vec<f64>>, bool), Response> {
+        let (points, single) = match (optional::<Vec<f64>>(body, "x"), optional::<Vec<Vec<f64>>>(body, "points")) {
+            (Ok(Some(x)), Ok(None)) => (vec![x], true),
+            (Ok(None), Ok(Some(points))) => (points, false),
+            (Err(e), _) | (_, Err(e)) => return Err(Response::error(400, e)),
+            _ => return Err(Response::error(400, "exactly one of `x` and `points` is needed")),
+        };
+        match points.iter().find(|x| x.len() != dimension) {
+            Some(x) => Err(Response::error(400, format!("a point has {} dimensions instead of {}", x.len(), dimension))),
+            None => Ok((points, single)),
+        }
+    }
+
+    /// This function evaluates points within a session, counting them against its budget
+    fn evaluate(&self, id: &str, body: &Json) -> std::result::Result<Response, Response> {
+        // The evaluations are counted before evaluating, so that concurrent requests cannot exceed the budget
+        let (function, points, single, evaluations, budget) = self.session(id, |session| {
+            let (points, single) = Server::points(body, session.dimension)?;
+            if session.budget.is_some_and(|budget| session.evaluations + points.len() > budget) {
+                return Err(Response::error(429, format!("the budget of {} evaluations would be exceeded", session.budget.unwrap())));
+            }
+            session.evaluations += points.len();
+            Ok((session.function, points, single, session.evaluations, session.budget))
+        })?;
+        let values = evaluate(|| points.iter().map(|x| (function.f)(x.clone())).collect::<Vec<f64>>())?;
+        // The session may have ended while evaluating, in which case there is no best point to update
+        let _ = self.session(id, |session| {
+            for (x, f) in points.iter().zip(values.iter()) {
+                if session.best.as_ref().is_none_or(|(_, best)| f < best) {
+                    session.best = Some((x.clone(), *f));
+                }
+            }
+            Ok(())
+        });
+        Ok(Response::ok(Json::Object(vec![
+            ("f".to_string(), if single { values[0].to_json() } else { values.to_json() }),
+            ("evaluations".to_string(), evaluations.to_json()),
+            ("remaining".to_string(), budget.map(|budget| budget - evaluations).to_json()),
+        ])))
+    }
+
+    /// This function evaluates the constraints of a point within a session
+    fn constraints(&self, id: &str, body: &Json) -> std::result::Result<Response, Response> {
+        let (function, x) = self.session(id, |session| {
+            if !session.function.properties.constrained {
+                return Err(Response::error(400, format!("{} has no constraints", session.function.name)));
+            }
+            let x: Vec<f64> = body.field("x").map_err(|e| Response::error(400, e.to_string()))?;
+            if x.len() != session.dimension {
+                return Err(Response::error(400, format!("a point has {} dimensions instead of {}", x.len(), session.dimension)));
+            }
+            session.constraint_evaluations += 1;
+            Ok((session.function, x))
+        })?;
+        let equality = evaluate(|| function.equality_constraints.map_or(vec![], |h| h(x.clone())))?;
+        let inequality = evaluate(|| function.inequality_constraints.map_or(vec![], |g| g(x.clone())))?;
+        Ok(Response::ok(Json::Object(vec![("equality".to_string(), equality.to_json()), ("inequality".to_string(), inequality.to_json())])))
+    }
+
+    /// This function reads an HTTP request from a connection and writes the response
+    pub fn handle_connection(&self, stream: &mut TcpStream) -> Result<()> {
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?.take(MAX_HEADERS as u64));
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let mut parts = line.split_whitespace();
+        let (method, target) = (parts.next().unwrap_or("").to_string(), parts.next().unwrap_or("").to_string());
+        let mut length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        let response = if length > MAX_BODY {
+            Response::error(413, format!("the body is larger than {} bytes", MAX_BODY))
+        } else {
+            reader.get_mut().set_limit(length as u64);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body)?;
+            match String::from_utf8(body) {
+                Ok(body) => self.handle(&method, &target, &body),
+                Err(_) => Response::error(400, "the body is not UTF-8"),
+            }
+        };
+        let text = response.body.to_string();
+        write!(stream, "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+               response.status, reason(response.status), text.len(), text)?;
+        stream.flush()
+    }
+
+    /// This function serves requests on an address, answering each connection on its own thread.
+    /// Connections that cannot be accepted, such as when the process runs out of file descriptors,
+    /// are logged and skipped.
+    pub fn serve<A: ToSocketAddrs>(self, address: A) -> Result<()> {
+        let listener = TcpListener::bind(address)?;
+        let server = Arc::new(self);
+        for stream in listener.incoming() {
+            match stream {
+                Ok(mut stream) => {
+                    let server = Arc::clone(&server);
+                    std::thread::spawn(move || server.handle_connection(&mut stream));
+                }
+                Err(error) => {
+                    eprintln!("benchfun: failed to accept a connection: {}", error);
+                    std::thread::sleep(Duration::from_millis(100));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This function returns the reason phrase of a status code
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        429 => "Too Many Requests",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod server_tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use super::{Server, MAX_BODY, MAX_SESSIONS};
+    use crate::json::Json;
+
+    #[test]
+    fn metadata() {
+        let server = Server::new();
+        let functions = server.handle("GET", "/functions", "");
+        assert_eq!(functions.status, 200);
+        assert!(matches!(functions.body, Json::Array(ref entries) if entries.len() == crate::registry::functions().len()));
+        let booth = server.handle("GET", "/functions/Booth", "");
+        assert_eq!(booth.body.get("minimizer"), Some(&Json::Array(vec![Json::Number(1.0), Json::Number(3.0)])));
+        assert_eq!(server.handle("GET", "/functions/Ackley?dimension=4", "").body.get("dimension"), Some(&Json::Number(4.0)));
+        assert_eq!(server.handle("GET", "/functions/Ackley", "").status, 400);
+        assert_eq!(server.handle("GET", "/functions/Booth?dimension=3", "").status, 400);
+        assert_eq!(server.handle("GET", "/functions/Nonexistent", "").status, 404);
+        assert_eq!(server.handle("PUT", "/functions", "").status, 405);
+        assert_eq!(server.handle("GET", "/elsewhere", "").status, 404);
+    }
+
+    #[test]
+    fn sessions() {
+        let server = Server::new();
+        assert_eq!(server.handle("POST", "/sessions", r#"{"function": "Rastrigin", "dimension": 3, "budget": 4}"#).status, 201);
+        let evaluate = |body: &str| server.handle("POST", "/sessions/0/evaluate", body);
+        assert_eq!(evaluate(r#"{"x": [1.0, 0.0, 0.0]}"#).body.get("f"), Some(&Json::Number(1.0)));
+        let batch = evaluate(r#"{"points": [[0.0, 0.0, 0.0], [0.0, 1.0, 0.0]]}"#);
+        assert_eq!(batch.body.to_string(), r#"{"f":[0,1],"evaluations":3,"remaining":1}"#);
+        assert_eq!(evaluate(r#"{"x": [1.0]}"#).status, 400);
+        assert_eq!(evaluate(r#"{}"#).status, 400);
+        assert_eq!(evaluate("not json").status, 400);
+        assert_eq!(server.handle("POST", "/sessions", &"[".repeat(200_000)).status, 400);
+        assert_eq!(evaluate(r#"{"points": [[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]}"#).status, 429);
+        assert_eq!(evaluate(r#"{"x": [0.0, 0.0, 2.0]}"#).status, 200);
+        assert_eq!(evaluate(r#"{"x": [0.0, 0.0, 2.0]}"#).status, 429);
+        let status = server.handle("DELETE", "/sessions/0", "").body;
+        assert_eq!(status.get("evaluations"), Some(&Json::Number(4.0)));
+        assert_eq!(status.get("best_f"), Some(&Json::Number(0.0)));
+        assert_eq!(server.handle("GET", "/sessions/0", "").status, 404);
+        assert_eq!(server.handle("POST", "/sessions", r#"{"function": "Booth", "dimension": 3}"#).status, 400);
+        assert_eq!(server.handle("POST", "/sessions", r#"{"dimension": 3}"#).status, 400);
+    }
+
+    #[test]
+    fn constraints() {
+        let server = Server::new();
+        server.handle("POST", "/sessions", r#"{"function": "RosenbrockConst1"}"#);
+        let response = server.handle("POST", "/sessions/0/constraints", r#"{"x": [1.0, 1.0]}"#);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body.get("equality"), Some(&Json::Array(vec![])));
+        assert_eq!(server.handle("GET", "/sessions/0", "").body.get("constraint_evaluations"), Some(&Json::Number(1.0)));
+        server.handle("POST", "/sessions", r#"{"function": "Booth"}"#);
+        assert_eq!(server.handle("POST", "/sessions/1/constraints", r#"{"x": [1.0, 1.0]}"#).status, 400);
+    }
+
+    #[test]
+    fn limit() {
+        let server = Server::new();
+        for _ in 0..MAX_SESSIONS {
+            assert_eq!(server.handle("POST", "/sessions", r#"{"function": "Booth"}"#).status, 201);
+        }
+        assert_eq!(server.handle("POST", "/sessions", r#"{"function": "Booth"}"#).status, 503);
+        assert_eq!(server.handle("DELETE", "/sessions/0", "").status, 200);
+        assert_eq!(server.handle("POST", "/sessions", r#"{"function": "Booth"}"#).status, 201);
+    }
+
+    /// This function sends a request to a server over a connection and returns the response
+    fn request(server: &Server, request: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            write!(stream, "{}", request).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+        server.handle_connection(&mut listener.accept().unwrap().0).unwrap();
+        client.join().unwrap()
+    }
+
+    #[test]
+    fn http() {
+        let server = Server::new();
+        let body = r#"{"function": "Booth"}"#;
+        let response = request(&server, format!("POST /sessions HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", body.len(), body));
+        assert!(response.starts_with("HTTP/1.1 201 Created\r\n"));
+        assert!(response.ends_with(r#""minimizer":[1,3]}}"#));
+        let response = request(&server, format!("POST /sessions HTTP/1.1\r\nContent-Length: {}\r\n\r\n", MAX_BODY + 1));
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+        let response = request(&server, format!("POST /sessions HTTP/1.1\r\nContent-Length: {}\r\n\r\n", usize::MAX));
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+    }
+}
@@ -0,0 +1,174 @@
+//! This module contains vectorized implementations of the functions that dominate evaluation cost
+//! in large-population experiments. It is only available with the `simd` feature. The `f` functions
+//! of Sphere, Rastrigin, Rosenbrock, Ackley and Griewank are not affected by the feature, so these
+//! implementations are only used when they are called directly.
+//!
+//! Each reduction is split across `LANES` independent accumulators, which removes the loop-carried
+//! dependency of a scalar sum and lets the compiler lower the loop body to SIMD instructions on
+//! stable Rust. Cosines are computed with a branch-free polynomial instead of calls into the math
+//! library, so that they vectorize too. Because of this, and because the terms are summed in a
+//! different order, results can differ from the scalar implementations in the last few bits.
+//!
+//! ```
+//! let x = vec![0.5; 1000];
+//! assert!((benchfun::simd::sphere(&x) - 250.0).abs() < 1e-12);
+//! ```
+
+use std::f64::consts::{E, PI};
+
+/// The number of independent accumulators used in each reduction
+pub const LANES: usize = 8;
+
+/// The Taylor coefficients of the cosine, which are accurate to within 1e-16 on [0, π/2]
+const COS_COEFFICIENTS: [f64; 11] = [
+    1.0, -0.5, 0.041666666666666664, -0.001388888888888889, 2.48015873015873e-05, -2.755731922398589e-07,
+    2.08767569878681e-09, -1.1470745597729725e-11, 4.779477332387385e-14, -1.5619206968586225e-16,
+    4.110317623312165e-19,
+];
+
+/// This function returns cos(2πt) without branches or library calls. It is accurate to within a
+/// few ulp for |t| < 2^51, and propagates NaN and infinity like the standard cosine.
+#[inline(always)]
+fn cos_2pi(t: f64) -> f64 {
+    const ROUND: f64 = 6_755_399_441_055_744.0;
+    let r = t - ((t + ROUND) - ROUND);
+    let a = r.abs();
+    let a = if a > 0.5 { 0.5 } else { a };
+    let flip = a > 0.25;
+    let z = 2.0*PI*(if flip { 0.5 - a } else { a });
+    let z2 = z*z;
+    let c = COS_COEFFICIENTS.iter().rev().fold(0.0, |p, k| p*z2 + k);
+    if flip { -c } else { c }
+}
+
+/// This function returns the sum of a term over each element of a slice
+#[inline(always)]
+fn sum_lanes<G: Fn(f64) -> f64>(x: &[f64], g: G) -> f64 {
+    let mut acc = [0.0; LANES];
+    let chunks = x.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (a, xi) in acc.iter_mut().zip(chunk.iter()) {
+            *a += g(*xi);
+        }
+    }
+    acc.iter().sum::<f64>() + remainder.iter().map(|xi| g(*xi)).sum::<f64>()
+}
+
+/// This function evaluates the Sphere function
+pub fn sphere(x: &[f64]) -> f64 {
+    sum_lanes(x, |xi| xi*xi)
+}
+
+/// This function evaluates the Rastrigin function
+pub fn rastrigin(x: &[f64]) -> f64 {
+    10.0*x.len() as f64 + sum_lanes(x, |xi| xi*xi - 10.0*cos_2pi(xi))
+}
+
+/// This function evaluates the Rosenbrock function
+pub fn rosenbrock(x: &[f64]) -> f64 {
+    if x.len() < 2 {
+        return 0.0;
+    }
+    let (head, tail) = (&x[..x.len() - 1], &x[1..]);
+    let mut acc = [0.0; LANES];
+    let chunks = head.chunks_exact(LANES).zip(tail.chunks_exact(LANES));
+    for (a_chunk, b_chunk) in chunks {
+        for ((a, xi), xj) in acc.iter_mut().zip(a_chunk.iter()).zip(b_chunk.iter()) {
+            *a += 100.0*(xj - xi*xi).powi(2) + (1.0 - xi).powi(2);
+        }
+    }
+    let done = head.len() - head.len() % LANES;
+    let remainder: f64 = head[done..].iter().zip(tail[done..].iter())
+        .map(|(xi, xj)| 100.0*(xj - xi*xi).powi(2) + (1.0 - xi).powi(2))
+        .sum();
+    acc.iter().sum::<f64>() + remainder
+}
+
+/// This function evaluates the Ackley function
+pub fn ackley(x: &[f64]) -> f64 {
+    let square_sum = sum_lanes(x, |xi| xi*xi);
+    let cosine_sum = sum_lanes(x, cos_2pi);
+    -20.0*(-0.2*(square_sum/(x.len() as f64)).sqrt()).exp() - (cosine_sum/(x.len() as f64)).exp() + E + 20.0
+}
+
+/// This function evaluates the Griewank function
+pub fn griewank(x: &[f64]) -> f64 {
+    let mut square_acc = [0.0; LANES];
+    let mut cosine_acc = [1.0; LANES];
+    let chunks = x.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for (c, chunk) in chunks.enumerate() {
+        for (k, xi) in chunk.iter().enumerate() {
+            square_acc[k] += xi*xi;
+            cosine_acc[k] *= cos_2pi(xi/(2.0*PI*((c*LANES + k + 1) as f64).sqrt()));
+        }
+    }
+    let offset = x.len() - remainder.len();
+    let mut square_sum: f64 = square_acc.iter().sum();
+    let mut cosine_prod: f64 = cosine_acc.iter().product();
+    for (k, xi) in remainder.iter().enumerate() {
+        square_sum += xi*xi;
+        cosine_prod *= cos_2pi(xi/(2.0*PI*((offset + k + 1) as f64).sqrt()));
+    }
+    1.0 + square_sum/4000.0 - cosine_prod
+}
+
+#[cfg(test)]
+mod simd_tests {
+    use super::{sphere, rastrigin, rosenbrock, ackley, griewank, cos_2pi};
+    use std::f64::consts::{E, PI};
+    use crate::{Rng, SingleObjective, Sphere, Rastrigin, Rosenbrock, Ackley, Griewank};
+
+    /// This function checks a vectorized implementation against a scalar reference at random points
+    /// of several dimensionalities, including ones that are not a multiple of the lane count
+    fn check(vectorized: fn(&[f64]) -> f64, scalar: fn(&[f64]) -> f64) {
+        let mut rng = Rng::new(13);
+        for d in [1, 2, 7, 8, 9, 31, 100, 1000].iter() {
+            let x: Vec<f64> = (0..*d).map(|_| 10.0*rng.uniform() - 5.0).collect();
+            let (v, s) = (vectorized(&x), scalar(&x));
+            assert!((v - s).abs() <= 1e-12*(1.0 + s.abs()), "d = {}: {} != {}", d, v, s);
+        }
+    }
+
+    #[test]
+    fn cosine() {
+        let mut rng = Rng::new(14);
+        for _ in 0..100_000 {
+            let t = 2000.0*rng.uniform() - 1000.0;
+            assert!((cos_2pi(t) - (2.0*PI*t).cos()).abs() < 1e-12);
+            let t = rng.uniform() - 0.5;
+            assert!((cos_2pi(t) - (2.0*PI*t).cos()).abs() < 1e-15);
+        }
+        assert_eq!(cos_2pi(0.0), 1.0);
+        assert_eq!(cos_2pi(0.5), -1.0);
+        assert!(cos_2pi(f64::NAN).is_nan());
+        assert!(cos_2pi(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn against_f() {
+        check(sphere, |x| Sphere::f(x.to_vec()));
+        check(rastrigin, |x| Rastrigin::f(x.to_vec()));
+        check(rosenbrock, |x| Rosenbrock::f(x.to_vec()));
+        check(ackley, |x| Ackley::f(x.to_vec()));
+        check(griewank, |x| Griewank::f(x.to_vec()));
+    }
+
+    #[test]
+    fn against_scalar() {
+        check(sphere, |x| x.iter().map(|xi| xi.powi(2)).sum());
+        check(rastrigin, |x| 10.0*x.len() as f64 + x.iter().map(|xi| xi.powi(2) - 10.0*(2.0*PI*xi).cos()).sum::<f64>());
+        check(rosenbrock, |x| x.windows(2).map(|w| 100.0*(w[1] - w[0].powi(2)).powi(2) + (1.0 - w[0]).powi(2)).sum());
+        check(ackley, |x| {
+            let square_sum: f64 = x.iter().map(|xi| xi.powi(2)).sum();
+            let cosine_sum: f64 = x.iter().map(|xi| (2.0*PI*xi).cos()).sum();
+            -20.0*(-0.2*(square_sum/(x.len() as f64)).sqrt()).exp() - (cosine_sum/(x.len() as f64)).exp() + E + 20.0
+        });
+        check(griewank, |x| {
+            let square_sum: f64 = x.iter().map(|xi| xi.powi(2)).sum();
+            let cosine_prod: f64 = x.iter().enumerate().map(|(i, xi)| (xi/((i + 1) as f64).sqrt()).cos()).product();
+            1.0 + square_sum/4000.0 - cosine_prod
+        });
+    }
+}
@@ -1,6 +1,9 @@
 //! This module contains single-objective functions
 
-use crate::{NDimensional, UnConstrained, UnBounded, Bounded, SingleObjective, FixedDimensional, Constrained};
+use std::sync::OnceLock;
+
+use crate::{NDimensional, UnConstrained, UnBounded, Bounded, SingleObjective, FixedDimensional, Constrained, Stochastic, Rng, MultipleMinimizers, NonSmooth, Parameterized, Shift, Smooth, Differentiable};
+use crate::instance::{oscillate, conditioning};
 
 /// This is the Sphere function.
 ///
@@ -23,7 +26,7 @@ impl SingleObjective for Sphere {
     fn f(x: Vec<f64>) -> f64 {
         let mut f = 0f64;
         for xi in x {
-            f -= xi.powi(2);
+            f += xi.powi(2);
         }
         f
     }
@@ -40,12 +43,17 @@ mod sphere_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(F::f(vec![1.0, -2.0]), 5.0);
     }
 }
 
@@ -94,12 +102,12 @@ mod rastrigin_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
     }
 }
 
@@ -146,12 +154,12 @@ mod rosenbrock_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
     }
 }
 
@@ -186,7 +194,7 @@ impl SingleObjective for Ackley {
             square_sum += xi.powi(2);
             cosine_sum += (2.0*std::f64::consts::PI*xi).cos();
         }
-        fx += -20.0*(-0.2*(0.5*square_sum).sqrt()).exp();
+        fx += -20.0*(-0.2*(square_sum/(n as f64)).sqrt()).exp();
         fx -= (cosine_sum/(n as f64)).exp();
         fx + std::f64::consts::E + 20.0
     }
@@ -203,25 +211,37 @@ mod ackley_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn formula() {
+        assert!((F::f(vec![1.0; 4]) - 20.0*(1.0 - (-0.2f64).exp())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn batch() {
+        let points = vec![vec![0.0, 0.0], vec![1.0, -1.0], vec![0.5, 2.0]];
+        let values: Vec<f64> = points.iter().map(|x| F::f(x.clone())).collect();
+        assert_eq!(F::f_batch(&points), values);
+        assert_eq!(F::f_batch_flat(&points.concat(), 2), values);
+        assert!(F::f_batch(&[]).is_empty());
     }
 }
 
 /// This is the Matyas function.
 ///
 /// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
-/// Although the function accepts a vector with an arbitrary number of inputs, this is what it looks
-/// like in 2D:
+/// This function is specifically 2 dimensional:
 ///
 /// ![](https://upload.wikimedia.org/wikipedia/commons/thumb/6/63/Matyas_function.pdf/page1-800px-Matyas_function.pdf.jpg)
 pub struct Matyas {}
 
-impl NDimensional for Matyas {}
 impl UnConstrained for Matyas {}
 
 impl Bounded for Matyas {
@@ -229,39 +249,41 @@ impl Bounded for Matyas {
     const BOUNDS: (f64, f64) = (-10.0, 10.0);
 }
 
+impl FixedDimensional for Matyas {
+    /// The function is only defined for 2 dimensions. With more dimensions, the product term
+    /// outgrows the quadratic term and the function is unbounded below.
+    const D: usize = 2;
+}
+
 impl SingleObjective for Matyas {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
 
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
-        let mut square_sum = 0.0;
-        let mut prod = 1.0;
-        for xi in x {
-            square_sum += xi.powi(2);
-            prod *= xi;
-        }
-        0.26*square_sum - 0.48*prod
+        Self::check_input(x.clone());
+        0.26*(x[0].powi(2) + x[1].powi(2)) - 0.48*x[0]*x[1]
     }
 
     /// This function returns the minimizer (argument that will return the global minimum
-    fn minimizer(n: usize) -> Vec<f64> {
-        vec![0.0; n]
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0; 2]
     }
 }
 
 #[cfg(test)]
 mod matyas_tests {
-    use super::{Matyas as F, NDimensional, SingleObjective};
+    use super::{Matyas as F, FixedDimensional, SingleObjective};
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::D)
     }
 
     #[test]
-    fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+    #[should_panic]
+    fn wrong_dimension() {
+        F::f(vec![1.0; 3]);
     }
 }
 
@@ -309,12 +331,12 @@ mod griewank_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
     }
 }
 
@@ -342,12 +364,12 @@ impl SingleObjective for Ridge {
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
         let d = 1.0;
-        let alpha = 0.0;
+        let alpha = 0.5;
         let mut square_sum = 0.0;
         for xi in x.iter().skip(1) {
             square_sum += xi.powi(2);
         }
-        -1.0 + x[0] + d * square_sum.powf(alpha)
+        x[0] + d * square_sum.powf(alpha)
     }
 
     /// This function returns the minimizer (argument that will return the global minimum
@@ -364,12 +386,17 @@ mod ridge_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn formula() {
+        assert_eq!(F::f(vec![1.0, 3.0, 4.0]), 6.0);
     }
 }
 
@@ -400,7 +427,7 @@ impl SingleObjective for Zakharov {
         let mut sum_ixi: f64 = 0.0;
         for (i, xi) in x.iter().enumerate() {
             square_sum += xi.powi(2);
-            sum_ixi += 0.5*xi*(i as f64);
+            sum_ixi += 0.5*xi*((i + 1) as f64);
         }
         square_sum + sum_ixi.powi(2) + sum_ixi.powi(4)
     }
@@ -417,12 +444,17 @@ mod zakharov_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn formula() {
+        assert_eq!(F::f(vec![1.0, 1.0]), 9.3125);
     }
 }
 
@@ -468,12 +500,12 @@ mod salomon_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::LOW_D)
+        F::check_minimizer_strict(F::LOW_D)
     }
 
     #[test]
     fn high_d() {
-        F::check_minimizer(F::HIGH_D)
+        F::check_minimizer_strict(F::HIGH_D)
     }
 }
 
@@ -530,7 +562,7 @@ mod rosenbrock_const1_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::D)
+        F::check_minimizer_strict(F::D)
     }
 }
 
@@ -588,6 +620,4141 @@ mod rosenbrock_const2_tests {
 
     #[test]
     fn low_d() {
-        F::check_minimizer(F::D)
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Katsuura function.
+///
+/// The function is defined as in the CEC 2014 benchmark suite, where it is used as a component of
+/// several hybrid and composition functions. It is continuous everywhere but differentiable nowhere.
+/// The function accepts a vector with an arbitrary number of inputs.
+pub struct Katsuura {}
+
+impl NDimensional for Katsuura {}
+impl UnConstrained for Katsuura {}
+impl NonSmooth for Katsuura {}
+
+impl Bounded for Katsuura {
+    /// The bounds of the canonical Katsuura optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl SingleObjective for Katsuura {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len() as f64;
+        let exponent = 10.0/n.powf(1.2);
+
+        // The product is accumulated as a sum of logarithms, and the final subtraction of one is
+        // done with exp_m1, so that there is no cancellation error close to the optimum.
+        let mut log_prod = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            let mut fractional_sum = 0.0;
+            let mut scale = 1.0;
+            for _ in 0..32 {
+                scale *= 2.0;
+                let t = scale*xi;
+                fractional_sum += (t - t.round()).abs()/scale;
+            }
+            log_prod += exponent*(((i + 1) as f64)*fractional_sum).ln_1p();
+        }
+        10.0/n.powi(2)*log_prod.exp_m1()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod katsuura_tests {
+    use super::{Katsuura as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Weierstrass function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008), and uses the standard
+/// parameters a = 0.5, b = 3 and k_max = 20. It is continuous everywhere and differentiable
+/// nowhere. The function accepts a vector with an arbitrary number of inputs.
+pub struct Weierstrass {}
+
+/// The terms of the Weierstrass series, which are the powers of a and b and the constant term
+struct WeierstrassSeries {
+    /// The powers of a
+    ak: [f64; 21],
+    /// The powers of b
+    bk: [f64; 21],
+    /// The constant term
+    constant: f64,
+}
+
+impl Weierstrass {
+    /// This function returns the terms of the series, which do not depend on x and are computed
+    /// on the first evaluation
+    fn series() -> &'static WeierstrassSeries {
+        static SERIES: OnceLock<WeierstrassSeries> = OnceLock::new();
+        SERIES.get_or_init(|| {
+            let a: f64 = 0.5;
+            let b: f64 = 3.0;
+            let mut series = WeierstrassSeries { ak: [0.0; 21], bk: [0.0; 21], constant: 0.0 };
+            for k in 0..21 {
+                series.ak[k] = a.powi(k as i32);
+                series.bk[k] = b.powi(k as i32);
+                series.constant += series.ak[k]*(std::f64::consts::PI*series.bk[k]).cos();
+            }
+            series
+        })
+    }
+}
+
+impl NDimensional for Weierstrass {}
+impl UnConstrained for Weierstrass {}
+impl NonSmooth for Weierstrass {}
+
+impl Bounded for Weierstrass {
+    /// The bounds of the canonical Weierstrass optimization problem
+    const BOUNDS: (f64, f64) = (-0.5, 0.5);
+}
+
+impl SingleObjective for Weierstrass {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let series = Self::series();
+        let mut fx = 0.0;
+        for xi in x {
+            let mut sum = 0.0;
+            for (ak, bk) in series.ak.iter().zip(series.bk.iter()) {
+                sum += ak*(2.0*std::f64::consts::PI*bk*(xi + 0.5)).cos();
+            }
+            fx += sum - series.constant;
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod weierstrass_tests {
+    use super::{Weierstrass as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Quartic function with uniform noise, also known as De Jong's fourth function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). The noise-free part of
+/// the function is evaluated by `f`, and `f_noisy` adds noise drawn uniformly from [0, 1). The
+/// function accepts a vector with an arbitrary number of inputs.
+pub struct Quartic {}
+
+impl NDimensional for Quartic {}
+impl UnConstrained for Quartic {}
+
+impl Bounded for Quartic {
+    /// The bounds of the canonical Quartic optimization problem
+    const BOUNDS: (f64, f64) = (-1.28, 1.28);
+}
+
+impl SingleObjective for Quartic {
+    /// The global minimum of the noise-free function is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating the noise-free function
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            fx += ((i + 1) as f64)*xi.powi(4);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+impl Stochastic for Quartic {
+    /// Function for evaluating with uniform noise
+    fn f_noisy(x: Vec<f64>, rng: &mut Rng) -> f64 {
+        Self::f(x) + rng.uniform()
+    }
+}
+
+#[cfg(test)]
+mod quartic_tests {
+    use super::{Quartic as F, NDimensional, SingleObjective, Stochastic, Rng};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn noise() {
+        let x = vec![0.5; F::LOW_D];
+        let fx = F::f_noisy(x.clone(), &mut Rng::new(1));
+        assert_eq!(fx, F::f_noisy(x.clone(), &mut Rng::new(1)));
+        assert!(fx >= F::f(x.clone()) && fx < F::f(x) + 1.0);
+    }
+}
+
+/// This is the Step function, also known as De Jong's third function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). It is made of flat
+/// plateaus, so gradients are zero almost everywhere and small perturbations rarely change the value.
+/// The function accepts a vector with an arbitrary number of inputs.
+pub struct Step {}
+
+impl NDimensional for Step {}
+impl UnConstrained for Step {}
+impl NonSmooth for Step {}
+
+impl Bounded for Step {
+    /// The bounds of the canonical Step optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl SingleObjective for Step {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for xi in x {
+            fx += (xi + 0.5).floor().powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod step_tests {
+    use super::{Step as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
     }
-}
\ No newline at end of file
+}
+
+/// This is the Exponential function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). It is smooth and
+/// unimodal, but unlike most functions in this module its global minimum is not zero. The function
+/// accepts a vector with an arbitrary number of inputs.
+pub struct Exponential {}
+
+impl NDimensional for Exponential {}
+impl UnConstrained for Exponential {}
+
+impl Bounded for Exponential {
+    /// The bounds of the canonical Exponential optimization problem
+    const BOUNDS: (f64, f64) = (-1.0, 1.0);
+}
+
+impl SingleObjective for Exponential {
+    /// The global minimum is constant and negative one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut square_sum = 0.0;
+        for xi in x {
+            square_sum += xi.powi(2);
+        }
+        -(-0.5*square_sum).exp()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod exponential_tests {
+    use super::{Exponential as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Brown function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). Each pair of adjacent
+/// variables is coupled through the exponents, which makes the function moderately ill-conditioned.
+/// The function accepts a vector with an arbitrary number of inputs.
+pub struct Brown {}
+
+impl NDimensional for Brown {}
+impl UnConstrained for Brown {}
+
+impl Bounded for Brown {
+    /// The bounds of the canonical Brown optimization problem
+    const BOUNDS: (f64, f64) = (-1.0, 4.0);
+}
+
+impl SingleObjective for Brown {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for pair in x.windows(2) {
+            let (a, b) = (pair[0].powi(2), pair[1].powi(2));
+            fx += a.powf(b + 1.0) + b.powf(a + 1.0);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod brown_tests {
+    use super::{Brown as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Chung Reynolds function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). It is unimodal, but it
+/// is extremely flat close to the optimum, which makes it useful for studying termination criteria.
+/// The function accepts a vector with an arbitrary number of inputs.
+pub struct ChungReynolds {}
+
+impl NDimensional for ChungReynolds {}
+impl UnConstrained for ChungReynolds {}
+
+impl Bounded for ChungReynolds {
+    /// The bounds of the canonical Chung Reynolds optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl SingleObjective for ChungReynolds {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut square_sum = 0.0;
+        for xi in x {
+            square_sum += xi.powi(2);
+        }
+        square_sum.powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod chung_reynolds_tests {
+    use super::{ChungReynolds as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Csendes function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). The term sin(1/x) is
+/// undefined at zero, but it is multiplied by x^6, so each term is given its limit value of zero
+/// there. The function accepts a vector with an arbitrary number of inputs.
+pub struct Csendes {}
+
+impl NDimensional for Csendes {}
+impl UnConstrained for Csendes {}
+
+impl Bounded for Csendes {
+    /// The bounds of the canonical Csendes optimization problem
+    const BOUNDS: (f64, f64) = (-1.0, 1.0);
+}
+
+impl SingleObjective for Csendes {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for xi in x {
+            if xi != 0.0 {
+                fx += xi.powi(6)*(2.0 + (1.0/xi).sin());
+            }
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod csendes_tests {
+    use super::{Csendes as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Qing function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). It has 2^n global
+/// minimizers at x_i = ±√i; the one with every coordinate positive is used as the canonical
+/// minimizer. The function accepts a vector with an arbitrary number of inputs.
+pub struct Qing {}
+
+impl NDimensional for Qing {}
+impl UnConstrained for Qing {}
+
+impl Bounded for Qing {
+    /// The bounds of the canonical Qing optimization problem
+    const BOUNDS: (f64, f64) = (-500.0, 500.0);
+}
+
+impl SingleObjective for Qing {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            fx += (xi.powi(2) - ((i + 1) as f64)).powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        (1..=n).map(|i| (i as f64).sqrt()).collect()
+    }
+}
+
+impl MultipleMinimizers for Qing {
+    /// There are two choices of sign for every coordinate
+    fn minimizer_count(n: usize) -> usize {
+        2usize.saturating_pow(n as u32)
+    }
+
+    /// This function enumerates all 2^n minimizers, so it should only be used in low dimensions
+    fn all_minimizers(n: usize) -> Vec<Vec<f64>> {
+        let canonical = Self::minimizer(n);
+        (0..Self::minimizer_count(n)).map(|signs| {
+            canonical.iter().enumerate()
+                .map(|(i, xi)| if (signs >> i) & 1 == 1 { -xi } else { *xi })
+                .collect()
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod qing_tests {
+    use super::{Qing as F, NDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers(F::LOW_D)
+    }
+}
+
+/// This is the Quintic function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). Every coordinate of a
+/// global minimizer is either -1 or 2, so there are 2^n global minimizers; the one with every
+/// coordinate equal to -1 is used as the canonical minimizer. The function accepts a vector with an
+/// arbitrary number of inputs.
+pub struct Quintic {}
+
+impl NDimensional for Quintic {}
+impl UnConstrained for Quintic {}
+impl NonSmooth for Quintic {}
+
+impl Bounded for Quintic {
+    /// The bounds of the canonical Quintic optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl SingleObjective for Quintic {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for xi in x {
+            fx += (xi.powi(5) - 3.0*xi.powi(4) + 4.0*xi.powi(3) + 2.0*xi.powi(2) - 10.0*xi - 4.0).abs();
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![-1.0; n]
+    }
+}
+
+impl MultipleMinimizers for Quintic {
+    /// There are two choices for every coordinate
+    fn minimizer_count(n: usize) -> usize {
+        2usize.saturating_pow(n as u32)
+    }
+
+    /// This function enumerates all 2^n minimizers, so it should only be used in low dimensions
+    fn all_minimizers(n: usize) -> Vec<Vec<f64>> {
+        (0..Self::minimizer_count(n)).map(|choice| {
+            (0..n).map(|i| if (choice >> i) & 1 == 1 { 2.0 } else { -1.0 }).collect()
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod quintic_tests {
+    use super::{Quintic as F, NDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers(F::LOW_D)
+    }
+}
+
+/// This is the expanded Schaffer F6 function.
+///
+/// The function is defined as in the CEC 2014 benchmark suite, where the two-dimensional Schaffer F6
+/// function is applied to every pair of adjacent variables (wrapping around from the last variable to
+/// the first) and the results are summed. The function accepts a vector with an arbitrary number of
+/// inputs.
+pub struct ExpandedSchafferF6 {}
+
+impl NDimensional for ExpandedSchafferF6 {}
+impl UnConstrained for ExpandedSchafferF6 {}
+
+impl Bounded for ExpandedSchafferF6 {
+    /// The bounds of the canonical expanded Schaffer F6 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl SingleObjective for ExpandedSchafferF6 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len();
+        let mut fx = 0.0;
+        for i in 0..n {
+            let square_sum = x[i].powi(2) + x[(i + 1) % n].powi(2);
+            fx += 0.5 + (square_sum.sqrt().sin().powi(2) - 0.5)/(1.0 + 0.001*square_sum).powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod expanded_schaffer_f6_tests {
+    use super::{ExpandedSchafferF6 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Schaffer F7 function.
+///
+/// The function is defined as in the CEC 2013 benchmark suite, and is built from the distances
+/// between every pair of adjacent variables. The function accepts a vector with at least two inputs.
+pub struct SchafferF7 {}
+
+impl NDimensional for SchafferF7 {}
+impl UnConstrained for SchafferF7 {}
+
+impl Bounded for SchafferF7 {
+    /// The bounds of the canonical Schaffer F7 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl SingleObjective for SchafferF7 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len();
+        let mut sum = 0.0;
+        for pair in x.windows(2) {
+            let s = (pair[0].powi(2) + pair[1].powi(2)).sqrt();
+            sum += s.sqrt() + s.sqrt()*(50.0*s.powf(0.2)).sin().powi(2);
+        }
+        (sum/((n - 1) as f64)).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod schaffer_f7_tests {
+    use super::{SchafferF7 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Bent Cigar function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the rotation
+/// and shift used in the BBOB suite. It is unimodal, but badly conditioned: the function is a
+/// million times more sensitive to every variable except the first. The function accepts a vector
+/// with an arbitrary number of inputs.
+pub struct BentCigar {}
+
+impl NDimensional for BentCigar {}
+impl UnConstrained for BentCigar {}
+
+impl Bounded for BentCigar {
+    /// The bounds of the canonical Bent Cigar optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl SingleObjective for BentCigar {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut square_sum = 0.0;
+        for xi in x.iter().skip(1) {
+            square_sum += xi.powi(2);
+        }
+        x[0].powi(2) + 1e6*square_sum
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod bent_cigar_tests {
+    use super::{BentCigar as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Discus function, also known as the Tablet function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the rotation
+/// and shift used in the BBOB suite. It is the complement of the Bent Cigar function: the function
+/// is a million times more sensitive to the first variable than to any other. The function accepts a
+/// vector with an arbitrary number of inputs.
+pub struct Discus {}
+
+impl NDimensional for Discus {}
+impl UnConstrained for Discus {}
+
+impl Bounded for Discus {
+    /// The bounds of the canonical Discus optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl SingleObjective for Discus {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut square_sum = 0.0;
+        for xi in x.iter().skip(1) {
+            square_sum += xi.powi(2);
+        }
+        1e6*x[0].powi(2) + square_sum
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod discus_tests {
+    use super::{Discus as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Sharp Ridge function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the rotation
+/// and shift used in the BBOB suite. The ridge along the first variable is not differentiable,
+/// which defeats many line-search methods. The function accepts a vector with an arbitrary number of
+/// inputs.
+pub struct SharpRidge {}
+
+impl NDimensional for SharpRidge {}
+impl UnConstrained for SharpRidge {}
+impl NonSmooth for SharpRidge {}
+
+impl Bounded for SharpRidge {
+    /// The bounds of the canonical Sharp Ridge optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl SingleObjective for SharpRidge {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut square_sum = 0.0;
+        for xi in x.iter().skip(1) {
+            square_sum += xi.powi(2);
+        }
+        x[0].powi(2) + 100.0*square_sum.sqrt()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod sharp_ridge_tests {
+    use super::{SharpRidge as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Ellipsoidal function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the
+/// oscillation transformation and shift used in the BBOB suite. The weights of the variables grow
+/// geometrically from 1 to the condition number, which is a parameter of the function:
+///
+/// ```
+/// use benchfun::{Ellipsoidal, Parameterized};
+///
+/// let f = Ellipsoidal::new(1e3);
+/// assert_eq!(f.evaluate(vec![0.0, 1.0]), 1e3);
+/// ```
+///
+/// The static `SingleObjective` implementation uses the standard condition number of 10^6. The
+/// function accepts a vector with an arbitrary number of inputs.
+pub struct Ellipsoidal {
+    /// The ratio between the largest and smallest weights
+    pub condition: f64,
+}
+
+impl Ellipsoidal {
+    /// This function creates an instance with a given condition number, which must be at least one
+    pub fn new(condition: f64) -> Ellipsoidal {
+        if condition.is_nan() || condition < 1.0 {
+            panic!("The condition number must be at least one, but {} was used.", condition);
+        }
+        Ellipsoidal { condition }
+    }
+}
+
+impl Default for Ellipsoidal {
+    fn default() -> Ellipsoidal {
+        Ellipsoidal::new(1e6)
+    }
+}
+
+impl NDimensional for Ellipsoidal {}
+impl UnConstrained for Ellipsoidal {}
+
+impl Bounded for Ellipsoidal {
+    /// The bounds of the canonical Ellipsoidal optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl Parameterized for Ellipsoidal {
+    /// Function for evaluating with the condition number of this instance
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        let n = x.len();
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            let weight = if n > 1 { self.condition.powf(i as f64/((n - 1) as f64)) } else { 1.0 };
+            fx += weight*xi.powi(2);
+        }
+        fx
+    }
+
+    /// The global minimum does not depend on the condition number
+    fn global_minimum(&self) -> Option<f64> {
+        Some(0.0)
+    }
+
+    /// The minimizer does not depend on the condition number
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        Some(vec![0.0; n])
+    }
+}
+
+impl SingleObjective for Ellipsoidal {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating with the default condition number
+    fn f(x: Vec<f64>) -> f64 {
+        Ellipsoidal::default().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod ellipsoidal_tests {
+    use super::{Ellipsoidal as F, NDimensional, SingleObjective, Parameterized};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn condition_sweep() {
+        for condition in [1.0, 1e2, 1e4, 1e6, 1e8].iter() {
+            let f = F::new(*condition);
+            f.check_global_minimizer(F::HIGH_D);
+            assert!((f.evaluate(vec![1.0, 1.0, 1.0]) - (1.0 + condition.sqrt() + condition)).abs() < 1e-9*condition);
+        }
+    }
+}
+
+/// This is the Attractive Sector function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the
+/// rotations used in the BBOB suite. Every variable is penalized a hundred times more heavily when
+/// it lies on the same side of the optimum as the optimum lies of the origin, so the function is
+/// highly asymmetric around the shifted optimum:
+///
+/// ```
+/// use benchfun::{AttractiveSector, Parameterized, Shift};
+///
+/// let f = AttractiveSector::new(Shift::random(10, 1));
+/// assert_eq!(f.global_minimizer(10), Some(f.shift.x_opt.clone()));
+/// ```
+///
+/// The function accepts a vector with the same number of inputs as the shift.
+pub struct AttractiveSector {
+    /// The shift that places the optimum of this instance
+    pub shift: Shift,
+}
+
+impl AttractiveSector {
+    /// This function creates an instance with a given shift
+    pub fn new(shift: Shift) -> AttractiveSector {
+        AttractiveSector { shift }
+    }
+}
+
+impl NDimensional for AttractiveSector {}
+impl UnConstrained for AttractiveSector {}
+
+impl Bounded for AttractiveSector {
+    /// The bounds of the canonical Attractive Sector optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl Parameterized for AttractiveSector {
+    /// Function for evaluating with the shift of this instance
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        let z = self.shift.apply(&x);
+        let n = z.len();
+        let mut fx = 0.0;
+        for (i, zi) in z.iter().enumerate() {
+            let zi = conditioning(10.0, i, n)*zi;
+            let si = if zi*self.shift.x_opt[i] > 0.0 { 100.0 } else { 1.0 };
+            fx += (si*zi).powi(2);
+        }
+        oscillate(fx).powf(0.9)
+    }
+
+    /// The global minimum is zero for every shift
+    fn global_minimum(&self) -> Option<f64> {
+        Some(0.0)
+    }
+
+    /// The minimizer is the location of the shifted optimum
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        if n == self.shift.x_opt.len() {
+            Some(self.shift.x_opt.clone())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod attractive_sector_tests {
+    use super::{AttractiveSector as F, NDimensional, Parameterized, Shift, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::new(Shift::random(F::LOW_D, 1)).check_global_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::new(Shift::random(F::HIGH_D, 1)).check_global_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn asymmetric() {
+        let f = F::new(Shift::new(vec![1.0, 1.0]));
+        assert!(f.evaluate(vec![1.5, 1.0]) > f.evaluate(vec![0.5, 1.0]));
+    }
+
+    #[test]
+    fn bounds() {
+        F::check_bounds(F::LOW_D);
+        F::check_bounds(F::HIGH_D);
+    }
+}
+
+/// This is the Lunacek bi-Rastrigin function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the
+/// rotations and shift used in the BBOB suite. A Rastrigin landscape is laid over two funnels: the
+/// global optimum is at the bottom of the funnel centred on μ0 = 2.5, but the funnel centred on μ1,
+/// which is slightly shallower, covers a larger part of the search space. The function accepts a
+/// vector with an arbitrary number of inputs.
+pub struct LunacekBiRastrigin {}
+
+impl LunacekBiRastrigin {
+    /// This constant is the centre of the funnel that contains the global optimum
+    pub const MU0: f64 = 2.5;
+
+    /// This function returns the parameter s, which controls the width of the second funnel
+    fn s(n: usize) -> f64 {
+        1.0 - 1.0/(2.0*((n + 20) as f64).sqrt() - 8.2)
+    }
+
+    /// This function returns μ1, the centre of the funnel that does not contain the global optimum
+    pub fn mu1(n: usize) -> f64 {
+        -((Self::MU0.powi(2) - 1.0)/Self::s(n)).sqrt()
+    }
+
+    /// This function returns the bottom of the funnel that does not contain the global optimum
+    pub fn second_funnel(n: usize) -> Vec<f64> {
+        vec![Self::mu1(n); n]
+    }
+}
+
+impl NDimensional for LunacekBiRastrigin {}
+impl UnConstrained for LunacekBiRastrigin {}
+
+impl Bounded for LunacekBiRastrigin {
+    /// The bounds of the canonical Lunacek bi-Rastrigin optimization problem
+    const BOUNDS: (f64, f64) = (-5.12, 5.12);
+}
+
+impl SingleObjective for LunacekBiRastrigin {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len();
+        let mu1 = Self::mu1(n);
+        let mut first_funnel = 0.0;
+        let mut second_funnel = 0.0;
+        let mut rastrigin = 0.0;
+        for xi in x {
+            first_funnel += (xi - Self::MU0).powi(2);
+            second_funnel += (xi - mu1).powi(2);
+            rastrigin += 1.0 - (2.0*std::f64::consts::PI*(xi - Self::MU0)).cos();
+        }
+        first_funnel.min(n as f64 + Self::s(n)*second_funnel) + 10.0*rastrigin
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![Self::MU0; n]
+    }
+}
+
+#[cfg(test)]
+mod lunacek_bi_rastrigin_tests {
+    use super::{LunacekBiRastrigin as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn second_funnel() {
+        for d in [F::LOW_D, F::HIGH_D].iter() {
+            assert!(F::f(F::second_funnel(*d)) > F::MINIMUM);
+        }
+    }
+}
+
+/// This is Gallagher's Gaussian peaks function.
+///
+/// The function is borrowed from [here](https://hal.inria.fr/inria-00362633), without the
+/// rotation used in the BBOB suite. The landscape is made of randomly placed and randomly
+/// conditioned Gaussian peaks, and the location of the highest peak is the global minimizer. There
+/// are two standard variants, with 101 peaks and with 21 peaks:
+///
+/// ```
+/// use benchfun::{Gallagher, Parameterized};
+///
+/// let f = Gallagher::peaks_101(5, 1);
+/// let (location, _height) = f.optimum_peak();
+/// assert_eq!(f.evaluate(location.to_vec()), 0.0);
+/// ```
+///
+/// Instances are generated reproducibly from a seed. Each instance has a fixed dimensionality.
+pub struct Gallagher {
+    /// The locations of the peaks, the first of which is the highest
+    pub locations: Vec<Vec<f64>>,
+    /// The heights of the peaks
+    pub heights: Vec<f64>,
+    /// The diagonal of the conditioning matrix of each peak
+    pub scales: Vec<Vec<f64>>,
+}
+
+impl Gallagher {
+    /// This function generates an instance with 101 peaks in n dimensions
+    pub fn peaks_101(n: usize, seed: u64) -> Gallagher {
+        Self::generate(n, seed, 101, 1000.0, 4.0, 5.0)
+    }
+
+    /// This function generates an instance with 21 peaks in n dimensions
+    pub fn peaks_21(n: usize, seed: u64) -> Gallagher {
+        Self::generate(n, seed, 21, 1000.0f64.powi(2), 3.92, 4.9)
+    }
+
+    /// This function generates the peaks. The highest peak has condition number `alpha_optimum` and
+    /// is placed in [-optimum_range, optimum_range]^n, and the others in [-peak_range, peak_range]^n.
+    fn generate(n: usize, seed: u64, n_peaks: usize, alpha_optimum: f64, optimum_range: f64, peak_range: f64) -> Gallagher {
+        let mut rng = Rng::new(seed);
+        let m = n_peaks - 1;
+
+        let mut alphas: Vec<f64> = (0..m).map(|j| 1000.0f64.powf(2.0*(j as f64)/((m - 1) as f64))).collect();
+        rng.shuffle(&mut alphas);
+        alphas.insert(0, alpha_optimum);
+
+        let mut heights = vec![10.0];
+        heights.extend((0..m).map(|i| 1.1 + 8.0*(i as f64)/((m - 1) as f64)));
+
+        let mut locations = Vec::with_capacity(n_peaks);
+        let mut scales = Vec::with_capacity(n_peaks);
+        for (i, alpha) in alphas.iter().enumerate() {
+            let range = if i == 0 { optimum_range } else { peak_range };
+            locations.push((0..n).map(|_| range*(2.0*rng.uniform() - 1.0)).collect());
+            let mut scale: Vec<f64> = (0..n).map(|j| conditioning(*alpha, j, n)/alpha.powf(0.25)).collect();
+            rng.shuffle(&mut scale);
+            scales.push(scale);
+        }
+
+        Gallagher { locations, heights, scales }
+    }
+
+    /// This function returns the location and height of the highest peak, which is the global optimum
+    pub fn optimum_peak(&self) -> (&[f64], f64) {
+        (&self.locations[0], self.heights[0])
+    }
+}
+
+impl NDimensional for Gallagher {}
+impl UnConstrained for Gallagher {}
+
+impl Bounded for Gallagher {
+    /// The bounds of the canonical Gallagher optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl Parameterized for Gallagher {
+    /// Function for evaluating with the peaks of this instance
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        let n = self.locations[0].len();
+        if x.len() != n {
+            panic!("A vector with size {} was used with an instance of dimensionality {}.", x.len(), n);
+        }
+
+        let mut highest: f64 = 0.0;
+        for ((location, height), scale) in self.locations.iter().zip(self.heights.iter()).zip(self.scales.iter()) {
+            let mut distance = 0.0;
+            for j in 0..n {
+                distance += scale[j]*(x[j] - location[j]).powi(2);
+            }
+            highest = highest.max(height*(-distance/(2.0*n as f64)).exp());
+        }
+
+        let mut penalty = 0.0;
+        for xi in x {
+            penalty += (xi.abs() - 5.0).max(0.0).powi(2);
+        }
+        oscillate(10.0 - highest).powi(2) + penalty
+    }
+
+    /// The global minimum is zero for every instance
+    fn global_minimum(&self) -> Option<f64> {
+        Some(0.0)
+    }
+
+    /// The minimizer is the location of the highest peak
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        if n == self.locations[0].len() {
+            Some(self.locations[0].clone())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod gallagher_tests {
+    use super::{Gallagher as F, NDimensional, Parameterized, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::peaks_101(F::LOW_D, 1).check_global_minimizer(F::LOW_D);
+        F::peaks_21(F::LOW_D, 1).check_global_minimizer(F::LOW_D);
+    }
+
+    #[test]
+    fn high_d() {
+        F::peaks_101(F::HIGH_D, 1).check_global_minimizer(F::HIGH_D);
+        F::peaks_21(F::HIGH_D, 1).check_global_minimizer(F::HIGH_D);
+    }
+
+    #[test]
+    fn reproducible() {
+        let f = F::peaks_21(10, 7);
+        assert_eq!(f.locations, F::peaks_21(10, 7).locations);
+        assert_eq!(f.heights.len(), 21);
+        assert!(f.heights.iter().skip(1).all(|h| *h < f.optimum_peak().1));
+    }
+
+    #[test]
+    fn bounds() {
+        F::check_bounds(F::LOW_D);
+        F::check_bounds(F::HIGH_D);
+    }
+}
+
+/// This is the Cosine Mixture function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). Its global minimum
+/// depends on the dimensionality: it is -0.1n for a vector with n inputs, so `minimum` should be
+/// used instead of `MINIMUM`, which holds the value in two dimensions. The function accepts a
+/// vector with an arbitrary number of inputs.
+pub struct CosineMixture {}
+
+impl NDimensional for CosineMixture {}
+impl UnConstrained for CosineMixture {}
+
+impl Bounded for CosineMixture {
+    /// The bounds of the canonical Cosine Mixture optimization problem
+    const BOUNDS: (f64, f64) = (-1.0, 1.0);
+}
+
+impl SingleObjective for CosineMixture {
+    /// The global minimum in two dimensions
+    const MINIMUM: f64 = -0.2;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut cosine_sum = 0.0;
+        let mut square_sum = 0.0;
+        for xi in x {
+            cosine_sum += (5.0*std::f64::consts::PI*xi).cos();
+            square_sum += xi.powi(2);
+        }
+        -0.1*cosine_sum + square_sum
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+
+    /// The global minimum is -0.1 for every input
+    fn minimum(n: usize) -> f64 {
+        -0.1*(n as f64)
+    }
+}
+
+#[cfg(test)]
+mod cosine_mixture_tests {
+    use super::{CosineMixture as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is Pintér's function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). It is the sum of a
+/// weighted sphere, a rugged sine term, and a logarithmic term, the last two of which couple every
+/// variable to its neighbours (wrapping around from the last variable to the first). The function
+/// accepts a vector with an arbitrary number of inputs.
+pub struct Pinter {}
+
+impl NDimensional for Pinter {}
+impl UnConstrained for Pinter {}
+
+impl Bounded for Pinter {
+    /// The bounds of the canonical Pintér optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl SingleObjective for Pinter {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len();
+        let mut fx = 0.0;
+        for i in 0..n {
+            let weight = (i + 1) as f64;
+            let previous = x[(i + n - 1) % n];
+            let next = x[(i + 1) % n];
+            let a = previous*x[i].sin() + next.sin();
+            let b = previous.powi(2) - 2.0*x[i] + 3.0*next - x[i].cos() + 1.0;
+            fx += weight*x[i].powi(2) + 20.0*weight*a.sin().powi(2) + weight*(1.0 + weight*b.powi(2)).log10();
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod pinter_tests {
+    use super::{Pinter as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Sine Envelope function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). A Schaffer-like term is
+/// applied to every pair of adjacent variables, producing concentric ripples whose envelope is
+/// almost flat far from the optimum. The function accepts a vector with at least two inputs.
+pub struct SineEnvelope {}
+
+impl NDimensional for SineEnvelope {}
+impl UnConstrained for SineEnvelope {}
+
+impl Bounded for SineEnvelope {
+    /// The bounds of the canonical Sine Envelope optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl SingleObjective for SineEnvelope {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut fx = 0.0;
+        for pair in x.windows(2) {
+            let square_sum = pair[0].powi(2) + pair[1].powi(2);
+            fx += (square_sum.sqrt().sin().powi(2) - 0.5)/(0.001*square_sum + 1.0).powi(2) + 0.5;
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod sine_envelope_tests {
+    use super::{SineEnvelope as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Vincent function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008), negated so that the
+/// optima are minima. Every coordinate of a global minimizer is one of the six values
+/// exp((π/2 + 2πk)/10) with k = -2, ..., 3, so there are 6^n global minimizers that are spaced more
+/// and more widely across the domain. This makes the function a standard benchmark for niching
+/// methods. The function accepts a vector with an arbitrary number of inputs.
+pub struct Vincent {}
+
+impl Vincent {
+    /// This function returns the six values that the coordinates of a global minimizer can take
+    pub fn optimal_coordinates() -> Vec<f64> {
+        (-2..=3).map(|k| ((0.5 + 2.0*k as f64)*std::f64::consts::PI/10.0).exp()).collect()
+    }
+}
+
+impl NDimensional for Vincent {}
+impl UnConstrained for Vincent {}
+
+impl Bounded for Vincent {
+    /// The bounds of the canonical Vincent optimization problem
+    const BOUNDS: (f64, f64) = (0.25, 10.0);
+}
+
+impl SingleObjective for Vincent {
+    /// The global minimum is constant and negative one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len() as f64;
+        let mut sine_sum = 0.0;
+        for xi in x {
+            sine_sum += (10.0*xi.ln()).sin();
+        }
+        -sine_sum/n
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![Self::optimal_coordinates()[2]; n]
+    }
+}
+
+impl MultipleMinimizers for Vincent {
+    /// There are six choices for every coordinate
+    fn minimizer_count(n: usize) -> usize {
+        6usize.saturating_pow(n as u32)
+    }
+
+    /// This function enumerates all 6^n minimizers, so it should only be used in low dimensions
+    fn all_minimizers(n: usize) -> Vec<Vec<f64>> {
+        let coordinates = Self::optimal_coordinates();
+        (0..Self::minimizer_count(n)).map(|mut choice| {
+            (0..n).map(|_| {
+                let xi = coordinates[choice % 6];
+                choice /= 6;
+                xi
+            }).collect()
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod vincent_tests {
+    use super::{Vincent as F, NDimensional, SingleObjective, MultipleMinimizers, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers(F::LOW_D);
+        assert!(F::all_minimizers(F::LOW_D).into_iter().all(F::in_bounds));
+    }
+}
+
+/// This is Deb's function N.1.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008), on the domain used in
+/// the niching literature. Every coordinate of a global minimizer is one of 0.1, 0.3, 0.5, 0.7 and
+/// 0.9, so there are 5^n equally spaced global minimizers with basins of equal size. The function
+/// accepts a vector with an arbitrary number of inputs.
+pub struct DebN1 {}
+
+impl DebN1 {
+    /// This function returns the five values that the coordinates of a global minimizer can take
+    pub fn optimal_coordinates() -> Vec<f64> {
+        vec![0.1, 0.3, 0.5, 0.7, 0.9]
+    }
+}
+
+impl NDimensional for DebN1 {}
+impl UnConstrained for DebN1 {}
+
+impl Bounded for DebN1 {
+    /// The bounds of the canonical Deb N.1 optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl SingleObjective for DebN1 {
+    /// The global minimum is constant and negative one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len() as f64;
+        let mut sine_sum = 0.0;
+        for xi in x {
+            sine_sum += (5.0*std::f64::consts::PI*xi).sin().powi(6);
+        }
+        -sine_sum/n
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.1; n]
+    }
+}
+
+impl MultipleMinimizers for DebN1 {
+    /// There are five choices for every coordinate
+    fn minimizer_count(n: usize) -> usize {
+        5usize.saturating_pow(n as u32)
+    }
+
+    /// This function enumerates all 5^n minimizers, so it should only be used in low dimensions
+    fn all_minimizers(n: usize) -> Vec<Vec<f64>> {
+        let coordinates = Self::optimal_coordinates();
+        (0..Self::minimizer_count(n)).map(|mut choice| {
+            (0..n).map(|_| {
+                let xi = coordinates[choice % 5];
+                choice /= 5;
+                xi
+            }).collect()
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod deb_n1_tests {
+    use super::{DebN1 as F, NDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers(F::LOW_D)
+    }
+}
+
+/// This is Deb's deceptive function, with unevenly spaced peaks of decreasing height.
+///
+/// The function is F4 from Deb and Goldberg's study of niching (1989), averaged over the
+/// coordinates and negated so that the peaks are minima. Along every coordinate there are five
+/// local minima, listed by `peaks`. The spacing between them grows while their depth shrinks, so
+/// the basin of the global minimum is the narrowest and most of the domain is attracted toward
+/// sub-optimal minima. There are 5^n local minima, only one of which is global. The function
+/// accepts a vector with an arbitrary number of inputs.
+pub struct DebDeceptive {}
+
+impl DebDeceptive {
+    /// This function returns the location and depth of the five minima along every coordinate, from
+    /// the global one to the shallowest
+    pub fn peaks() -> Vec<(f64, f64)> {
+        vec![
+            (0.079_699_779_611_795_82, 0.999_999_828_454_472_5),
+            (0.246_278_679_461_454_3, 0.948_689_312_566_446_5),
+            (0.449_495_533_121_724_7, 0.770_815_238_605_467_2),
+            (0.679_165_738_146_838, 0.504_111_509_545_692_2),
+            (0.930_152_737_419_732_8, 0.251_610_081_281_318_6),
+        ]
+    }
+
+    /// This function returns the number of local minima, saturating at `usize::MAX`
+    pub fn local_minimizer_count(n: usize) -> usize {
+        5usize.saturating_pow(n as u32)
+    }
+}
+
+impl NDimensional for DebDeceptive {}
+impl UnConstrained for DebDeceptive {}
+
+impl Bounded for DebDeceptive {
+    /// The bounds of the canonical Deb deceptive optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl SingleObjective for DebDeceptive {
+    /// The global minimum is constant and slightly above negative one
+    const MINIMUM: f64 = -0.999_999_828_454_472_5;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let n = x.len() as f64;
+        let mut peak_sum = 0.0;
+        for xi in x {
+            let envelope = (-2.0*std::f64::consts::LN_2*((xi - 0.08)/0.854).powi(2)).exp();
+            peak_sum += envelope*(5.0*std::f64::consts::PI*(xi.powf(0.75) - 0.05)).sin().powi(6);
+        }
+        -peak_sum/n
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![Self::peaks()[0].0; n]
+    }
+}
+
+#[cfg(test)]
+mod deb_deceptive_tests {
+    use super::{DebDeceptive as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-14)
+    }
+
+    #[test]
+    fn peaks() {
+        for (location, depth) in F::peaks() {
+            assert!((F::f(vec![location]) + depth).abs() < 1e-12);
+            assert!(F::f(vec![location - 1e-3]) > -depth && F::f(vec![location + 1e-3]) > -depth);
+        }
+    }
+}
+
+/// This is the Periodic function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). A periodic landscape of
+/// local minima with value one surrounds a slightly deeper global minimum at the origin. The
+/// function accepts a vector with an arbitrary number of inputs.
+pub struct Periodic {}
+
+impl NDimensional for Periodic {}
+impl UnConstrained for Periodic {}
+
+impl Bounded for Periodic {
+    /// The bounds of the canonical Periodic optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl SingleObjective for Periodic {
+    /// The global minimum is constant and 0.9
+    const MINIMUM: f64 = 0.9;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        let mut sine_sum = 0.0;
+        let mut square_sum = 0.0;
+        for xi in x {
+            sine_sum += xi.sin().powi(2);
+            square_sum += xi.powi(2);
+        }
+        1.0 + sine_sum - 0.1*(-square_sum).exp()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod periodic_tests {
+    use super::{Periodic as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is Whitley's function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008). It applies a
+/// Griewank-like term to a two-variable Rosenbrock term for every ordered pair of variables, so each
+/// evaluation costs O(n^2) operations. The function accepts a vector with an arbitrary number of
+/// inputs.
+pub struct Whitley {}
+
+impl NDimensional for Whitley {}
+impl UnConstrained for Whitley {}
+
+impl Bounded for Whitley {
+    /// The bounds of the canonical Whitley optimization problem
+    const BOUNDS: (f64, f64) = (-10.24, 10.24);
+}
+
+impl SingleObjective for Whitley {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        // The terms that only depend on one variable are computed once, outside the quadratic loop
+        let squares: Vec<f64> = x.iter().map(|xi| xi.powi(2)).collect();
+        let offsets: Vec<f64> = x.iter().map(|xj| (1.0 - xj).powi(2)).collect();
+        let mut fx = 0.0;
+        for xi2 in squares.iter() {
+            for (xj, offset) in x.iter().zip(offsets.iter()) {
+                let y = 100.0*(xi2 - xj).powi(2) + offset;
+                fx += y.powi(2)/4000.0 - y.cos() + 1.0;
+            }
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![1.0; n]
+    }
+}
+
+#[cfg(test)]
+mod whitley_tests {
+    use super::{Whitley as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_strict(F::HIGH_D)
+    }
+}
+
+/// This is the Beale function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has sharp peaks at the corners of its domain.
+pub struct Beale {}
+
+impl UnConstrained for Beale {}
+
+impl Bounded for Beale {
+    /// The bounds of the canonical Beale optimization problem
+    const BOUNDS: (f64, f64) = (-4.5, 4.5);
+}
+
+impl FixedDimensional for Beale {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Beale {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (1.5 - x[0] + x[0]*x[1]).powi(2)
+            + (2.25 - x[0] + x[0]*x[1].powi(2)).powi(2)
+            + (2.625 - x[0] + x[0]*x[1].powi(3)).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![3.0, 0.5]
+    }
+}
+
+#[cfg(test)]
+mod beale_tests {
+    use super::{Beale as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Booth function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and is a simple convex quadratic.
+pub struct Booth {}
+
+impl UnConstrained for Booth {}
+
+impl Bounded for Booth {
+    /// The bounds of the canonical Booth optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl FixedDimensional for Booth {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Booth {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (x[0] + 2.0*x[1] - 7.0).powi(2) + (2.0*x[0] + x[1] - 5.0).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![1.0, 3.0]
+    }
+}
+
+#[cfg(test)]
+mod booth_tests {
+    use super::{Booth as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Bukin function N.6.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, has different bounds for each dimension, and has
+/// a narrow, curved, non-differentiable valley that contains the global minimum.
+pub struct BukinN6 {}
+
+impl UnConstrained for BukinN6 {}
+impl NonSmooth for BukinN6 {}
+
+impl Bounded for BukinN6 {
+    /// The smallest interval that contains the bounds of both dimensions
+    const BOUNDS: (f64, f64) = (-15.0, 3.0);
+
+    /// The bounds of the canonical Bukin N.6 optimization problem
+    fn bounds(_n: usize) -> Vec<(f64, f64)> {
+        vec![(-15.0, -5.0), (-3.0, 3.0)]
+    }
+}
+
+impl FixedDimensional for BukinN6 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for BukinN6 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        100.0*(x[1] - 0.01*x[0].powi(2)).abs().sqrt() + 0.01*(x[0] + 10.0).abs()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![-10.0, 1.0]
+    }
+}
+
+#[cfg(test)]
+mod bukin_n6_tests {
+    use super::{BukinN6 as F, FixedDimensional, SingleObjective, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(F::in_bounds(F::minimizer(F::D)));
+        assert!(!F::in_bounds(vec![0.0, 0.0]));
+    }
+}
+
+/// This is the Cross-in-Tray function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has four global minimizers at (±1.3494, ±1.3494).
+/// The global minimum is only known numerically.
+pub struct CrossInTray {}
+
+impl CrossInTray {
+    /// This constant is the magnitude of both coordinates of every global minimizer
+    const OPTIMUM: f64 = 1.349_406_617_153_911;
+}
+
+impl UnConstrained for CrossInTray {}
+impl NonSmooth for CrossInTray {}
+
+impl Bounded for CrossInTray {
+    /// The bounds of the canonical Cross-in-Tray optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl FixedDimensional for CrossInTray {
+    const D: usize = 2;
+}
+
+impl SingleObjective for CrossInTray {
+    /// The global minimum is constant and approximately -2.06261
+    const MINIMUM: f64 = -2.062_611_870_822_737;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let radius = (x[0].powi(2) + x[1].powi(2)).sqrt();
+        let tray = (x[0].sin()*x[1].sin()*(100.0 - radius/std::f64::consts::PI).abs().exp()).abs();
+        -0.0001*(tray + 1.0).powf(0.1)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![Self::OPTIMUM, Self::OPTIMUM]
+    }
+}
+
+impl MultipleMinimizers for CrossInTray {
+    /// There is one global minimizer in each quadrant
+    fn minimizer_count(_n: usize) -> usize {
+        4
+    }
+
+    /// This function returns the global minimizer in each quadrant
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        let x = Self::OPTIMUM;
+        vec![vec![x, x], vec![-x, x], vec![-x, -x], vec![x, -x]]
+    }
+}
+
+#[cfg(test)]
+mod cross_in_tray_tests {
+    use super::{CrossInTray as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-12)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-12)
+    }
+}
+
+/// This is the Drop-Wave function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/drop.html).
+/// This function is specifically 2 dimensional, and is a highly multimodal bowl of concentric
+/// ripples around the global minimum at the origin.
+pub struct DropWave {}
+
+impl UnConstrained for DropWave {}
+
+impl Bounded for DropWave {
+    /// The bounds of the canonical Drop-Wave optimization problem
+    const BOUNDS: (f64, f64) = (-5.12, 5.12);
+}
+
+impl FixedDimensional for DropWave {
+    const D: usize = 2;
+}
+
+impl SingleObjective for DropWave {
+    /// The global minimum is constant and negative one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let square_sum = x[0].powi(2) + x[1].powi(2);
+        -(1.0 + (12.0*square_sum.sqrt()).cos())/(0.5*square_sum + 2.0)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod drop_wave_tests {
+    use super::{DropWave as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Easom function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional. It is flat almost everywhere, and the global minimum
+/// sits at the bottom of a narrow well at (π, π).
+pub struct Easom {}
+
+impl UnConstrained for Easom {}
+
+impl Bounded for Easom {
+    /// The bounds of the canonical Easom optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl FixedDimensional for Easom {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Easom {
+    /// The global minimum is constant and negative one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = std::f64::consts::PI;
+        -x[0].cos()*x[1].cos()*(-(x[0] - pi).powi(2) - (x[1] - pi).powi(2)).exp()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![std::f64::consts::PI; 2]
+    }
+}
+
+#[cfg(test)]
+mod easom_tests {
+    use super::{Easom as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Eggholder function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and is highly multimodal. The global minimizer lies
+/// on the boundary of the domain, and the global minimum is only known numerically.
+pub struct Eggholder {}
+
+impl UnConstrained for Eggholder {}
+impl NonSmooth for Eggholder {}
+
+impl Bounded for Eggholder {
+    /// The bounds of the canonical Eggholder optimization problem
+    const BOUNDS: (f64, f64) = (-512.0, 512.0);
+}
+
+impl FixedDimensional for Eggholder {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Eggholder {
+    /// The global minimum is constant and approximately -959.6407
+    const MINIMUM: f64 = -959.640_662_720_850_8;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let y = x[1] + 47.0;
+        -y*(x[0]/2.0 + y).abs().sqrt().sin() - x[0]*(x[0] - y).abs().sqrt().sin()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![512.0, 404.231_805_113_757_8]
+    }
+}
+
+#[cfg(test)]
+mod eggholder_tests {
+    use super::{Eggholder as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-10)
+    }
+}
+
+/// This is the Goldstein-Price function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional. Its values span several orders of magnitude, so a
+/// log-transformed variant is often used instead:
+///
+/// ```
+/// use benchfun::{GoldsteinPrice, Parameterized};
+///
+/// let f = GoldsteinPrice::new(true);
+/// assert_eq!(f.global_minimum(), Some(3.0f64.ln()));
+/// ```
+///
+/// The static `SingleObjective` implementation uses the original function.
+pub struct GoldsteinPrice {
+    /// This indicates whether the natural logarithm of the function is returned
+    pub log_transformed: bool,
+}
+
+impl GoldsteinPrice {
+    /// This function creates an instance of the original or the log-transformed function
+    pub fn new(log_transformed: bool) -> GoldsteinPrice {
+        GoldsteinPrice { log_transformed }
+    }
+}
+
+impl Default for GoldsteinPrice {
+    fn default() -> GoldsteinPrice {
+        GoldsteinPrice::new(false)
+    }
+}
+
+impl UnConstrained for GoldsteinPrice {}
+
+impl Bounded for GoldsteinPrice {
+    /// The bounds of the canonical Goldstein-Price optimization problem
+    const BOUNDS: (f64, f64) = (-2.0, 2.0);
+}
+
+impl FixedDimensional for GoldsteinPrice {
+    const D: usize = 2;
+}
+
+impl Parameterized for GoldsteinPrice {
+    /// Function for evaluating the original or the log-transformed function
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let (a, b) = (x[0], x[1]);
+        let first = 1.0 + (a + b + 1.0).powi(2)*(19.0 - 14.0*a + 3.0*a.powi(2) - 14.0*b + 6.0*a*b + 3.0*b.powi(2));
+        let second = 30.0 + (2.0*a - 3.0*b).powi(2)*(18.0 - 32.0*a + 12.0*a.powi(2) + 48.0*b - 36.0*a*b + 27.0*b.powi(2));
+        if self.log_transformed {
+            (first*second).ln()
+        } else {
+            first*second
+        }
+    }
+
+    /// The global minimum is 3 for the original function
+    fn global_minimum(&self) -> Option<f64> {
+        if self.log_transformed {
+            Some(3.0f64.ln())
+        } else {
+            Some(3.0)
+        }
+    }
+
+    /// The minimizer is the same for both variants
+    fn global_minimizer(&self, _n: usize) -> Option<Vec<f64>> {
+        Some(vec![0.0, -1.0])
+    }
+}
+
+impl SingleObjective for GoldsteinPrice {
+    /// The global minimum is constant and 3
+    const MINIMUM: f64 = 3.0;
+
+    /// Function for evaluating the original function
+    fn f(x: Vec<f64>) -> f64 {
+        GoldsteinPrice::default().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, -1.0]
+    }
+}
+
+#[cfg(test)]
+mod goldstein_price_tests {
+    use super::{GoldsteinPrice as F, FixedDimensional, SingleObjective, Parameterized};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+
+    #[test]
+    fn log_transformed() {
+        F::new(true).check_global_minimizer(F::D)
+    }
+}
+
+/// This is Himmelblau's function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has four global minimizers, all of which are
+/// returned by `all_minimizers`.
+pub struct Himmelblau {}
+
+impl UnConstrained for Himmelblau {}
+
+impl Bounded for Himmelblau {
+    /// The bounds of the canonical Himmelblau optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl FixedDimensional for Himmelblau {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Himmelblau {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (x[0].powi(2) + x[1] - 11.0).powi(2) + (x[0] + x[1].powi(2) - 7.0).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![3.0, 2.0]
+    }
+}
+
+impl MultipleMinimizers for Himmelblau {
+    /// There are four global minimizers
+    fn minimizer_count(_n: usize) -> usize {
+        4
+    }
+
+    /// This function returns the four global minimizers
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        vec![
+            vec![3.0, 2.0],
+            vec![-2.805_118_086_952_745, 3.131_312_518_250_573],
+            vec![-3.779_310_253_377_747, -3.283_185_991_286_169],
+            vec![3.584_428_340_330_492, -1.848_126_526_964_404],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod himmelblau_tests {
+    use super::{Himmelblau as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers(F::D)
+    }
+}
+
+/// This is the Hölder Table function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has four global minimizers near the corners of
+/// its domain, at (±8.0550, ±9.6646). The global minimum is only known numerically.
+pub struct HolderTable {}
+
+impl HolderTable {
+    /// This constant holds the magnitudes of the coordinates of every global minimizer
+    const OPTIMUM: (f64, f64) = (8.055_023_475_736_563, 9.664_590_019_241_273);
+}
+
+impl UnConstrained for HolderTable {}
+impl NonSmooth for HolderTable {}
+
+impl Bounded for HolderTable {
+    /// The bounds of the canonical Hölder Table optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl FixedDimensional for HolderTable {
+    const D: usize = 2;
+}
+
+impl SingleObjective for HolderTable {
+    /// The global minimum is constant and approximately -19.2085
+    const MINIMUM: f64 = -19.208_502_567_886_73;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let radius = (x[0].powi(2) + x[1].powi(2)).sqrt();
+        -(x[0].sin()*x[1].cos()*(1.0 - radius/std::f64::consts::PI).abs().exp()).abs()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![Self::OPTIMUM.0, Self::OPTIMUM.1]
+    }
+}
+
+impl MultipleMinimizers for HolderTable {
+    /// There is one global minimizer in each quadrant
+    fn minimizer_count(_n: usize) -> usize {
+        4
+    }
+
+    /// This function returns the global minimizer in each quadrant
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        let (x, y) = Self::OPTIMUM;
+        vec![vec![x, y], vec![-x, y], vec![-x, -y], vec![x, -y]]
+    }
+}
+
+#[cfg(test)]
+mod holder_table_tests {
+    use super::{HolderTable as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-12)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-12)
+    }
+}
+
+/// This is the Lévi function N.13.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has a rugged landscape of local minima around
+/// the global minimizer at (1, 1).
+pub struct LeviN13 {}
+
+impl UnConstrained for LeviN13 {}
+
+impl Bounded for LeviN13 {
+    /// The bounds of the canonical Lévi N.13 optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl FixedDimensional for LeviN13 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for LeviN13 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = std::f64::consts::PI;
+        (3.0*pi*x[0]).sin().powi(2)
+            + (x[0] - 1.0).powi(2)*(1.0 + (3.0*pi*x[1]).sin().powi(2))
+            + (x[1] - 1.0).powi(2)*(1.0 + (2.0*pi*x[1]).sin().powi(2))
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![1.0, 1.0]
+    }
+}
+
+#[cfg(test)]
+mod levi_n13_tests {
+    use super::{LeviN13 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::D)
+    }
+}
+
+/// This is the McCormick function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has different bounds for each dimension. The
+/// global minimizer is (1/2 - π/3, -1/2 - π/3), and the global minimum is -√3/2 - π/3.
+pub struct McCormick {}
+
+impl UnConstrained for McCormick {}
+
+impl Bounded for McCormick {
+    /// The smallest interval that contains the bounds of both dimensions
+    const BOUNDS: (f64, f64) = (-3.0, 4.0);
+
+    /// The bounds of the canonical McCormick optimization problem
+    fn bounds(_n: usize) -> Vec<(f64, f64)> {
+        vec![(-1.5, 4.0), (-3.0, 4.0)]
+    }
+}
+
+impl FixedDimensional for McCormick {
+    const D: usize = 2;
+}
+
+impl SingleObjective for McCormick {
+    /// The global minimum is constant and approximately -1.9132
+    const MINIMUM: f64 = -1.913_222_954_981_036_4;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (x[0] + x[1]).sin() + (x[0] - x[1]).powi(2) - 1.5*x[0] + 2.5*x[1] + 1.0
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        let third_pi = std::f64::consts::FRAC_PI_3;
+        vec![0.5 - third_pi, -0.5 - third_pi]
+    }
+}
+
+#[cfg(test)]
+mod mccormick_tests {
+    use super::{McCormick as F, FixedDimensional, SingleObjective, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-15)
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(F::in_bounds(F::minimizer(F::D)));
+        assert!(!F::in_bounds(vec![-2.0, 0.0]));
+    }
+}
+
+/// This is the Schaffer function N.2.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional. Unlike the expanded Schaffer functions, it is not
+/// scalable.
+pub struct SchafferN2 {}
+
+impl UnConstrained for SchafferN2 {}
+
+impl Bounded for SchafferN2 {
+    /// The bounds of the canonical Schaffer N.2 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl FixedDimensional for SchafferN2 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for SchafferN2 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let square_sum = x[0].powi(2) + x[1].powi(2);
+        0.5 + ((x[0].powi(2) - x[1].powi(2)).sin().powi(2) - 0.5)/(1.0 + 0.001*square_sum).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod schaffer_n2_tests {
+    use super::{SchafferN2 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Schaffer function N.4.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has four global minimizers on the axes, at
+/// (0, ±1.2531) and (±1.2531, 0). The global minimum is only known numerically.
+pub struct SchafferN4 {}
+
+impl SchafferN4 {
+    /// This constant is the distance of every global minimizer from the origin
+    const OPTIMUM: f64 = 1.253_131_831_463_733;
+}
+
+impl UnConstrained for SchafferN4 {}
+impl NonSmooth for SchafferN4 {}
+
+impl Bounded for SchafferN4 {
+    /// The bounds of the canonical Schaffer N.4 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl FixedDimensional for SchafferN4 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for SchafferN4 {
+    /// The global minimum is constant and approximately 0.292579
+    const MINIMUM: f64 = 0.292_578_632_035_980_5;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let square_sum = x[0].powi(2) + x[1].powi(2);
+        0.5 + ((x[0].powi(2) - x[1].powi(2)).abs().sin().cos().powi(2) - 0.5)/(1.0 + 0.001*square_sum).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, Self::OPTIMUM]
+    }
+}
+
+impl MultipleMinimizers for SchafferN4 {
+    /// There is one global minimizer on each half-axis
+    fn minimizer_count(_n: usize) -> usize {
+        4
+    }
+
+    /// This function returns the global minimizer on each half-axis
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        let r = Self::OPTIMUM;
+        vec![vec![0.0, r], vec![0.0, -r], vec![r, 0.0], vec![-r, 0.0]]
+    }
+}
+
+#[cfg(test)]
+mod schaffer_n4_tests {
+    use super::{SchafferN4 as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-15)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-15)
+    }
+}
+
+/// This is the Three-Hump Camel function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has three local minima, the deepest of which is
+/// at the origin.
+pub struct ThreeHumpCamel {}
+
+impl UnConstrained for ThreeHumpCamel {}
+
+impl Bounded for ThreeHumpCamel {
+    /// The bounds of the canonical Three-Hump Camel optimization problem
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl FixedDimensional for ThreeHumpCamel {
+    const D: usize = 2;
+}
+
+impl SingleObjective for ThreeHumpCamel {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        2.0*x[0].powi(2) - 1.05*x[0].powi(4) + x[0].powi(6)/6.0 + x[0]*x[1] + x[1].powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod three_hump_camel_tests {
+    use super::{ThreeHumpCamel as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Six-Hump Camel function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/camel6.html).
+/// This function is specifically 2 dimensional, has different bounds for each dimension, and has
+/// six local minima, two of which are global. The global minimum is only known numerically.
+pub struct SixHumpCamel {}
+
+impl SixHumpCamel {
+    /// This constant holds the coordinates of the canonical global minimizer
+    const OPTIMUM: (f64, f64) = (0.089_842_013_100_318_06, -0.712_656_403_020_739_6);
+}
+
+impl UnConstrained for SixHumpCamel {}
+
+impl Bounded for SixHumpCamel {
+    /// The smallest interval that contains the bounds of both dimensions
+    const BOUNDS: (f64, f64) = (-3.0, 3.0);
+
+    /// The bounds of the canonical Six-Hump Camel optimization problem
+    fn bounds(_n: usize) -> Vec<(f64, f64)> {
+        vec![(-3.0, 3.0), (-2.0, 2.0)]
+    }
+}
+
+impl FixedDimensional for SixHumpCamel {
+    const D: usize = 2;
+}
+
+impl SingleObjective for SixHumpCamel {
+    /// The global minimum is constant and approximately -1.0316
+    const MINIMUM: f64 = -1.031_628_453_489_877_4;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (4.0 - 2.1*x[0].powi(2) + x[0].powi(4)/3.0)*x[0].powi(2) + x[0]*x[1] + (-4.0 + 4.0*x[1].powi(2))*x[1].powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![Self::OPTIMUM.0, Self::OPTIMUM.1]
+    }
+}
+
+impl MultipleMinimizers for SixHumpCamel {
+    /// The function is symmetric about the origin, so there are two global minimizers
+    fn minimizer_count(_n: usize) -> usize {
+        2
+    }
+
+    /// This function returns both global minimizers
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        let (x, y) = Self::OPTIMUM;
+        vec![vec![x, y], vec![-x, -y]]
+    }
+}
+
+#[cfg(test)]
+mod six_hump_camel_tests {
+    use super::{SixHumpCamel as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-15)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-15)
+    }
+}
+
+/// This is the Branin function, also known as the Branin-Hoo function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/branin.html).
+/// This function is specifically 2 dimensional, has different bounds for each dimension, and has
+/// three global minimizers, at (-π, 12.275), (π, 2.275) and (3π, 2.475). Bayesian optimization
+/// papers often use a variant that is rescaled to the unit square, with a mean close to zero and a
+/// variance close to one:
+///
+/// ```
+/// use benchfun::{Branin, Parameterized};
+///
+/// let f = Branin::rescaled();
+/// let minimizer = f.global_minimizer(2).unwrap();
+/// assert!(minimizer.iter().all(|xi| 0.0 <= *xi && *xi <= 1.0));
+/// ```
+///
+/// The static `SingleObjective` implementation uses the original function.
+pub struct Branin {
+    /// This indicates whether the function is rescaled to the unit square
+    pub rescaled: bool,
+}
+
+impl Branin {
+    /// This function creates an instance of the original function
+    pub fn new() -> Branin {
+        Branin { rescaled: false }
+    }
+
+    /// This function creates an instance of the variant that is rescaled to the unit square
+    pub fn rescaled() -> Branin {
+        Branin { rescaled: true }
+    }
+
+    /// This function returns the value of the quadratic and cosine terms of the original function
+    fn terms(x: &[f64]) -> (f64, f64) {
+        let pi = std::f64::consts::PI;
+        let quadratic = (x[1] - 5.1*x[0].powi(2)/(4.0*pi.powi(2)) + 5.0*x[0]/pi - 6.0).powi(2);
+        let cosine = (10.0 - 10.0/(8.0*pi))*x[0].cos();
+        (quadratic, cosine)
+    }
+}
+
+impl Default for Branin {
+    fn default() -> Branin {
+        Branin::new()
+    }
+}
+
+impl UnConstrained for Branin {}
+
+impl Bounded for Branin {
+    /// The smallest interval that contains the bounds of both dimensions
+    const BOUNDS: (f64, f64) = (-5.0, 15.0);
+
+    /// The bounds of the canonical Branin optimization problem
+    fn bounds(_n: usize) -> Vec<(f64, f64)> {
+        vec![(-5.0, 10.0), (0.0, 15.0)]
+    }
+}
+
+impl FixedDimensional for Branin {
+    const D: usize = 2;
+}
+
+impl Parameterized for Branin {
+    /// Function for evaluating the original or the rescaled function
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        if self.rescaled {
+            let (quadratic, cosine) = Self::terms(&[15.0*x[0] - 5.0, 15.0*x[1]]);
+            (quadratic + cosine - 44.81)/51.95
+        } else {
+            let (quadratic, cosine) = Self::terms(&x);
+            quadratic + cosine + 10.0
+        }
+    }
+
+    /// The global minimum is 5/(4π) for the original function
+    fn global_minimum(&self) -> Option<f64> {
+        let minimum = 5.0/(4.0*std::f64::consts::PI);
+        if self.rescaled {
+            Some((minimum - 54.81)/51.95)
+        } else {
+            Some(minimum)
+        }
+    }
+
+    /// The canonical minimizer is (π, 2.275) for the original function
+    fn global_minimizer(&self, _n: usize) -> Option<Vec<f64>> {
+        let pi = std::f64::consts::PI;
+        if self.rescaled {
+            Some(vec![(pi + 5.0)/15.0, 2.275/15.0])
+        } else {
+            Some(vec![pi, 2.275])
+        }
+    }
+}
+
+impl SingleObjective for Branin {
+    /// The global minimum is constant and 5/(4π)
+    const MINIMUM: f64 = 0.397_887_357_729_738_16;
+
+    /// Function for evaluating the original function
+    fn f(x: Vec<f64>) -> f64 {
+        Branin::new().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![std::f64::consts::PI, 2.275]
+    }
+}
+
+impl MultipleMinimizers for Branin {
+    /// There are three global minimizers
+    fn minimizer_count(_n: usize) -> usize {
+        3
+    }
+
+    /// This function returns the three global minimizers of the original function
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        let pi = std::f64::consts::PI;
+        vec![vec![-pi, 12.275], vec![pi, 2.275], vec![3.0*pi, 2.475]]
+    }
+}
+
+#[cfg(test)]
+mod branin_tests {
+    use super::{Branin as F, FixedDimensional, SingleObjective, MultipleMinimizers, Parameterized};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-14)
+    }
+
+    #[test]
+    fn rescaled() {
+        let f = F::rescaled();
+        let minimizer = f.global_minimizer(F::D).unwrap();
+        assert!((f.evaluate(minimizer) - f.global_minimum().unwrap()).abs() < 1e-14);
+    }
+}
+
+/// This is the Shubert function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/shubert.html).
+/// This function is specifically 2 dimensional, and has 760 local minima, 18 of which are global.
+/// Every global minimizer pairs a minimizer of the one-dimensional factor with a maximizer of the
+/// other, which is how `all_minimizers` generates them. The global minimum is only known
+/// numerically.
+pub struct Shubert {}
+
+impl Shubert {
+    /// This constant is the number of local minima, including the global ones
+    pub const LOCAL_MINIMA: usize = 760;
+
+    /// These are the minimizers of the one-dimensional factor of the function
+    const FACTOR_MINIMIZERS: [f64; 3] = [-7.708_313_735_499_347, -1.425_128_428_319_761, 4.858_056_878_859_826];
+
+    /// These are the maximizers of the one-dimensional factor of the function
+    const FACTOR_MAXIMIZERS: [f64; 3] = [-7.083_506_407_651_56, -0.800_321_100_471_973_1, 5.482_864_206_707_613];
+}
+
+impl UnConstrained for Shubert {}
+
+impl Bounded for Shubert {
+    /// The bounds of the canonical Shubert optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl FixedDimensional for Shubert {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Shubert {
+    /// The global minimum is constant and approximately -186.7309
+    const MINIMUM: f64 = -186.730_908_831_023_83;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 1.0;
+        for xi in x {
+            let mut factor = 0.0;
+            for i in 1..=5 {
+                let i = i as f64;
+                factor += i*((i + 1.0)*xi + i).cos();
+            }
+            fx *= factor;
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![Self::FACTOR_MINIMIZERS[1], Self::FACTOR_MAXIMIZERS[1]]
+    }
+}
+
+impl MultipleMinimizers for Shubert {
+    /// There are 18 global minimizers
+    fn minimizer_count(_n: usize) -> usize {
+        18
+    }
+
+    /// This function returns the 18 global minimizers
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        let mut minimizers = Vec::with_capacity(18);
+        for a in Self::FACTOR_MINIMIZERS.iter() {
+            for b in Self::FACTOR_MAXIMIZERS.iter() {
+                minimizers.push(vec![*a, *b]);
+                minimizers.push(vec![*b, *a]);
+            }
+        }
+        minimizers
+    }
+}
+
+#[cfg(test)]
+mod shubert_tests {
+    use super::{Shubert as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-12)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-12)
+    }
+}
+
+/// This is Shekel's Foxholes function, also known as De Jong's fifth function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/dejong5.html).
+/// This function is specifically 2 dimensional. It is flat almost everywhere, with 25 holes of
+/// different depths on a regular grid, and the deepest hole is close to (-32, -32). The global
+/// minimum is only known numerically.
+pub struct ShekelFoxholes {}
+
+impl UnConstrained for ShekelFoxholes {}
+
+impl Bounded for ShekelFoxholes {
+    /// The bounds of the canonical Shekel's Foxholes optimization problem
+    const BOUNDS: (f64, f64) = (-65.536, 65.536);
+}
+
+impl FixedDimensional for ShekelFoxholes {
+    const D: usize = 2;
+}
+
+impl SingleObjective for ShekelFoxholes {
+    /// The global minimum is constant and approximately 0.998004
+    const MINIMUM: f64 = 0.998_003_837_794_450_3;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let grid = [-32.0, -16.0, 0.0, 16.0, 32.0];
+        let mut sum = 0.002;
+        for j in 0..25 {
+            let (a0, a1) = (grid[j % 5], grid[j/5]);
+            sum += 1.0/((j + 1) as f64 + (x[0] - a0).powi(6) + (x[1] - a1).powi(6));
+        }
+        1.0/sum
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![-31.978_334_835_656_97, -31.978_334_837_300_795]
+    }
+}
+
+#[cfg(test)]
+mod shekel_foxholes_tests {
+    use super::{ShekelFoxholes as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+}
+
+/// This is the Langermann function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/langer.html). It is a sum of
+/// m oscillating terms, centred on the rows of a matrix A and weighted by a vector c. The default
+/// instance uses the standard two-dimensional coefficients, for which the global minimum is known
+/// numerically. Other coefficients can be supplied in any number of dimensions:
+///
+/// ```
+/// use benchfun::{Langermann, Parameterized};
+///
+/// let f = Langermann::new(vec![1.0, 2.0], vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+/// f.evaluate(vec![0.0; 3]);
+/// assert_eq!(f.global_minimum(), None);
+/// ```
+///
+/// The static `SingleObjective` implementation uses the standard coefficients, so it is specifically
+/// 2 dimensional.
+pub struct Langermann {
+    /// The weight of each term
+    pub c: Vec<f64>,
+    /// The centre of each term
+    pub a: Vec<Vec<f64>>,
+}
+
+impl Langermann {
+    /// This function creates an instance from a vector of weights and a matrix of centres, which must
+    /// have one row for every weight, and one column for every dimension
+    pub fn new(c: Vec<f64>, a: Vec<Vec<f64>>) -> Langermann {
+        if c.len() != a.len() {
+            panic!("{} weights were used with {} centres.", c.len(), a.len());
+        }
+        if a.iter().any(|row| row.len() != a[0].len()) {
+            panic!("The centres do not all have the same dimensionality.");
+        }
+        Langermann { c, a }
+    }
+
+    /// This function returns true if this instance uses the standard coefficients
+    fn is_standard(&self) -> bool {
+        let standard = Langermann::default();
+        self.c == standard.c && self.a == standard.a
+    }
+}
+
+impl Default for Langermann {
+    fn default() -> Langermann {
+        Langermann::new(
+            vec![1.0, 2.0, 5.0, 2.0, 3.0],
+            vec![vec![3.0, 5.0], vec![5.0, 2.0], vec![2.0, 1.0], vec![1.0, 4.0], vec![7.0, 9.0]],
+        )
+    }
+}
+
+impl UnConstrained for Langermann {}
+
+impl FixedDimensional for Langermann {
+    const D: usize = 2;
+}
+
+impl Bounded for Langermann {
+    /// The bounds of the canonical Langermann optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 10.0);
+}
+
+impl Parameterized for Langermann {
+    /// Function for evaluating with the coefficients of this instance
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        if x.len() != self.a[0].len() {
+            panic!("A vector with size {} was used with an instance of dimensionality {}.", x.len(), self.a[0].len());
+        }
+        let mut fx = 0.0;
+        for (ci, row) in self.c.iter().zip(self.a.iter()) {
+            let mut distance = 0.0;
+            for (xj, aj) in x.iter().zip(row.iter()) {
+                distance += (xj - aj).powi(2);
+            }
+            fx += ci*(-distance/std::f64::consts::PI).exp()*(std::f64::consts::PI*distance).cos();
+        }
+        fx
+    }
+
+    /// The global minimum is only known for the standard coefficients
+    fn global_minimum(&self) -> Option<f64> {
+        if self.is_standard() {
+            Some(Self::MINIMUM)
+        } else {
+            None
+        }
+    }
+
+    /// The minimizer is only known for the standard coefficients
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        if self.is_standard() && n == Self::D {
+            Some(Self::minimizer(n))
+        } else {
+            None
+        }
+    }
+}
+
+impl SingleObjective for Langermann {
+    /// The global minimum for the standard coefficients is constant and approximately -4.1558
+    const MINIMUM: f64 = -4.155_809_291_847_785;
+
+    /// Function for evaluating with the standard coefficients
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        Langermann::default().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![2.793_402_208_645_037, 1.597_232_501_328_36]
+    }
+}
+
+#[cfg(test)]
+mod langermann_tests {
+    use super::{Langermann as F, FixedDimensional, SingleObjective, Parameterized};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+
+    #[test]
+    fn custom() {
+        let f = F::new(vec![1.0], vec![vec![1.0; 5]]);
+        assert!((f.evaluate(vec![1.0; 5]) - 1.0).abs() < f64::EPSILON);
+        assert_eq!(f.global_minimizer(5), None);
+        assert_eq!(F::default().global_minimizer(3), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrong_dimension() {
+        F::f(vec![0.0; 3]);
+    }
+}
+
+/// This is the Bohachevsky function N.1.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/boha.html).
+/// This function is specifically 2 dimensional. The cosine terms are separable.
+pub struct BohachevskyN1 {}
+
+impl UnConstrained for BohachevskyN1 {}
+
+impl Bounded for BohachevskyN1 {
+    /// The bounds of the canonical Bohachevsky N.1 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl FixedDimensional for BohachevskyN1 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for BohachevskyN1 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = std::f64::consts::PI;
+        x[0].powi(2) + 2.0*x[1].powi(2) - 0.3*(3.0*pi*x[0]).cos() - 0.4*(4.0*pi*x[1]).cos() + 0.7
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod bohachevsky_n1_tests {
+    use super::{BohachevskyN1 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Bohachevsky function N.2.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/boha.html).
+/// This function is specifically 2 dimensional. The cosine terms are multiplied, so they are not separable.
+pub struct BohachevskyN2 {}
+
+impl UnConstrained for BohachevskyN2 {}
+
+impl Bounded for BohachevskyN2 {
+    /// The bounds of the canonical Bohachevsky N.2 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl FixedDimensional for BohachevskyN2 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for BohachevskyN2 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = std::f64::consts::PI;
+        x[0].powi(2) + 2.0*x[1].powi(2) - 0.3*(3.0*pi*x[0]).cos()*(4.0*pi*x[1]).cos() + 0.3
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod bohachevsky_n2_tests {
+    use super::{BohachevskyN2 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Bohachevsky function N.3.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/boha.html).
+/// This function is specifically 2 dimensional. The cosine term depends on a sum of the variables, so it is not separable.
+pub struct BohachevskyN3 {}
+
+impl UnConstrained for BohachevskyN3 {}
+
+impl Bounded for BohachevskyN3 {
+    /// The bounds of the canonical Bohachevsky N.3 optimization problem
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl FixedDimensional for BohachevskyN3 {
+    const D: usize = 2;
+}
+
+impl SingleObjective for BohachevskyN3 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = std::f64::consts::PI;
+        x[0].powi(2) + 2.0*x[1].powi(2) - 0.3*(3.0*pi*x[0] + 4.0*pi*x[1]).cos() + 0.3
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod bohachevsky_n3_tests {
+    use super::{BohachevskyN3 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the Bird function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008).
+/// This function is specifically 2 dimensional, and has two global minimizers, at approximately
+/// (4.7010, 3.1529) and (-1.5821, -3.1302). The global minimum is only known numerically.
+pub struct Bird {}
+
+impl UnConstrained for Bird {}
+
+impl Bounded for Bird {
+    /// The bounds of the canonical Bird optimization problem
+    const BOUNDS: (f64, f64) = (-2.0*std::f64::consts::PI, 2.0*std::f64::consts::PI);
+}
+
+impl FixedDimensional for Bird {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Bird {
+    /// The global minimum is constant and approximately -106.7645
+    const MINIMUM: f64 = -106.764_536_749_264_67;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        x[0].sin()*(1.0 - x[1].cos()).powi(2).exp() + x[1].cos()*(1.0 - x[0].sin()).powi(2).exp() + (x[0] - x[1]).powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![4.701_043_130_249_553, 3.152_938_503_724_93]
+    }
+}
+
+impl MultipleMinimizers for Bird {
+    /// There are two global minimizers
+    fn minimizer_count(_n: usize) -> usize {
+        2
+    }
+
+    /// This function returns both global minimizers
+    fn all_minimizers(_n: usize) -> Vec<Vec<f64>> {
+        vec![
+            vec![4.701_043_130_249_553, 3.152_938_503_724_93],
+            vec![-1.582_142_176_930_033_5, -3.130_246_803_454_656_4],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod bird_tests {
+    use super::{Bird as F, FixedDimensional, SingleObjective, MultipleMinimizers};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-12)
+    }
+
+    #[test]
+    fn all_minimizers() {
+        F::check_all_minimizers_within(F::D, 1e-12)
+    }
+}
+
+/// This is the Adjiman function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008).
+/// This function is specifically 2 dimensional, and has different bounds for each dimension. The
+/// global minimizer lies on the boundary of the domain, and the global minimum is only known
+/// numerically.
+pub struct Adjiman {}
+
+impl UnConstrained for Adjiman {}
+
+impl Bounded for Adjiman {
+    /// The smallest interval that contains the bounds of both dimensions
+    const BOUNDS: (f64, f64) = (-1.0, 2.0);
+
+    /// The bounds of the canonical Adjiman optimization problem
+    fn bounds(_n: usize) -> Vec<(f64, f64)> {
+        vec![(-1.0, 2.0), (-1.0, 1.0)]
+    }
+}
+
+impl FixedDimensional for Adjiman {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Adjiman {
+    /// The global minimum is constant and approximately -2.02181
+    const MINIMUM: f64 = -2.021_806_783_359_787;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        x[0].cos()*x[1].sin() - x[0]/(x[1].powi(2) + 1.0)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![2.0, 0.105_783_469_451_716_9]
+    }
+}
+
+#[cfg(test)]
+mod adjiman_tests {
+    use super::{Adjiman as F, FixedDimensional, SingleObjective, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-15)
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(F::in_bounds(F::minimizer(F::D)));
+        assert!(!F::in_bounds(vec![0.0, 1.5]));
+    }
+}
+
+/// This is the Bartels Conn function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008).
+/// This function is specifically 2 dimensional, and is built from absolute values, so it is not
+/// differentiable everywhere.
+pub struct BartelsConn {}
+
+impl UnConstrained for BartelsConn {}
+impl NonSmooth for BartelsConn {}
+
+impl Bounded for BartelsConn {
+    /// The bounds of the canonical Bartels Conn optimization problem
+    const BOUNDS: (f64, f64) = (-500.0, 500.0);
+}
+
+impl FixedDimensional for BartelsConn {
+    const D: usize = 2;
+}
+
+impl SingleObjective for BartelsConn {
+    /// The global minimum is constant and one
+    const MINIMUM: f64 = 1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (x[0].powi(2) + x[1].powi(2) + x[0]*x[1]).abs() + x[0].sin().abs() + x[1].cos().abs()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+}
+
+#[cfg(test)]
+mod bartels_conn_tests {
+    use super::{BartelsConn as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+}
+
+/// This is the three-dimensional Hartmann function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/hart3.html).
+/// This function is specifically 3 dimensional, and is a sum of four Gaussian wells defined on the
+/// unit cube. The global minimum is only known numerically.
+pub struct Hartmann3 {}
+
+impl Hartmann3 {
+    /// The depth of each well
+    const ALPHA: [f64; 4] = [1.0, 1.2, 3.0, 3.2];
+
+    /// The width of each well along each dimension
+    const A: [[f64; 3]; 4] = [
+        [3.0, 10.0, 30.0],
+        [0.1, 10.0, 35.0],
+        [3.0, 10.0, 30.0],
+        [0.1, 10.0, 35.0],
+    ];
+
+    /// The centre of each well
+    const P: [[f64; 3]; 4] = [
+        [0.3689, 0.1170, 0.2673],
+        [0.4699, 0.4387, 0.7470],
+        [0.1091, 0.8732, 0.5547],
+        [0.0381, 0.5743, 0.8828],
+    ];
+}
+
+impl UnConstrained for Hartmann3 {}
+
+impl Bounded for Hartmann3 {
+    /// The bounds of the canonical Hartmann 3-D optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl FixedDimensional for Hartmann3 {
+    const D: usize = 3;
+}
+
+impl SingleObjective for Hartmann3 {
+    /// The global minimum is constant and approximately -3.86278
+    const MINIMUM: f64 = -3.862_779_787_332_662_5;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 0.0;
+        for ((alpha, a), p) in Self::ALPHA.iter().zip(Self::A.iter()).zip(Self::P.iter()) {
+            let mut exponent = 0.0;
+            for ((xj, aj), pj) in x.iter().zip(a.iter()).zip(p.iter()) {
+                exponent += aj*(xj - pj).powi(2);
+            }
+            fx -= alpha*(-exponent).exp();
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.114_588_876_655_068_97, 0.555_648_894_616_930_1, 0.852_546_984_686_677_4]
+    }
+}
+
+#[cfg(test)]
+mod hartmann3_tests {
+    use super::{Hartmann3 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+}
+
+/// This is the six-dimensional Hartmann function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/hart6.html).
+/// This function is specifically 6 dimensional, and is a sum of four Gaussian wells defined on the
+/// unit hypercube. The global minimum is only known numerically. Some papers use the
+/// log-transformed variant -ln(-f) instead:
+///
+/// ```
+/// use benchfun::{Hartmann6, Parameterized};
+///
+/// let f = Hartmann6::new(true);
+/// assert!(f.global_minimum().unwrap() < 0.0);
+/// ```
+///
+/// The static `SingleObjective` implementation uses the original function.
+pub struct Hartmann6 {
+    /// This indicates whether the log-transformed variant is returned
+    pub log_transformed: bool,
+}
+
+impl Hartmann6 {
+    /// The depth of each well
+    const ALPHA: [f64; 4] = [1.0, 1.2, 3.0, 3.2];
+
+    /// The width of each well along each dimension
+    const A: [[f64; 6]; 4] = [
+        [10.0, 3.0, 17.0, 3.5, 1.7, 8.0],
+        [0.05, 10.0, 17.0, 0.1, 8.0, 14.0],
+        [3.0, 3.5, 1.7, 10.0, 17.0, 8.0],
+        [17.0, 8.0, 0.05, 10.0, 0.1, 14.0],
+    ];
+
+    /// The centre of each well
+    const P: [[f64; 6]; 4] = [
+        [0.1312, 0.1696, 0.5569, 0.0124, 0.8283, 0.5886],
+        [0.2329, 0.4135, 0.8307, 0.3736, 0.1004, 0.9991],
+        [0.2348, 0.1451, 0.3522, 0.2883, 0.3047, 0.6650],
+        [0.4047, 0.8828, 0.8732, 0.5743, 0.1091, 0.0381],
+    ];
+
+    /// This function creates an instance of the original or the log-transformed function
+    pub fn new(log_transformed: bool) -> Hartmann6 {
+        Hartmann6 { log_transformed }
+    }
+}
+
+impl Default for Hartmann6 {
+    fn default() -> Hartmann6 {
+        Hartmann6::new(false)
+    }
+}
+
+impl UnConstrained for Hartmann6 {}
+
+impl Bounded for Hartmann6 {
+    /// The bounds of the canonical Hartmann 6-D optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl FixedDimensional for Hartmann6 {
+    const D: usize = 6;
+}
+
+impl Parameterized for Hartmann6 {
+    /// Function for evaluating the original or the log-transformed function
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 0.0;
+        for ((alpha, a), p) in Self::ALPHA.iter().zip(Self::A.iter()).zip(Self::P.iter()) {
+            let mut exponent = 0.0;
+            for ((xj, aj), pj) in x.iter().zip(a.iter()).zip(p.iter()) {
+                exponent += aj*(xj - pj).powi(2);
+            }
+            fx -= alpha*(-exponent).exp();
+        }
+        if self.log_transformed {
+            -(-fx).ln()
+        } else {
+            fx
+        }
+    }
+
+    /// The global minimum is approximately -3.32237 for the original function
+    fn global_minimum(&self) -> Option<f64> {
+        if self.log_transformed {
+            Some(-(-Self::MINIMUM).ln())
+        } else {
+            Some(Self::MINIMUM)
+        }
+    }
+
+    /// The minimizer is the same for both variants
+    fn global_minimizer(&self, n: usize) -> Option<Vec<f64>> {
+        Some(Self::minimizer(n))
+    }
+}
+
+impl SingleObjective for Hartmann6 {
+    /// The global minimum is constant and approximately -3.32237
+    const MINIMUM: f64 = -3.322_368_011_415_515;
+
+    /// Function for evaluating the original function
+    fn f(x: Vec<f64>) -> f64 {
+        Hartmann6::default().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![
+            0.201_689_511_006_705_43,
+            0.150_010_691_823_457_97,
+            0.476_873_974_221_897,
+            0.275_332_430_494_056_1,
+            0.311_651_616_600_113_24,
+            0.657_300_534_065_620_3,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod hartmann6_tests {
+    use super::{Hartmann6 as F, FixedDimensional, SingleObjective, Parameterized};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+
+    #[test]
+    fn log_transformed() {
+        let f = F::new(true);
+        let minimizer = f.global_minimizer(F::D).unwrap();
+        assert!((f.evaluate(minimizer) - f.global_minimum().unwrap()).abs() < 1e-14);
+    }
+}
+
+/// This is the Shekel function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/shekel.html).
+/// This function is specifically 4 dimensional, and has m local minima, where m is 5, 7 or 10.
+/// The global minimum of each variant is only known numerically:
+///
+/// ```
+/// use benchfun::{Shekel, Parameterized};
+///
+/// let minima: Vec<f64> = [5, 7, 10].iter().map(|m| Shekel::new(*m).global_minimum().unwrap()).collect();
+/// assert!(minima[0] > minima[1] && minima[1] > minima[2]);
+/// ```
+///
+/// The static `SingleObjective` implementation uses m = 10.
+pub struct Shekel {
+    /// The number of local minima, which is 5, 7 or 10
+    pub m: usize,
+}
+
+impl Shekel {
+    /// The offset of each local minimum
+    const BETA: [f64; 10] = [0.1, 0.2, 0.2, 0.4, 0.4, 0.6, 0.3, 0.7, 0.5, 0.5];
+
+    /// The location of each local minimum
+    const C: [[f64; 4]; 10] = [
+        [4.0, 4.0, 4.0, 4.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [8.0, 8.0, 8.0, 8.0],
+        [6.0, 6.0, 6.0, 6.0],
+        [3.0, 7.0, 3.0, 7.0],
+        [2.0, 9.0, 2.0, 9.0],
+        [5.0, 3.0, 5.0, 3.0],
+        [8.0, 1.0, 8.0, 1.0],
+        [6.0, 2.0, 6.0, 2.0],
+        [7.0, 3.6, 7.0, 3.6],
+    ];
+
+    /// This function creates an instance with m local minima, where m is 5, 7 or 10
+    pub fn new(m: usize) -> Shekel {
+        if m != 5 && m != 7 && m != 10 {
+            panic!("The Shekel function is defined for m = 5, 7 or 10, but {} was used.", m);
+        }
+        Shekel { m }
+    }
+}
+
+impl Default for Shekel {
+    fn default() -> Shekel {
+        Shekel::new(10)
+    }
+}
+
+impl UnConstrained for Shekel {}
+
+impl Bounded for Shekel {
+    /// The bounds of the canonical Shekel optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 10.0);
+}
+
+impl FixedDimensional for Shekel {
+    const D: usize = 4;
+}
+
+impl Parameterized for Shekel {
+    /// Function for evaluating with the first m local minima
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 0.0;
+        for (beta, c) in Self::BETA.iter().zip(Self::C.iter()).take(self.m) {
+            let mut distance = 0.0;
+            for (xj, cj) in x.iter().zip(c.iter()) {
+                distance += (xj - cj).powi(2);
+            }
+            fx -= 1.0/(distance + beta);
+        }
+        fx
+    }
+
+    /// The global minimum depends on m
+    fn global_minimum(&self) -> Option<f64> {
+        match self.m {
+            5 => Some(-10.153_199_679_058_227),
+            7 => Some(-10.402_915_336_777_743),
+            _ => Some(-10.536_443_153_483_528),
+        }
+    }
+
+    /// The minimizer depends slightly on m
+    fn global_minimizer(&self, _n: usize) -> Option<Vec<f64>> {
+        let (a, b) = match self.m {
+            5 => (4.000_037_152_819_676, 4.000_133_276_591_56),
+            7 => (4.000_572_819_251_117, 3.999_606_209_609_689),
+            _ => (4.000_746_868_270_634, 3.999_509_480_085_773_6),
+        };
+        Some(vec![a, b, a, b])
+    }
+}
+
+impl SingleObjective for Shekel {
+    /// The global minimum for m = 10 is constant and approximately -10.5364
+    const MINIMUM: f64 = -10.536_443_153_483_528;
+
+    /// Function for evaluating with m = 10
+    fn f(x: Vec<f64>) -> f64 {
+        Shekel::default().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        Shekel::default().global_minimizer(n).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod shekel_tests {
+    use super::{Shekel as F, FixedDimensional, SingleObjective, Parameterized};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+
+    #[test]
+    fn variants() {
+        for m in [5, 7, 10].iter() {
+            let f = F::new(*m);
+            let minimizer = f.global_minimizer(F::D).unwrap();
+            assert!((f.evaluate(minimizer) - f.global_minimum().unwrap()).abs() < 1e-14);
+        }
+    }
+}
+
+/// This is the Colville function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/colville.html).
+/// This function is specifically 4 dimensional, and couples two Rosenbrock-like valleys through a
+/// cross term. Its analytic gradient is available through `Differentiable`.
+pub struct Colville {}
+
+impl UnConstrained for Colville {}
+impl Smooth for Colville {}
+
+impl Bounded for Colville {
+    /// The bounds of the canonical Colville optimization problem
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl FixedDimensional for Colville {
+    const D: usize = 4;
+}
+
+impl SingleObjective for Colville {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        100.0*(x[0].powi(2) - x[1]).powi(2) + (x[0] - 1.0).powi(2)
+            + (x[2] - 1.0).powi(2) + 90.0*(x[2].powi(2) - x[3]).powi(2)
+            + 10.1*((x[1] - 1.0).powi(2) + (x[3] - 1.0).powi(2))
+            + 19.8*(x[1] - 1.0)*(x[3] - 1.0)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![1.0; 4]
+    }
+}
+
+impl Differentiable for Colville {
+    /// Function for evaluating the gradient
+    fn gradient(x: Vec<f64>) -> Vec<f64> {
+        Self::check_input(x.clone());
+        vec![
+            -400.0*x[0]*(x[1] - x[0].powi(2)) - 2.0*(1.0 - x[0]),
+            200.0*(x[1] - x[0].powi(2)) + 20.2*(x[1] - 1.0) + 19.8*(x[3] - 1.0),
+            -360.0*x[2]*(x[3] - x[2].powi(2)) - 2.0*(1.0 - x[2]),
+            180.0*(x[3] - x[2].powi(2)) + 20.2*(x[3] - 1.0) + 19.8*(x[1] - 1.0),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod colville_tests {
+    use super::{Colville as F, FixedDimensional, SingleObjective, Differentiable};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+
+    #[test]
+    fn gradient() {
+        assert_eq!(F::gradient(F::minimizer(F::D)), vec![0.0; F::D]);
+        F::check_gradient(vec![-3.0, -1.0, -3.0, -1.0], 1e-6);
+        F::check_gradient(vec![0.5, -0.2, 1.3, 2.0], 1e-6);
+    }
+}
+
+/// This is the Wood function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008), and is the Colville
+/// function under its more common name in the quasi-Newton literature, so it is evaluated by
+/// `Colville`. This function is specifically 4 dimensional. Its analytic gradient is available
+/// through `Differentiable`.
+pub struct Wood {}
+
+impl UnConstrained for Wood {}
+impl Smooth for Wood {}
+
+impl Bounded for Wood {
+    /// The bounds are those of the Colville function
+    const BOUNDS: (f64, f64) = Colville::BOUNDS;
+}
+
+impl FixedDimensional for Wood {
+    const D: usize = Colville::D;
+}
+
+impl SingleObjective for Wood {
+    /// The global minimum is that of the Colville function
+    const MINIMUM: f64 = Colville::MINIMUM;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Colville::f(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        Colville::minimizer(n)
+    }
+}
+
+impl Differentiable for Wood {
+    /// Function for evaluating the gradient
+    fn gradient(x: Vec<f64>) -> Vec<f64> {
+        Colville::gradient(x)
+    }
+}
+
+#[cfg(test)]
+mod wood_tests {
+    use super::{Wood as F, Colville, FixedDimensional, SingleObjective, Differentiable};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+
+    #[test]
+    fn colville() {
+        let x = vec![0.5, -0.2, 1.3, 2.0];
+        assert_eq!(F::f(x.clone()), Colville::f(x.clone()));
+        assert_eq!(F::gradient(x.clone()), Colville::gradient(x));
+    }
+}
+
+/// This is the Forrester function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/forretal08.html).
+/// This function is specifically 1 dimensional, and is widely used to demonstrate surrogate models.
+/// Multi-fidelity studies pair it with a cheap low-fidelity approximation, whose global minimizer
+/// is in a different place:
+///
+/// ```
+/// use benchfun::{Forrester, Parameterized};
+///
+/// let high = Forrester::new();
+/// let low = Forrester::low_fidelity();
+/// assert!(low.global_minimizer(1).unwrap()[0] < high.global_minimizer(1).unwrap()[0]);
+/// ```
+///
+/// The static `SingleObjective` implementation uses the high-fidelity function.
+pub struct Forrester {
+    /// This indicates whether the low-fidelity approximation is returned
+    pub low_fidelity: bool,
+}
+
+impl Forrester {
+    /// This function creates an instance of the high-fidelity function
+    pub fn new() -> Forrester {
+        Forrester { low_fidelity: false }
+    }
+
+    /// This function creates an instance of the low-fidelity approximation
+    pub fn low_fidelity() -> Forrester {
+        Forrester { low_fidelity: true }
+    }
+}
+
+impl Default for Forrester {
+    fn default() -> Forrester {
+        Forrester::new()
+    }
+}
+
+impl UnConstrained for Forrester {}
+
+impl Bounded for Forrester {
+    /// The bounds of the canonical Forrester optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl FixedDimensional for Forrester {
+    const D: usize = 1;
+}
+
+impl Parameterized for Forrester {
+    /// Function for evaluating the high-fidelity function or the low-fidelity approximation
+    fn evaluate(&self, x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let high = (6.0*x[0] - 2.0).powi(2)*(12.0*x[0] - 4.0).sin();
+        if self.low_fidelity {
+            0.5*high + 10.0*(x[0] - 0.5) + 5.0
+        } else {
+            high
+        }
+    }
+
+    /// The global minimum is only known numerically
+    fn global_minimum(&self) -> Option<f64> {
+        if self.low_fidelity {
+            Some(0.665_095_123_046_361_8)
+        } else {
+            Some(-6.020_740_055_767_083)
+        }
+    }
+
+    /// The global minimizer is only known numerically
+    fn global_minimizer(&self, _n: usize) -> Option<Vec<f64>> {
+        if self.low_fidelity {
+            Some(vec![0.092_392_875_845_544_87])
+        } else {
+            Some(vec![0.757_248_757_841_855_9])
+        }
+    }
+}
+
+impl SingleObjective for Forrester {
+    /// The global minimum is constant and approximately -6.02074
+    const MINIMUM: f64 = -6.020_740_055_767_083;
+
+    /// Function for evaluating the high-fidelity function
+    fn f(x: Vec<f64>) -> f64 {
+        Forrester::new().evaluate(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.757_248_757_841_855_9]
+    }
+}
+
+#[cfg(test)]
+mod forrester_tests {
+    use super::{Forrester as F, FixedDimensional, SingleObjective, Parameterized, Bounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+
+    #[test]
+    fn low_fidelity() {
+        let f = F::low_fidelity();
+        let minimizer = f.global_minimizer(F::D).unwrap();
+        assert!(F::in_bounds(minimizer.clone()));
+        assert!((f.evaluate(minimizer) - f.global_minimum().unwrap()).abs() < 1e-14);
+    }
+}
+
+/// This is the Gramacy & Lee function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/grlee12.html).
+/// This function is specifically 1 dimensional. It oscillates quickly on the left of its domain
+/// and is smooth on the right, which makes it a standard test for Gaussian process surrogates. The
+/// global minimum is only known numerically.
+pub struct GramacyLee {}
+
+impl UnConstrained for GramacyLee {}
+
+impl Bounded for GramacyLee {
+    /// The bounds of the canonical Gramacy & Lee optimization problem
+    const BOUNDS: (f64, f64) = (0.5, 2.5);
+}
+
+impl FixedDimensional for GramacyLee {
+    const D: usize = 1;
+}
+
+impl SingleObjective for GramacyLee {
+    /// The global minimum is constant and approximately -0.869011
+    const MINIMUM: f64 = -0.869_011_134_989_499_8;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (10.0*std::f64::consts::PI*x[0]).sin()/(2.0*x[0]) + (x[0] - 1.0).powi(4)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.548_563_444_527_605_2]
+    }
+}
+
+#[cfg(test)]
+mod gramacy_lee_tests {
+    use super::{GramacyLee as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-14)
+    }
+}
+
+/// This is the Damavandi function.
+///
+/// The function is borrowed from [here](https://arxiv.org/abs/1308.4008).
+/// This function is specifically 2 dimensional, and is highly deceptive: a wide basin leads to a
+/// local minimum with value 2 at (7, 7), while the global minimum sits in a tiny basin at (2, 2).
+/// The formula divides by zero along the lines x = 2 and y = 2, so the ratio of sines is replaced
+/// by its limit there.
+pub struct Damavandi {}
+
+impl Damavandi {
+    /// This function returns sin(πt)/(πt), with its limit value of one at zero
+    fn sinc(t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else {
+            (std::f64::consts::PI*t).sin()/(std::f64::consts::PI*t)
+        }
+    }
+}
+
+impl UnConstrained for Damavandi {}
+
+impl Bounded for Damavandi {
+    /// The bounds of the canonical Damavandi optimization problem
+    const BOUNDS: (f64, f64) = (0.0, 14.0);
+}
+
+impl FixedDimensional for Damavandi {
+    const D: usize = 2;
+}
+
+impl SingleObjective for Damavandi {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let ratio = (Self::sinc(x[0] - 2.0)*Self::sinc(x[1] - 2.0)).abs();
+        (1.0 - ratio.powi(5))*(2.0 + (x[0] - 7.0).powi(2) + 2.0*(x[1] - 7.0).powi(2))
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![2.0, 2.0]
+    }
+}
+
+#[cfg(test)]
+mod damavandi_tests {
+    use super::{Damavandi as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_strict(F::D)
+    }
+
+    #[test]
+    fn singularity() {
+        assert!(F::f(vec![2.0, 3.5]).is_finite());
+        assert!((F::f(vec![2.0 + 1e-9, 2.0]) - F::f(vec![2.0, 2.0])).abs() < 1e-12);
+        assert!((F::f(vec![7.0, 7.0]) - 2.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,235 @@
+//! This module contains a thread-safe collector of statistics from concurrent runs
+//!
+//! A `StatsHub` is shared between the threads of an experiment, such as 31 independent runs of an
+//! optimizer. Each run reports into its own `RunHandle`, and the hub can be queried at any time for
+//! a live summary, for example to drive a progress display.
+//!
+//! ```
+//! use benchfun::Sphere;
+//! use benchfun::stats::StatsHub;
+//!
+//! let hub = StatsHub::new();
+//! let handles: Vec<_> = (0..4).map(|run| {
+//!     let handle = hub.run(format!("run {}", run));
+//!     std::thread::spawn(move || {
+//!         for i in 0..100 {
+//!             handle.evaluate::<Sphere>(vec![i as f64/100.0, 1.0]);
+//!         }
+//!         handle.finish();
+//!     })
+//! }).collect();
+//! handles.into_iter().for_each(|h| h.join().unwrap());
+//! assert_eq!(hub.summary().evaluations, 400);
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::SingleObjective;
+#[cfg(feature = "json")]
+use crate::json::{Json, ToJson};
+
+/// This struct holds the statistics of one run
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunStats {
+    /// The number of evaluations
+    pub evaluations: usize,
+    /// The best objective function value
+    pub best: f64,
+    /// The total time spent in evaluations
+    pub evaluation_time: Duration,
+    /// Whether the run has finished
+    pub finished: bool,
+}
+
+impl Default for RunStats {
+    fn default() -> Self {
+        RunStats { evaluations: 0, best: f64::INFINITY, evaluation_time: Duration::ZERO, finished: false }
+    }
+}
+
+/// This struct holds statistics aggregated over all runs
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    /// The number of runs
+    pub runs: usize,
+    /// The number of finished runs
+    pub finished: usize,
+    /// The total number of evaluations
+    pub evaluations: usize,
+    /// The best objective function value of any run
+    pub best: f64,
+    /// The median of the best values of the runs
+    pub median_best: f64,
+    /// The mean of the best values of the runs
+    pub mean_best: f64,
+    /// The total time spent in evaluations
+    pub evaluation_time: Duration,
+}
+
+/// This is a thread-safe collector of statistics. Clones share the same statistics.
+#[derive(Clone, Debug, Default)]
+pub struct StatsHub {
+    runs: Arc<Mutex<BTreeMap<String, RunStats>>>,
+}
+
+/// This is a handle through which a single run reports its statistics
+#[derive(Clone, Debug)]
+pub struct RunHandle {
+    hub: StatsHub,
+    label: String,
+}
+
+impl StatsHub {
+    /// This function creates an empty hub
+    pub fn new() -> StatsHub {
+        StatsHub::default()
+    }
+
+    /// This function registers a run, or returns the handle of an existing run with the same label
+    pub fn run<S: Into<String>>(&self, label: S) -> RunHandle {
+        let label = label.into();
+        self.runs.lock().unwrap().entry(label.clone()).or_default();
+        RunHandle { hub: self.clone(), label }
+    }
+
+    /// This function returns the statistics of each run, ordered by label
+    pub fn snapshot(&self) -> Vec<(String, RunStats)> {
+        self.runs.lock().unwrap().iter().map(|(label, stats)| (label.clone(), stats.clone())).collect()
+    }
+
+    /// This function returns statistics aggregated over all runs
+    pub fn summary(&self) -> Summary {
+        let runs = self.snapshot();
+        let mut bests: Vec<f64> = runs.iter().map(|(_, s)| s.best).collect();
+        bests.sort_by(|a, b| a.total_cmp(b));
+        let median_best = match bests.len() {
+            0 => f64::NAN,
+            n if n % 2 == 1 => bests[n/2],
+            n => 0.5*(bests[n/2 - 1] + bests[n/2]),
+        };
+        Summary {
+            runs: runs.len(),
+            finished: runs.iter().filter(|(_, s)| s.finished).count(),
+            evaluations: runs.iter().map(|(_, s)| s.evaluations).sum(),
+            best: bests.first().copied().unwrap_or(f64::INFINITY),
+            median_best,
+            mean_best: if bests.is_empty() { f64::NAN } else { bests.iter().sum::<f64>()/bests.len() as f64 },
+            evaluation_time: runs.iter().map(|(_, s)| s.evaluation_time).sum(),
+        }
+    }
+
+    fn update<G: FnOnce(&mut RunStats)>(&self, label: &str, g: G) {
+        g(self.runs.lock().unwrap().entry(label.to_string()).or_default())
+    }
+}
+
+impl RunHandle {
+    /// This function records an evaluation that was made elsewhere, along with the time it took
+    pub fn record(&self, f: f64, elapsed: Duration) {
+        self.hub.update(&self.label, |stats| {
+            stats.evaluations += 1;
+            stats.best = stats.best.min(f);
+            stats.evaluation_time += elapsed;
+        })
+    }
+
+    /// Function for evaluating, which times the evaluation and records it
+    pub fn evaluate<F: SingleObjective>(&self, x: Vec<f64>) -> f64 {
+        let start = Instant::now();
+        let fx = F::f(x);
+        self.record(fx, start.elapsed());
+        fx
+    }
+
+    /// This function marks the run as finished
+    pub fn finish(&self) {
+        self.hub.update(&self.label, |stats| stats.finished = true)
+    }
+
+    /// This function returns the current statistics of this run
+    pub fn stats(&self) -> RunStats {
+        self.hub.runs.lock().unwrap().get(&self.label).cloned().unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for RunStats {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("evaluations".to_string(), self.evaluations.to_json()),
+            ("best".to_string(), self.best.to_json()),
+            ("evaluation_time".to_string(), self.evaluation_time.as_secs_f64().to_json()),
+            ("finished".to_string(), self.finished.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Summary {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("runs".to_string(), self.runs.to_json()),
+            ("finished".to_string(), self.finished.to_json()),
+            ("evaluations".to_string(), self.evaluations.to_json()),
+            ("best".to_string(), self.best.to_json()),
+            ("median_best".to_string(), self.median_best.to_json()),
+            ("mean_best".to_string(), self.mean_best.to_json()),
+            ("evaluation_time".to_string(), self.evaluation_time.as_secs_f64().to_json()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::{StatsHub, RunStats};
+    use crate::Booth;
+    #[cfg(feature = "json")]
+    use crate::json::ToJson;
+    use std::time::Duration;
+
+    #[test]
+    fn concurrent() {
+        let hub = StatsHub::new();
+        std::thread::scope(|scope| {
+            for run in 0..31 {
+                let handle = hub.run(format!("{:02}", run));
+                scope.spawn(move || {
+                    for i in 0..100 {
+                        handle.evaluate::<Booth>(vec![1.0 + (run + i) as f64/100.0, 3.0]);
+                    }
+                    handle.finish();
+                });
+            }
+        });
+        let summary = hub.summary();
+        assert_eq!((summary.runs, summary.finished, summary.evaluations), (31, 31, 3100));
+        assert_eq!(summary.best, 0.0);
+        let snapshot = hub.snapshot();
+        assert_eq!(snapshot[0].0, "00");
+        assert!(snapshot.iter().all(|(_, s)| s.evaluations == 100 && s.finished));
+    }
+
+    #[test]
+    fn summary() {
+        let hub = StatsHub::new();
+        assert!(hub.summary().median_best.is_nan());
+        for (label, best) in [("a", 3.0), ("b", 1.0), ("c", 2.0), ("d", 10.0)].iter() {
+            hub.run(*label).record(*best, Duration::from_millis(5));
+        }
+        hub.run("a").record(4.0, Duration::from_millis(5));
+        let summary = hub.summary();
+        assert_eq!((summary.best, summary.median_best, summary.mean_best), (1.0, 2.5, 4.0));
+        assert_eq!(summary.evaluation_time, Duration::from_millis(25));
+        assert_eq!(hub.run("a").stats(), RunStats { evaluations: 2, best: 3.0, evaluation_time: Duration::from_millis(10), finished: false });
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let hub = StatsHub::new();
+        hub.run("a").record(1.0, Duration::from_millis(5));
+        assert_eq!(hub.summary().to_json().get("runs").unwrap().to_string(), "1");
+    }
+}
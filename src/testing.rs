@@ -0,0 +1,192 @@
+//! This module contains property-based helpers for testing functions, in the style of proptest or
+//! quickcheck
+//!
+//! The helpers sample random points and check that no function value is below the global minimum,
+//! and that the global minimizer is feasible. Half of the points are drawn uniformly from the
+//! bounds, and half from a small neighbourhood of the minimizer, where a wrong minimum or minimizer
+//! is most likely to show. Points are drawn from a seeded generator, so failures are reproducible.
+//! The helpers work on any type that implements the traits of this crate, including functions
+//! defined outside it, and on the functions of the registry.
+//!
+//! ```
+//! use benchfun::Ackley;
+//! use benchfun::testing::{check_above_minimum, check_minimizer_feasible, PropertyConfig};
+//!
+//! check_above_minimum::<Ackley>(5, &PropertyConfig::default());
+//! check_minimizer_feasible::<Ackley>(5);
+//! ```
+
+use crate::{Bounded, Constrained, Rng, SingleObjective, Tolerance};
+use crate::feasibility::violations;
+use crate::registry::Function;
+
+/// The range sampled in dimensions without finite bounds
+const UNBOUNDED_RANGE: (f64, f64) = (-100.0, 100.0);
+
+/// This struct configures the property checks
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PropertyConfig {
+    /// The number of points to sample
+    pub cases: usize,
+    /// The seed of the random number generator
+    pub seed: u64,
+    /// The tolerance within which a value may be below the minimum
+    pub tolerance: Tolerance,
+}
+
+impl Default for PropertyConfig {
+    fn default() -> PropertyConfig {
+        PropertyConfig { cases: 256, seed: 0, tolerance: Tolerance::new(1e-12, 1e-12) }
+    }
+}
+
+/// This struct describes a point where a function is below its global minimum
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counterexample {
+    /// The point
+    pub x: Vec<f64>,
+    /// The value of the function at the point
+    pub f: f64,
+    /// The global minimum
+    pub minimum: f64,
+}
+
+/// This function samples points from bounds, half uniformly and half close to a minimizer.
+/// Infinite bounds are replaced by [-100, 100].
+pub fn sample(bounds: &[(f64, f64)], minimizer: &[f64], config: &PropertyConfig) -> Vec<Vec<f64>> {
+    let bounds: Vec<(f64, f64)> = bounds.iter()
+        .map(|(low, high)| (if low.is_finite() { *low } else { UNBOUNDED_RANGE.0 }, if high.is_finite() { *high } else { UNBOUNDED_RANGE.1 }))
+        .collect();
+    let mut rng = Rng::new(config.seed);
+    (0..config.cases)
+        .map(|case| {
+            bounds.iter().zip(minimizer.iter())
+                .map(|((low, high), xi)| {
+                    if case % 2 == 0 {
+                        low + (high - low)*rng.uniform()
+                    } else {
+                        // The neighbourhood spans a thousandth of the bounds on either side
+                        (xi + 1e-3*(high - low)*(2.0*rng.uniform() - 1.0)).clamp(*low, *high)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// This function returns the lowest of a set of points where a function is below a minimum by more
+/// than a tolerance, if there is one
+pub fn find_below_minimum(f: fn(Vec<f64>) -> f64, minimum: f64, points: &[Vec<f64>], tolerance: Tolerance) -> Option<Counterexample> {
+    points.iter()
+        .map(|x| Counterexample { x: x.clone(), f: f(x.clone()), minimum })
+        .filter(|c| c.f < c.minimum && !tolerance.accepts(c.f, c.minimum))
+        .min_by(|a, b| a.f.total_cmp(&b.f))
+}
+
+/// This function panics with a counterexample, if there is one
+fn assert_no_counterexample(counterexample: Option<Counterexample>, config: &PropertyConfig) {
+    if let Some(c) = counterexample {
+        panic!("The function is {} at {:?}, below its global minimum of {} (seed {}).", c.f, c.x, c.minimum, config.seed);
+    }
+}
+
+/// This function is used for testing, and checks that a bounded function is not below its global
+/// minimum at random points in its bounds
+pub fn check_above_minimum<F: SingleObjective + Bounded>(d: usize, config: &PropertyConfig) {
+    let points = sample(&F::bounds(d), &F::minimizer(d), config);
+    assert_no_counterexample(find_below_minimum(F::f, F::minimum(d), &points, config.tolerance), config)
+}
+
+/// This function is used for testing, and checks that the global minimizer of a bounded function
+/// is within its bounds
+pub fn check_minimizer_feasible<F: SingleObjective + Bounded>(d: usize) {
+    F::check_minimizer_in_bounds(d)
+}
+
+/// This function returns whether a point satisfies constraints to within a tolerance, where
+/// inequality constraints are satisfied when they are not positive
+pub fn satisfies(equality: &[f64], inequality: &[f64], tolerance: Tolerance) -> bool {
+    violations(equality, inequality, tolerance).is_empty()
+}
+
+/// This function is used for testing, and checks that the global minimizer of a constrained
+/// function satisfies its constraints to within a tolerance
+pub fn check_minimizer_satisfies_constraints<F: SingleObjective + Constrained>(d: usize, tolerance: Tolerance) {
+    let minimizer = F::minimizer(d);
+    let (h, g) = (F::h(minimizer.clone()), F::g(minimizer.clone()));
+    assert!(satisfies(&h, &g, tolerance), "The minimizer {:?} violates the constraints, with h = {:?} and g = {:?}.", minimizer, h, g);
+}
+
+/// This function is used for testing, and checks a function of the registry at a dimensionality:
+/// that its bounds and minimizer have that dimensionality, that its bounds are finite and ordered,
+/// or span the real line for unbounded functions, that its minimizer is within its bounds and
+/// satisfies its constraints, and that it is not below its global minimum at random feasible points
+pub fn check_function(function: &Function, d: usize, config: &PropertyConfig) {
+    let (bounds, minimizer) = ((function.bounds)(d), (function.minimizer)(d));
+    assert_eq!(bounds.len(), d, "{} has bounds for {} dimensions instead of {}.", function.name, bounds.len(), d);
+    assert_eq!(minimizer.len(), d, "The minimizer of {} has {} dimensions instead of {}.", function.name, minimizer.len(), d);
+    for (i, (low, high)) in bounds.iter().enumerate() {
+        let finite = low.is_finite() && high.is_finite() && low < high;
+        let infinite = *low == f64::NEG_INFINITY && *high == f64::INFINITY;
+        assert!(finite || infinite, "The bounds ({}, {}) of dimension {} of {} are not finite and ordered.", low, high, i, function.name);
+    }
+    let constraints = |x: &[f64]| {
+        let h = function.equality_constraints.map_or(vec![], |h| h(x.to_vec()));
+        let g = function.inequality_constraints.map_or(vec![], |g| g(x.to_vec()));
+        satisfies(&h, &g, config.tolerance)
+    };
+    assert!(minimizer.iter().zip(bounds.iter()).all(|(xi, (low, high))| low <= xi && xi <= high), "The minimizer of {} is outside its bounds.", function.name);
+    assert!(constraints(&minimizer), "The minimizer of {} violates its constraints.", function.name);
+    let points: Vec<Vec<f64>> = sample(&bounds, &minimizer, config).into_iter().filter(|x| constraints(x)).collect();
+    assert_no_counterexample(find_below_minimum(function.f, (function.minimum)(d), &points, config.tolerance), config)
+}
+
+#[cfg(test)]
+mod testing_tests {
+    use super::{sample, find_below_minimum, check_above_minimum, check_minimizer_feasible, check_minimizer_satisfies_constraints, check_function, PropertyConfig};
+    use crate::{Booth, RosenbrockConst1, SingleObjective, Tolerance};
+    use crate::registry::{functions, find, Function};
+
+    #[test]
+    fn sampling() {
+        let config = PropertyConfig { cases: 100, ..Default::default() };
+        let points = sample(&[(-10.0, 10.0), (f64::NEG_INFINITY, f64::INFINITY)], &[1.0, 3.0], &config);
+        assert_eq!(points.len(), 100);
+        assert!(points.iter().all(|x| x[0].abs() <= 10.0 && x[1].abs() <= 100.0));
+        assert!(points.iter().skip(1).step_by(2).all(|x| (x[0] - 1.0).abs() <= 0.02 && (x[1] - 3.0).abs() <= 0.2));
+        assert_eq!(points, sample(&[(-10.0, 10.0), (f64::NEG_INFINITY, f64::INFINITY)], &[1.0, 3.0], &config));
+    }
+
+    #[test]
+    fn counterexamples() {
+        // A minimum that is stated too high is found close to the minimizer
+        let points = sample(&[(-10.0, 10.0); 2], &[1.0, 3.0], &PropertyConfig::default());
+        let counterexample = find_below_minimum(Booth::f, 1e-3, &points, Tolerance::default()).unwrap();
+        assert!(counterexample.f < 1e-3 && counterexample.minimum == 1e-3);
+        assert!(find_below_minimum(Booth::f, 0.0, &points, Tolerance::default()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "below its global minimum")]
+    fn failure() {
+        check_function(&Function { minimum: |_| 1.0, ..*find("Booth").unwrap() }, 2, &PropertyConfig::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "are not finite and ordered")]
+    fn unordered_bounds() {
+        check_function(&Function { bounds: |n| vec![(10.0, -10.0); n], ..*find("Booth").unwrap() }, 2, &PropertyConfig::default());
+    }
+
+    #[test]
+    fn functions_are_above_their_minima() {
+        for function in functions() {
+            for d in function.dimension.map_or(vec![2, 5], |d| vec![d]) {
+                check_function(function, d, &PropertyConfig::default());
+            }
+        }
+        check_above_minimum::<Booth>(2, &PropertyConfig { cases: 1000, seed: 3, ..Default::default() });
+        check_minimizer_feasible::<Booth>(2);
+        check_minimizer_satisfies_constraints::<RosenbrockConst1>(2, Tolerance::default());
+    }
+}
@@ -0,0 +1,186 @@
+//! This module contains measurements of the cost of evaluating benchmark functions
+//!
+//! The cost of an evaluation is measured by evaluating the function repeatedly at a fixed set of
+//! random points in its bounds, after a warm-up period that also calibrates how many evaluations
+//! fit in each sample. Per-evaluation costs let results be reported against CPU time as well as
+//! against evaluations, and the `evaluation` bench uses them to catch performance regressions.
+//!
+//! ```
+//! use std::time::Duration;
+//! use benchfun::Rastrigin;
+//! use benchfun::timing::{time, TimingConfig};
+//!
+//! let config = TimingConfig { samples: 5, sample_time: Duration::from_millis(1), warmup: Duration::from_millis(1), ..Default::default() };
+//! for timing in time::<Rastrigin>(&[2, 100], &config) {
+//!     println!("{} {}D: {:.1} ns per evaluation", timing.name, timing.dimension, timing.median());
+//! }
+//! ```
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Bounded, SingleObjective, Rng};
+#[cfg(feature = "json")]
+use crate::json::{Json, JsonError, ToJson, FromJson};
+use crate::problem::name;
+use crate::sampling::uniform_in_bounds;
+
+/// This struct configures a timing measurement
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingConfig {
+    /// The number of distinct points that are evaluated in turn
+    pub points: usize,
+    /// The number of samples
+    pub samples: usize,
+    /// The target duration of each sample
+    pub sample_time: Duration,
+    /// The duration of the warm-up period
+    pub warmup: Duration,
+    /// The seed of the points
+    pub seed: u64,
+}
+
+impl Default for TimingConfig {
+    fn default() -> Self {
+        TimingConfig { points: 64, samples: 20, sample_time: Duration::from_millis(10), warmup: Duration::from_millis(50), seed: 0 }
+    }
+}
+
+/// This struct holds the measured cost of evaluating a function at a dimensionality
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timing {
+    /// The name of the function
+    pub name: String,
+    /// The number of dimensions
+    pub dimension: usize,
+    /// The mean time per evaluation of each sample, in nanoseconds
+    pub samples: Vec<f64>,
+}
+
+impl Timing {
+    /// This function returns the mean time per evaluation, in nanoseconds
+    pub fn mean(&self) -> f64 {
+        self.samples.iter().sum::<f64>()/self.samples.len() as f64
+    }
+
+    /// This function returns the median time per evaluation, in nanoseconds
+    pub fn median(&self) -> f64 {
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let n = sorted.len();
+        if n % 2 == 1 { sorted[n/2] } else { 0.5*(sorted[n/2 - 1] + sorted[n/2]) }
+    }
+
+    /// This function returns the fastest time per evaluation, in nanoseconds
+    pub fn min(&self) -> f64 {
+        self.samples.iter().cloned().fold(f64::INFINITY, f64::min)
+    }
+
+    /// This function returns the median time per evaluation
+    pub fn per_evaluation(&self) -> Duration {
+        Duration::from_nanos(self.median().round() as u64)
+    }
+
+    /// This function returns the number of evaluations that would take as long as a duration, which
+    /// converts CPU time into evaluations for CPU-time-normalized comparisons
+    pub fn equivalent_evaluations(&self, elapsed: Duration) -> f64 {
+        elapsed.as_nanos() as f64/self.median()
+    }
+
+    /// This function returns the relative change of the median time from a baseline measurement,
+    /// where positive values are slowdowns
+    pub fn change_from(&self, baseline: &Timing) -> f64 {
+        self.median()/baseline.median() - 1.0
+    }
+}
+
+#[cfg(feature = "json")]
+impl ToJson for Timing {
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            ("name".to_string(), self.name.to_json()),
+            ("dimension".to_string(), self.dimension.to_json()),
+            ("median".to_string(), self.median().to_json()),
+            ("samples".to_string(), self.samples.to_json()),
+        ])
+    }
+}
+
+#[cfg(feature = "json")]
+impl FromJson for Timing {
+    fn from_json(json: &Json) -> Result<Self, JsonError> {
+        Ok(Timing { name: json.field("name")?, dimension: json.field("dimension")?, samples: json.field("samples")? })
+    }
+}
+
+/// This function measures the cost of evaluating a function at a dimensionality
+pub fn time_at<F: SingleObjective + Bounded>(d: usize, config: &TimingConfig) -> Timing {
+    assert!(config.points > 0 && config.samples > 0, "At least one point and one sample are needed.");
+    let points = uniform_in_bounds::<F>(config.points, d, &mut Rng::new(config.seed));
+    let mut next = 0;
+    let mut batch = |evaluations: usize| {
+        let start = Instant::now();
+        for _ in 0..evaluations {
+            black_box(F::f(black_box(points[next].clone())));
+            next = (next + 1) % points.len();
+        }
+        start.elapsed()
+    };
+
+    // Warm up, doubling the batch size until the warm-up period is over, to calibrate the samples
+    let mut evaluations = 1;
+    let mut spent = Duration::ZERO;
+    let mut last = batch(evaluations);
+    while spent < config.warmup {
+        evaluations *= 2;
+        last = batch(evaluations);
+        spent += last;
+    }
+    let per_evaluation = last.as_secs_f64().max(1e-9)/evaluations as f64;
+    let per_sample = ((config.sample_time.as_secs_f64()/per_evaluation) as usize).max(1);
+
+    let samples = (0..config.samples)
+        .map(|_| batch(per_sample).as_nanos() as f64/per_sample as f64)
+        .collect();
+    Timing { name: name::<F>().to_string(), dimension: d, samples }
+}
+
+/// This function measures the cost of evaluating a function at each of several dimensionalities
+pub fn time<F: SingleObjective + Bounded>(dims: &[usize], config: &TimingConfig) -> Vec<Timing> {
+    dims.iter().map(|d| time_at::<F>(*d, config)).collect()
+}
+
+#[cfg(test)]
+mod timing_tests {
+    use std::time::Duration;
+    use super::{time, Timing, TimingConfig};
+    use crate::Rastrigin;
+    #[cfg(feature = "json")]
+    use crate::json::{Json, ToJson, FromJson};
+
+    #[test]
+    fn statistics() {
+        let timing = Timing { name: "Rastrigin".to_string(), dimension: 2, samples: vec![4.0, 1.0, 3.0, 2.0] };
+        assert_eq!((timing.mean(), timing.median(), timing.min()), (2.5, 2.5, 1.0));
+        assert_eq!(timing.equivalent_evaluations(Duration::from_nanos(25)), 10.0);
+        assert_eq!(timing.per_evaluation(), Duration::from_nanos(3));
+        let slower = Timing { samples: vec![5.0], ..timing.clone() };
+        assert_eq!(slower.change_from(&timing), 1.0);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        let timing = Timing { name: "Rastrigin".to_string(), dimension: 2, samples: vec![4.0, 1.0, 3.0, 2.0] };
+        let json: Json = timing.to_json().to_string().parse().unwrap();
+        assert_eq!(Timing::from_json(&json).unwrap(), timing);
+    }
+
+    #[test]
+    fn measurement() {
+        let config = TimingConfig { points: 4, samples: 3, sample_time: Duration::from_micros(200), warmup: Duration::from_micros(200), seed: 1 };
+        let timings = time::<Rastrigin>(&[2, 1000], &config);
+        assert_eq!(timings.iter().map(|t| (t.name.as_str(), t.dimension, t.samples.len())).collect::<Vec<_>>(), vec![("Rastrigin", 2, 3), ("Rastrigin", 1000, 3)]);
+        assert!(timings.iter().all(|t| t.min() > 0.0));
+    }
+}
@@ -0,0 +1,76 @@
+//! This module contains the tolerance used to compare function values with known minima
+//!
+//! A value is within a tolerance of an expected value if it is within the absolute tolerance or
+//! within the relative tolerance of the larger magnitude of the two. A zero tolerance only accepts
+//! exact equality.
+//!
+//! ```
+//! use benchfun::Tolerance;
+//!
+//! assert!(Tolerance::relative(1e-9).accepts(-959.6407, -959.6407 + 1e-7));
+//! assert!(!Tolerance::absolute(1e-9).accepts(-959.6407, -959.6407 + 1e-7));
+//! assert!(Tolerance::exact().accepts(0.0, -0.0));
+//! ```
+
+/// This struct holds an absolute and a relative tolerance
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    /// The largest accepted absolute difference
+    pub absolute: f64,
+    /// The largest accepted difference relative to the larger magnitude of the two values
+    pub relative: f64,
+}
+
+impl Tolerance {
+    /// This function creates a tolerance with both an absolute and a relative part
+    pub fn new(absolute: f64, relative: f64) -> Tolerance {
+        assert!(absolute >= 0.0 && relative >= 0.0, "Tolerances must be non-negative.");
+        Tolerance { absolute, relative }
+    }
+
+    /// This function creates a purely absolute tolerance
+    pub fn absolute(absolute: f64) -> Tolerance {
+        Tolerance::new(absolute, 0.0)
+    }
+
+    /// This function creates a purely relative tolerance
+    pub fn relative(relative: f64) -> Tolerance {
+        Tolerance::new(0.0, relative)
+    }
+
+    /// This function creates a tolerance that only accepts exact equality
+    pub fn exact() -> Tolerance {
+        Tolerance::new(0.0, 0.0)
+    }
+
+    /// This function returns whether a value is within the tolerance of an expected value
+    pub fn accepts(&self, value: f64, expected: f64) -> bool {
+        value == expected || (value - expected).abs() <= self.absolute.max(self.relative*value.abs().max(expected.abs()))
+    }
+}
+
+impl Default for Tolerance {
+    /// The default tolerance allows for the rounding error of a few operations
+    fn default() -> Tolerance {
+        Tolerance::new(f64::EPSILON, 4.0*f64::EPSILON)
+    }
+}
+
+#[cfg(test)]
+mod tolerance_tests {
+    use super::Tolerance;
+
+    #[test]
+    fn accepts() {
+        assert!(Tolerance::exact().accepts(1.5, 1.5));
+        assert!(!Tolerance::exact().accepts(1.5, 1.5 + f64::EPSILON));
+        assert!(Tolerance::exact().accepts(f64::NEG_INFINITY, f64::NEG_INFINITY));
+        assert!(!Tolerance::default().accepts(f64::NAN, f64::NAN));
+        assert!(Tolerance::absolute(0.1).accepts(1.0, 1.1 - 1e-12));
+        assert!(!Tolerance::absolute(0.1).accepts(1.0, 1.2));
+        assert!(Tolerance::relative(1e-3).accepts(1000.0, 1000.9));
+        assert!(!Tolerance::relative(1e-3).accepts(1.0, 1.01));
+        assert!(Tolerance::new(1e-3, 1e-3).accepts(0.0, 1e-3));
+        assert!(Tolerance::default().accepts(0.1 + 0.2, 0.3));
+    }
+}
@@ -0,0 +1,180 @@
+//! This module contains a panic-free API for evaluating the functions of the registry
+//!
+//! The `f` functions panic when a fixed-dimensional function is given a point of the wrong size, and
+//! several N-dimensional functions panic on an empty point. The functions of this module validate
+//! their input instead, and report every problem as an `EvaluationError`: they are defined for any
+//! name, any point and any dimensionality, so they can be embedded in long-running services. Every
+//! function is defined in one or more dimensions and none in zero, so an empty point is an error,
+//! and so is a result that is NaN or infinite.
+//!
+//! ```
+//! use benchfun::total::{evaluate, EvaluationError};
+//!
+//! assert_eq!(evaluate("Booth", &[1.0, 3.0]), Ok(0.0));
+//! assert_eq!(evaluate("Rosenbrock", &[1.0]), Ok(0.0));
+//! assert!(matches!(evaluate("Booth", &[1.0]), Err(EvaluationError::Dimension { .. })));
+//! assert!(matches!(evaluate("Rosenbrock", &[]), Err(EvaluationError::Dimension { .. })));
+//! assert!(matches!(evaluate("Vincent", &[-1.0]), Err(EvaluationError::NonFinite(_))));
+//! assert!(matches!(evaluate("Nonexistent", &[0.0]), Err(EvaluationError::UnknownFunction(_))));
+//! ```
+
+use std::fmt;
+
+use crate::NonFiniteError;
+use crate::problem::Descriptor;
+use crate::registry::{find, Function};
+
+/// This is an error encountered while evaluating a function of the registry
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvaluationError {
+    /// There is no function with this name in the registry
+    UnknownFunction(String),
+    /// The function is not defined at this dimensionality
+    Dimension {
+        /// The name of the function
+        function: &'static str,
+        /// The dimensionality that was requested
+        dimension: usize,
+        /// The dimensionality of a fixed-dimensional function, or `None` for N-dimensional functions
+        expected: Option<usize>,
+    },
+    /// The function, or one of its constraints, evaluated to NaN or an infinity
+    NonFinite(NonFiniteError),
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvaluationError::UnknownFunction(name) => write!(f, "There is no function named {}.", name),
+            EvaluationError::Dimension { function, dimension, expected: Some(d) } =>
+                write!(f, "{} was evaluated in {} dimensions instead of {}.", function, dimension, d),
+            EvaluationError::Dimension { function, dimension, expected: None } =>
+                write!(f, "{} was evaluated in {} dimensions instead of one or more.", function, dimension),
+            EvaluationError::NonFinite(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for EvaluationError {}
+
+impl From<NonFiniteError> for EvaluationError {
+    fn from(error: NonFiniteError) -> EvaluationError {
+        EvaluationError::NonFinite(error)
+    }
+}
+
+impl Function {
+    /// This function checks that the function is defined at a dimensionality
+    pub fn check_dimension(&self, d: usize) -> Result<(), EvaluationError> {
+        if self.supports(d) {
+            Ok(())
+        } else {
+            Err(EvaluationError::Dimension { function: self.name, dimension: d, expected: self.dimension })
+        }
+    }
+
+    /// This function evaluates the function without panicking, returning an error for a point of an
+    /// unsupported dimensionality or a value that is NaN or infinite
+    pub fn try_f(&self, x: &[f64]) -> Result<f64, EvaluationError> {
+        self.check_dimension(x.len())?;
+        let value = (self.f)(x.to_vec());
+        NonFiniteError::check(x, &[value])?;
+        Ok(value)
+    }
+
+    /// This function evaluates the equality and inequality constraints without panicking. Both are
+    /// empty for unconstrained functions.
+    pub fn try_constraints(&self, x: &[f64]) -> Result<(Vec<f64>, Vec<f64>), EvaluationError> {
+        self.check_dimension(x.len())?;
+        let h = self.equality_constraints.map_or(vec![], |h| h(x.to_vec()));
+        let g = self.inequality_constraints.map_or(vec![], |g| g(x.to_vec()));
+        NonFiniteError::check(x, &[h.as_slice(), g.as_slice()].concat())?;
+        Ok((h, g))
+    }
+
+    /// This function describes the function at a dimensionality without panicking
+    pub fn try_descriptor(&self, d: usize) -> Result<Descriptor, EvaluationError> {
+        self.check_dimension(d)?;
+        Ok(self.descriptor(d))
+    }
+}
+
+/// This function returns the function of the registry with a name
+fn lookup(name: &str) -> Result<&'static Function, EvaluationError> {
+    find(name).ok_or_else(|| EvaluationError::UnknownFunction(name.to_string()))
+}
+
+/// This function evaluates a function of the registry by name without panicking
+pub fn evaluate(name: &str, x: &[f64]) -> Result<f64, EvaluationError> {
+    lookup(name)?.try_f(x)
+}
+
+/// This function evaluates the equality and inequality constraints of a function of the registry by
+/// name without panicking
+pub fn constraints(name: &str, x: &[f64]) -> Result<(Vec<f64>, Vec<f64>), EvaluationError> {
+    lookup(name)?.try_constraints(x)
+}
+
+/// This function describes a function of the registry by name at a dimensionality without panicking
+pub fn describe(name: &str, d: usize) -> Result<Descriptor, EvaluationError> {
+    lookup(name)?.try_descriptor(d)
+}
+
+#[cfg(test)]
+mod total_tests {
+    use super::{evaluate, constraints, describe, EvaluationError};
+    use crate::{Rng, NonFiniteError};
+    use crate::registry::functions;
+
+    /// The coordinates that are most likely to break a function
+    const EDGES: [f64; 10] = [0.0, -0.0, 1.0, -1.0, 5e-324, 1e308, -1e308, f64::INFINITY, f64::NEG_INFINITY, f64::NAN];
+
+    #[test]
+    fn edges() {
+        for function in functions() {
+            for d in 0..=8 {
+                let supported = d > 0 && function.dimension.is_none_or(|fixed| fixed == d);
+                assert_eq!(describe(function.name, d).is_ok(), supported, "{} in {} dimensions", function.name, d);
+                for xi in EDGES.iter() {
+                    for result in [evaluate(function.name, &vec![*xi; d]).map(|_| ()), constraints(function.name, &vec![*xi; d]).map(|_| ())] {
+                        match result {
+                            Err(EvaluationError::Dimension { function: name, dimension, expected }) => {
+                                assert!(!supported);
+                                assert_eq!((name, dimension, expected), (function.name, d, function.dimension));
+                            }
+                            Err(EvaluationError::NonFinite(_)) | Ok(()) => assert!(supported),
+                            Err(EvaluationError::UnknownFunction(_)) => panic!(),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random() {
+        // Points of random size, with coordinates from random bit patterns, as a fuzzer would produce
+        let mut rng = Rng::new(0);
+        for _ in 0..20_000 {
+            let function = &functions()[rng.below(functions().len())];
+            let x: Vec<f64> = (0..rng.below(8)).map(|_| f64::from_bits(rng.next_u64())).collect();
+            if let Ok(value) = evaluate(function.name, &x) {
+                assert!(value.is_finite());
+            }
+            if let Ok((h, g)) = constraints(function.name, &x) {
+                assert!(h.iter().chain(g.iter()).all(|v| v.is_finite()));
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(evaluate("Booth", &[1.0, 3.0, 0.0]).unwrap_err().to_string(), "Booth was evaluated in 3 dimensions instead of 2.");
+        assert_eq!(evaluate("Sphere", &[]).unwrap_err().to_string(), "Sphere was evaluated in 0 dimensions instead of one or more.");
+        assert_eq!(describe("", 2).unwrap_err().to_string(), "There is no function named .");
+        assert!(matches!(evaluate("Sphere", &[f64::NAN]), Err(EvaluationError::NonFinite(NonFiniteError { non_finite_inputs, .. })) if non_finite_inputs == vec![0]));
+        assert_eq!(constraints("Sphere", &[1.0]), Ok((vec![], vec![])));
+        assert!(!constraints("RosenbrockConst1", &[1.0, 1.0]).unwrap().1.is_empty());
+        assert_eq!(describe("Ridge", 1).unwrap().minimizer.len(), 1);
+    }
+}
@@ -0,0 +1,139 @@
+//! This module contains WebAssembly exports for browser visualizations. It is only available with
+//! the `wasm` feature, which also enables the `capi` feature.
+//!
+//! The exports use the plain WebAssembly ABI rather than generated bindings, so the crate can be
+//! built with
+//! `cargo rustc --target wasm32-unknown-unknown --release --features wasm --crate-type cdylib`
+//! and loaded with `wasm/benchfun.js`. Besides the C interface of the `capi` module, there are exports to
+//! allocate buffers in the module's memory, to evaluate a function on a grid for plotting, and to
+//! describe a function as JSON.
+//!
+//! ```
+//! use benchfun::wasm::*;
+//! use benchfun::capi::BENCHFUN_OK;
+//!
+//! let buffer = benchfun_alloc(4096);
+//! unsafe {
+//!     let length = benchfun_metadata(0, 2, buffer, 4096);
+//!     assert!(length > 0);
+//!     let json = std::str::from_utf8(std::slice::from_raw_parts(buffer, length as usize)).unwrap();
+//!     assert!(json.starts_with("{\"name\":"));
+//!     benchfun_free(buffer, 4096);
+//! }
+//! ```
+
+use std::alloc::{alloc, dealloc, Layout};
+use std::os::raw::c_int;
+
+use crate::capi::{function, BENCHFUN_OK, BENCHFUN_UNKNOWN_FUNCTION, BENCHFUN_UNSUPPORTED_DIMENSION, BENCHFUN_INVALID_ARGUMENT};
+use crate::json::ToJson;
+
+/// This function returns the layout of a buffer, which is aligned for doubles
+fn layout(bytes: usize) -> Option<Layout> {
+    Layout::from_size_align(bytes.max(1), 8).ok()
+}
+
+/// This function allocates a buffer of `bytes` bytes, aligned for doubles, or returns null
+#[no_mangle]
+pub extern "C" fn benchfun_alloc(bytes: usize) -> *mut u8 {
+    // The layout is never zero-sized
+    layout(bytes).map_or(std::ptr::null_mut(), |layout| unsafe { alloc(layout) })
+}
+
+/// This function frees a buffer allocated by `benchfun_alloc` with the same size
+///
+/// # Safety
+/// `buffer` must be null or have been returned by `benchfun_alloc(bytes)` and not freed since.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_free(buffer: *mut u8, bytes: usize) {
+    if let (false, Some(layout)) = (buffer.is_null(), layout(bytes)) {
+        dealloc(buffer, layout)
+    }
+}
+
+/// This function evaluates a function on a `resolution` by `resolution` grid that spans its bounds
+/// in 2-D. The coordinates are written to `x` and `y`, and the values to `z` in row-major order,
+/// with `z[j*resolution + i]` the value at `(x[i], y[j])`. Functions that are unbounded or cannot
+/// be evaluated in 2-D are unsupported.
+///
+/// # Safety
+/// `x` and `y` must each point to `resolution` writable doubles and `z` to `resolution*resolution`,
+/// or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_grid(id: c_int, resolution: usize, x: *mut f64, y: *mut f64, z: *mut f64) -> c_int {
+    let function = match function(id) {
+        Some(function) => function,
+        None => return BENCHFUN_UNKNOWN_FUNCTION,
+    };
+    if x.is_null() || y.is_null() || z.is_null() || resolution < 2 {
+        return BENCHFUN_INVALID_ARGUMENT;
+    }
+    let grid = match function.grid(resolution) {
+        Some(grid) => grid,
+        None => return BENCHFUN_UNSUPPORTED_DIMENSION,
+    };
+    std::ptr::copy_nonoverlapping(grid.x.as_ptr(), x, resolution);
+    std::ptr::copy_nonoverlapping(grid.y.as_ptr(), y, resolution);
+    for (j, row) in grid.z.iter().enumerate() {
+        std::ptr::copy_nonoverlapping(row.as_ptr(), z.add(j*resolution), resolution);
+    }
+    BENCHFUN_OK
+}
+
+/// This function writes the description of a function at a dimensionality as UTF-8 JSON, with the
+/// fields of `problem::Descriptor`, and returns its length in bytes. Nothing is written if the
+/// buffer holds fewer than that many bytes, so the length can be queried with a capacity of zero.
+///
+/// # Safety
+/// `buffer` must point to `capacity` writable bytes, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn benchfun_metadata(id: c_int, d: usize, buffer: *mut u8, capacity: usize) -> c_int {
+    let function = match function(id) {
+        Some(function) => function,
+        None => return BENCHFUN_UNKNOWN_FUNCTION,
+    };
+    if !function.supports(d) {
+        return BENCHFUN_UNSUPPORTED_DIMENSION;
+    }
+    let json = function.descriptor(d).to_json().to_string();
+    if json.len() <= capacity && !buffer.is_null() {
+        std::ptr::copy_nonoverlapping(json.as_ptr(), buffer, json.len());
+    }
+    json.len() as c_int
+}
+
+#[cfg(test)]
+mod wasm_tests {
+    use super::*;
+    use crate::registry::index;
+    use crate::grid::grid_evaluate;
+    use crate::Himmelblau;
+    use std::ptr::null_mut;
+
+    #[test]
+    fn grid() {
+        let id = index("Himmelblau").unwrap() as c_int;
+        let (mut x, mut y, mut z) = (vec![0.0; 5], vec![0.0; 5], vec![0.0; 25]);
+        unsafe {
+            assert_eq!(benchfun_grid(id, 5, x.as_mut_ptr(), y.as_mut_ptr(), z.as_mut_ptr()), BENCHFUN_OK);
+            assert_eq!(benchfun_grid(id, 5, null_mut(), y.as_mut_ptr(), z.as_mut_ptr()), BENCHFUN_INVALID_ARGUMENT);
+            assert_eq!(benchfun_grid(index("Sphere").unwrap() as c_int, 5, x.as_mut_ptr(), y.as_mut_ptr(), z.as_mut_ptr()), BENCHFUN_UNSUPPORTED_DIMENSION);
+        }
+        let grid = grid_evaluate::<Himmelblau>(5);
+        assert_eq!((x, y, z), (grid.x, grid.y, grid.z.concat()));
+    }
+
+    #[test]
+    fn metadata() {
+        let id = index("Booth").unwrap() as c_int;
+        unsafe {
+            let length = benchfun_metadata(id, 2, null_mut(), 0);
+            let buffer = benchfun_alloc(length as usize);
+            assert_eq!(benchfun_metadata(id, 2, buffer, length as usize), length);
+            let json = std::str::from_utf8(std::slice::from_raw_parts(buffer, length as usize)).unwrap().to_string();
+            benchfun_free(buffer, length as usize);
+            assert_eq!(json, "{\"name\":\"Booth\",\"dimension\":2,\"bounds\":[[-10,10],[-10,10]],\"minimum\":0,\"minimizer\":[1,3]}");
+            assert_eq!(benchfun_metadata(id, 3, null_mut(), 0), BENCHFUN_UNSUPPORTED_DIMENSION);
+        }
+    }
+}
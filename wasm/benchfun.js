@@ -0,0 +1,86 @@
+// Loader for the WebAssembly build of benchfun, built with
+// `cargo rustc --target wasm32-unknown-unknown --release --features wasm --crate-type cdylib`.
+//
+//     import { load } from "./benchfun.js";
+//     const benchfun = await load("benchfun.wasm");
+//     benchfun.evaluate("Ackley", [0.5, 0.5]);
+//     const { x, y, z } = benchfun.grid("Himmelblau", 100);
+
+export async function load(url) {
+  const { instance } = await WebAssembly.instantiateStreaming(fetch(url), {});
+  const wasm = instance.exports;
+
+  // Run a call with a temporary buffer of doubles, which is freed afterwards. Views of the memory
+  // are recreated after each call, since the memory may have grown.
+  function withDoubles(n, g) {
+    const bytes = 8 * Math.max(n, 1);
+    const pointer = wasm.benchfun_alloc(bytes);
+    try {
+      return g(pointer, new Float64Array(wasm.memory.buffer, pointer, n));
+    } finally {
+      wasm.benchfun_free(pointer, bytes);
+    }
+  }
+
+  function check(status) {
+    if (status < 0) {
+      throw new Error(`benchfun call failed with status ${status}`);
+    }
+    return status;
+  }
+
+  function names() {
+    const count = wasm.benchfun_count();
+    return Array.from({ length: count }, (_, id) => metadataOf(id).name);
+  }
+
+  function id(name) {
+    const found = names().indexOf(name);
+    if (found < 0) {
+      throw new Error(`unknown function ${name}`);
+    }
+    return found;
+  }
+
+  function metadataOf(id, d) {
+    // size_t is 32 bits wide on wasm32
+    const dimension = withDoubles(1, (pointer) => {
+      check(wasm.benchfun_dimension(id, pointer));
+      return new Uint32Array(wasm.memory.buffer, pointer, 1)[0];
+    });
+    const n = d ?? (dimension || 2);
+    const length = check(wasm.benchfun_metadata(id, n, 0, 0));
+    const pointer = wasm.benchfun_alloc(length);
+    try {
+      check(wasm.benchfun_metadata(id, n, pointer, length));
+      return JSON.parse(new TextDecoder().decode(new Uint8Array(wasm.memory.buffer, pointer, length)));
+    } finally {
+      wasm.benchfun_free(pointer, length);
+    }
+  }
+
+  function evaluate(name, x) {
+    return withDoubles(x.length + 1, (pointer, view) => {
+      view.set(x);
+      check(wasm.benchfun_evaluate(id(name), pointer, x.length, pointer + 8 * x.length));
+      return new Float64Array(wasm.memory.buffer, pointer, x.length + 1)[x.length];
+    });
+  }
+
+  function grid(name, resolution) {
+    const n = resolution * (resolution + 2);
+    return withDoubles(n, (pointer) => {
+      const x = pointer, y = x + 8 * resolution, z = y + 8 * resolution;
+      check(wasm.benchfun_grid(id(name), resolution, x, y, z));
+      const values = new Float64Array(wasm.memory.buffer, pointer, n);
+      const row = (j) => Array.from(values.subarray(2 * resolution + j * resolution, 2 * resolution + (j + 1) * resolution));
+      return {
+        x: Array.from(values.subarray(0, resolution)),
+        y: Array.from(values.subarray(resolution, 2 * resolution)),
+        z: Array.from({ length: resolution }, (_, j) => row(j)),
+      };
+    });
+  }
+
+  return { names, metadata: (name, d) => metadataOf(id(name), d), evaluate, grid };
+}